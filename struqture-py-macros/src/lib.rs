@@ -20,6 +20,8 @@ mod product_wrapper;
 use product_wrapper::productwrapper;
 
 /// Attribute macro for constructing the pyo3 implementation for mixed indices.
+///
+/// The generated code converts errors with `struqture_error_to_py_err`, which has to be in scope where the macro is used.
 #[proc_macro_attribute]
 pub fn product_wrapper(
     metadata: proc_macro::TokenStream,
@@ -32,6 +34,8 @@ mod noiseless_system_wrapper;
 use noiseless_system_wrapper::noiselesswrapper;
 
 /// Attribute macro for constructing the pyo3 implementation for noiseless systems.
+///
+/// The generated code converts errors with `struqture_error_to_py_err`, which has to be in scope where the macro is used.
 #[proc_macro_attribute]
 pub fn noiseless_system_wrapper(
     metadata: proc_macro::TokenStream,
@@ -44,6 +48,8 @@ mod noisy_system_wrapper;
 use noisy_system_wrapper::noisywrapper;
 
 /// Attribute macro for constructing the pyo3 implementation for noisy systems.
+///
+/// The generated code converts errors with `struqture_error_to_py_err`, which has to be in scope where the macro is used.
 #[proc_macro_attribute]
 pub fn noisy_system_wrapper(
     metadata: proc_macro::TokenStream,
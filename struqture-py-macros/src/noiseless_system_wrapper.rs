@@ -167,6 +167,7 @@ pub fn noiselesswrapper(
                 ///
                 /// Raises:
                 ///     ValueError: Product could not be constructed.
+                ///     StruqtureError: Key could not be set, e.g. NumberSpinsExceededError if the key exceeds the number of spins of the system.
                 pub fn set(
                     &mut self,
                     key: &Bound<PyAny>,
@@ -180,12 +181,11 @@ pub fn noiselesswrapper(
                             err
                         ))
                     })?;
-                    match self.internal.set(converted_key, value).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Error in set function of System: {:?}",
-                            err
-                        ))
-                    })? {
+                    match self
+                        .internal
+                        .set(converted_key, value)
+                        .map_err(struqture_error_to_py_err)?
+                    {
                         Some(x) => Ok(Some(#value_type { internal: x })),
                         None => Ok(None),
                     }
@@ -211,12 +211,7 @@ pub fn noiselesswrapper(
                     })?;
                     self.internal
                         .add_operator_product(converted_key, value)
-                        .map_err(|err| {
-                            PyValueError::new_err(format!(
-                                "Error in add_operator_product function of System: {:?}",
-                                err
-                            ))
-                        })
+                        .map_err(struqture_error_to_py_err)
                 }
 
                 /// Return unsorted values in self.
@@ -280,7 +275,7 @@ pub fn noiselesswrapper(
             /// Raises:
             ///     ValueError: Error in adding terms to return values.
             pub fn separate_into_n_terms(&self, number_creators_annihilators: (usize, usize)) -> PyResult<(#ident, #ident)> {
-                let (separated, remainder) = self.internal.separate_into_n_terms(number_creators_annihilators).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+                let (separated, remainder) = self.internal.separate_into_n_terms(number_creators_annihilators).map_err(struqture_error_to_py_err)?;
                 Ok((
                     #ident { internal: separated },
                     #ident { internal: remainder }
@@ -319,7 +314,7 @@ pub fn noiselesswrapper(
                 /// Raises:
                 ///     ValueError: Error in adding terms to return values.
         pub fn separate_into_n_terms(&self, number_spins: usize) -> PyResult<(#ident, #ident)> {
-                    let (separated, remainder) = self.internal.separate_into_n_terms(number_spins).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+                    let (separated, remainder) = self.internal.separate_into_n_terms(number_spins).map_err(struqture_error_to_py_err)?;
                     Ok((
                         #ident { internal: separated },
                         #ident { internal: remainder }
@@ -341,23 +336,40 @@ pub fn noiselesswrapper(
                 ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 #[pyo3(signature = (number_spins = None))]
                 pub fn sparse_matrix_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
                         .sparse_matrix_coo(number_spins)
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     to_py_coo(coo)
                 }
+
+                /// Constructs the sparse matrix representation of self in scipy compatible CSR form with a given number of spins.
+                ///
+                /// The returned arrays can be passed directly to `scipy.sparse.csr_matrix((data, indices, indptr))`.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+                ///
+                /// Raises:
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_csr(number_spins)
+                        .map_err(struqture_error_to_py_err)?;
+                    to_py_csr(csr)
+                }
         }
     } else {
         TokenStream::new()
@@ -369,7 +381,7 @@ pub fn noiselesswrapper(
                 /// Construct the sparse matrix representation of the superoperator in COO representation.
                 ///
                 /// The superoperator for the operator O is defined as the Matrix S so that
-                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+                /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
                 /// and `flatten` flattens a matrix into a vector in row-major form.
                 ///
                 /// Args:
@@ -379,44 +391,58 @@ pub fn noiselesswrapper(
                 ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 #[pyo3(signature = (number_spins = None))]
                 pub fn sparse_matrix_superoperator_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
                         .sparse_matrix_superoperator_coo(number_spins)
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     to_py_coo(coo)
                 }
 
+                /// Construct the sparse matrix representation of the superoperator in CSR representation.
+                ///
+                /// The superoperator for the operator O is defined as the Matrix S so that
+                /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+                /// and `flatten` flattens a matrix into a vector in row-major form.
+                ///
+                /// The returned arrays can be passed directly to `scipy.sparse.csr_matrix((data, indices, indptr))`.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+                ///
+                /// Raises:
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(struqture_error_to_py_err)?;
+                    to_py_csr(csr)
+                }
+
                 /// Return the unitary part of the superoperator in the sparse COO format.
                 ///
                 /// Returns:
                 ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of the unitary part of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 pub fn unitary_sparse_matrix_coo(&self) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
                         .unitary_sparse_matrix_coo()
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     to_py_coo(coo)
                 }
 
@@ -426,20 +452,14 @@ pub fn noiselesswrapper(
                 ///     List[Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], complex]]: The matrix representation of the noise part of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 ///     RuntimeError: Could not convert to complex superoperator matrix.
                 pub fn sparse_lindblad_entries(&self) -> PyResult<Vec<(PyCooMatrix, PyCooMatrix, Complex64)>> {
                     let coo = self
                         .internal
                         .sparse_lindblad_entries()
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     let mut res_vec: Vec<(PyCooMatrix, PyCooMatrix, Complex64)> = Vec::new();
                     for mat in coo {
                         let left = to_py_coo(mat.0).map_err(|err| match err {
@@ -520,7 +540,7 @@ pub fn noiselesswrapper(
                 // /// Raises:
                 // ///     ValueError: Operator with the noise terms where number_particles matches the number of spins the operator product acts on and Operator with all other contributions.
                 // pub fn separate_into_n_terms(&self, number_particles: (usize, usize, usize)) -> PyResult<(#ident, #ident)> {
-                //     let (separated, remainder) = self.internal.separate_into_n_terms(number_particles).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+                //     let (separated, remainder) = self.internal.separate_into_n_terms(number_particles).map_err(struqture_error_to_py_err)?;
                 //     Ok((
                 //         #ident { internal: separated },
                 //         #ident { internal: remainder }
@@ -553,7 +573,7 @@ pub fn noiselesswrapper(
             /// Raises:
             ///     ValueError: Objects could not be added.
             pub fn __add__(&self, other: #ident) -> PyResult<#ident> {
-                let new_self = (self.clone().internal + other.internal).map_err(|err| PyValueError::new_err(format!("Objects could not be added: {:?}", err)))?;
+                let new_self = (self.clone().internal + other.internal).map_err(struqture_error_to_py_err)?;
                 Ok(#ident {
                     internal: new_self
                 })
@@ -570,7 +590,7 @@ pub fn noiselesswrapper(
             /// Raises:
             ///     ValueError: Objects could not be subtracted.
             pub fn __sub__(&self, other: #ident) -> PyResult<#ident> {
-                let new_self = (self.clone().internal - other.internal).map_err(|err| PyValueError::new_err(format!("Objects could not be subtracted: {:?}", err)))?;
+                let new_self = (self.clone().internal - other.internal).map_err(struqture_error_to_py_err)?;
                 Ok(#ident {
                     internal: new_self
                 })
@@ -651,7 +671,7 @@ pub fn noiselesswrapper(
             ///
             /// Raises:
             ///     TypeError: Input cannot be converted to byte array.
-            ///     ValueError: Input cannot be deserialized.
+            ///     GenericError: Input cannot be deserialized or was created with an incompatible version of struqture.
             #[staticmethod]
             pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<#ident> {
                 let bytes = input
@@ -659,12 +679,45 @@ pub fn noiselesswrapper(
                     .extract::<Vec<u8>>()
                     .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
+                Ok(#ident {
+                    internal: struqture::deserialize_with_version_check(|| {
+                        bincode::deserialize(&bytes[..])
+                    })
+                    .map_err(|err| {
+                        struqture_error_to_py_err(struqture::StruqtureError::GenericError {
+                            msg: format!("Input cannot be deserialized from bytes. {}", err),
+                        })
+                    })?,
+                })
+            }
+
+            /// Convert the bincode representation of self to an instance using the [bincode] crate, without checking the struqture version.
+            ///
+            /// Use with care: data written by a newer version of struqture can contain features that are
+            /// silently dropped or misinterpreted by this version of struqture. Only use this function when
+            /// the compatibility of the data is known.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in [bincode] form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     GenericError: Input cannot be deserialized.
+            #[staticmethod]
+            pub fn from_bincode_unchecked(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
                 Ok(#ident {
                     internal: bincode::deserialize(&bytes[..]).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized from bytes. {}",
-                            err
-                        ))
+                        struqture_error_to_py_err(struqture::StruqtureError::GenericError {
+                            msg: format!("Input cannot be deserialized from bytes. {}", err),
+                        })
                     })?,
                 })
             }
@@ -708,17 +761,37 @@ pub fn noiselesswrapper(
             ///     The deserialized object.
             ///
             /// Raises:
-            ///     ValueError: Input cannot be deserialized.
+            ///     VersionMismatchError: Input was created with an incompatible version of struqture.
+            ///     GenericError: Input cannot be deserialized.
             #[staticmethod]
             #[pyo3(text_signature = "(input)")]
             pub fn from_json(input: String) -> PyResult<#ident> {
                 Ok(#ident {
-                    internal: serde_json::from_str(&input).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized: {}",
-                            err
-                        ))
-                    })?,
+                    internal: struqture::from_json(&input)
+                        .map_err(struqture_error_to_py_err)?,
+                })
+            }
+
+            /// Convert the json representation of self to an instance, without checking the struqture version.
+            ///
+            /// Use with care: data written by a newer version of struqture can contain features that are
+            /// silently dropped or misinterpreted by this version of struqture. Only use this function when
+            /// the compatibility of the data is known.
+            ///
+            /// Args:
+            ///     input (str): The serialized object in json form.
+            ///
+            /// Returns:
+            ///     The deserialized object.
+            ///
+            /// Raises:
+            ///     GenericError: Input cannot be deserialized.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_json_unchecked(input: String) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::from_json_unchecked(&input)
+                        .map_err(struqture_error_to_py_err)?,
                 })
             }
 
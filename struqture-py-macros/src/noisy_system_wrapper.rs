@@ -155,9 +155,7 @@ pub fn noisywrapper(
                         match self
                             .internal
                             .set((converted_left, converted_right), value)
-                            .map_err(|err| {
-                                PyValueError::new_err(format!("Error in set function of FermionSystem: {:?}", err))
-                            })? {
+                            .map_err(struqture_error_to_py_err)? {
                             Some(x) => Ok(Some(CalculatorComplexWrapper { internal: x })),
                             None => Ok(None),
                         }
@@ -199,12 +197,7 @@ pub fn noisywrapper(
                         );
                         self.internal
                             .add_operator_product((converted_left, converted_right), value)
-                            .map_err(|err| {
-                                PyValueError::new_err(format!(
-                                    "Error in add_operator_product function of System: {:?}",
-                                    err
-                                ))
-                            })?;
+                            .map_err(struqture_error_to_py_err)?;
                         Ok(())
                     })
                 }
@@ -358,7 +351,7 @@ pub fn noisywrapper(
                 /// Construct the sparse matrix representation of the superoperator in COO representation.
                 ///
                 /// The superoperator for the operator O is defined as the Matrix S so that
-                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+                /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
                 /// and `flatten` flattens a matrix into a vector in row-major form.
                 ///
                 /// Args:
@@ -368,44 +361,58 @@ pub fn noisywrapper(
                 ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 #[pyo3(signature = (number_spins = None))]
                 pub fn sparse_matrix_superoperator_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
                         .sparse_matrix_superoperator_coo(number_spins)
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     to_py_coo(coo)
                 }
 
+                /// Construct the sparse matrix representation of the superoperator in CSR representation.
+                ///
+                /// The superoperator for the operator O is defined as the Matrix S so that
+                /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+                /// and `flatten` flattens a matrix into a vector in row-major form.
+                ///
+                /// The returned arrays can be passed directly to `scipy.sparse.csr_matrix((data, indices, indptr))`.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+                ///
+                /// Raises:
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     GenericError: No number_spins was given and self is empty.
+                ///     StruqtureError: The sparse matrix could not be constructed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(struqture_error_to_py_err)?;
+                    to_py_csr(csr)
+                }
+
                 /// Return the unitary part of the superoperator in the sparse COO format.
                 ///
                 /// Returns:
                 ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of the unitary part of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
-                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 pub fn unitary_sparse_matrix_coo(&self) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
                         .unitary_sparse_matrix_coo()
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     to_py_coo(coo)
                 }
 
@@ -415,20 +422,14 @@ pub fn noisywrapper(
                 ///     List[Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], complex]]: The matrix representation of the noise part of self.
                 ///
                 /// Raises:
-                ///     ValueError: CalculatorError.
+                ///     CalculatorError: Coefficients could not be converted to float.
+                ///     StruqtureError: The sparse matrix could not be constructed.
                 ///     RuntimeError: Could not convert to complex superoperator matrix.
                 pub fn sparse_lindblad_entries(&self) -> PyResult<Vec<(PyCooMatrix, PyCooMatrix, Complex64)>> {
                     let coo = self
                         .internal
                         .sparse_lindblad_entries()
-                        .map_err(|err| match err {
-                            StruqtureError::CalculatorError(c_err) => {
-                                PyValueError::new_err(format!("{}", c_err))
-                            }
-                            _ => PyRuntimeError::new_err(
-                                "Could not convert to complex superoperator matrix".to_string(),
-                            ),
-                        })?;
+                        .map_err(struqture_error_to_py_err)?;
                     let mut res_vec: Vec<(PyCooMatrix, PyCooMatrix, Complex64)> = Vec::new();
                     for mat in coo {
                         let left = to_py_coo(mat.0).map_err(|err| match err {
@@ -587,9 +588,7 @@ pub fn noisywrapper(
                 let noise = #noise_type::from_pyany(noise).map_err(|err| {
                     PyValueError::new_err(format!("Noise could not be constructed: {:?}", err))
                 })?;
-                let new_self = #struct_ident::group(system, noise).map_err(|err| {
-                    PyValueError::new_err(format!("Grouping could not be constructed: {:?}", err))
-                })?;
+                let new_self = #struct_ident::group(system, noise).map_err(struqture_error_to_py_err)?;
                 Ok(Self { internal: new_self })
             }
 
@@ -833,7 +832,7 @@ pub fn noisywrapper(
             /// Raises:
             ///     ValueError: Objects could not be added.
             pub fn __add__(&self, other: #ident) -> PyResult<#ident> {
-                let new_self = (self.clone().internal + other.internal).map_err(|err| PyValueError::new_err(format!("Objects could not be added: {:?}", err)))?;
+                let new_self = (self.clone().internal + other.internal).map_err(struqture_error_to_py_err)?;
                 Ok(#ident {
                     internal: new_self
                 })
@@ -850,7 +849,7 @@ pub fn noisywrapper(
             /// Raises:
             ///     ValueError: Objects could not be subtracted.
             pub fn __sub__(&self, other: #ident) -> PyResult<#ident> {
-                let new_self = (self.clone().internal - other.internal).map_err(|err| PyValueError::new_err(format!("Objects could not be subtracted: {:?}", err)))?;
+                let new_self = (self.clone().internal - other.internal).map_err(struqture_error_to_py_err)?;
                 Ok(#ident {
                     internal: new_self
                 })
@@ -924,7 +923,7 @@ pub fn noisywrapper(
             ///
             /// Raises:
             ///     TypeError: Input cannot be converted to byte array.
-            ///     ValueError: Input cannot be deserialized.
+            ///     GenericError: Input cannot be deserialized or was created with an incompatible version of struqture.
             #[staticmethod]
             pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<#ident> {
                 let bytes = input
@@ -932,12 +931,45 @@ pub fn noisywrapper(
                     .extract::<Vec<u8>>()
                     .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
+                Ok(#ident {
+                    internal: struqture::deserialize_with_version_check(|| {
+                        bincode::deserialize(&bytes[..])
+                    })
+                    .map_err(|err| {
+                        struqture_error_to_py_err(struqture::StruqtureError::GenericError {
+                            msg: format!("Input cannot be deserialized from bytes. {}", err),
+                        })
+                    })?,
+                })
+            }
+
+            /// Convert the bincode representation of the object to an instance using the [bincode] crate, without checking the struqture version.
+            ///
+            /// Use with care: data written by a newer version of struqture can contain features that are
+            /// silently dropped or misinterpreted by this version of struqture. Only use this function when
+            /// the compatibility of the data is known.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in [bincode] form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     GenericError: Input cannot be deserialized.
+            #[staticmethod]
+            pub fn from_bincode_unchecked(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
                 Ok(#ident {
                     internal: bincode::deserialize(&bytes[..]).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized from bytes. {}",
-                            err
-                        ))
+                        struqture_error_to_py_err(struqture::StruqtureError::GenericError {
+                            msg: format!("Input cannot be deserialized from bytes. {}", err),
+                        })
                     })?,
                 })
             }
@@ -981,17 +1013,37 @@ pub fn noisywrapper(
             ///     The deserialized object.
             ///
             /// Raises:
-            ///     ValueError: Input cannot be deserialized.
+            ///     VersionMismatchError: Input was created with an incompatible version of struqture.
+            ///     GenericError: Input cannot be deserialized.
             #[staticmethod]
             #[pyo3(text_signature = "(input)")]
             pub fn from_json(input: String) -> PyResult<#ident> {
                 Ok(#ident {
-                    internal: serde_json::from_str(&input).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized: {}",
-                            err
-                        ))
-                    })?,
+                    internal: struqture::from_json(&input)
+                        .map_err(struqture_error_to_py_err)?,
+                })
+            }
+
+            /// Convert the json representation of the object to an instance, without checking the struqture version.
+            ///
+            /// Use with care: data written by a newer version of struqture can contain features that are
+            /// silently dropped or misinterpreted by this version of struqture. Only use this function when
+            /// the compatibility of the data is known.
+            ///
+            /// Args:
+            ///     input (str): The serialized object in json form.
+            ///
+            /// Returns:
+            ///     The deserialized object.
+            ///
+            /// Raises:
+            ///     GenericError: Input cannot be deserialized.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_json_unchecked(input: String) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::from_json_unchecked(&input)
+                        .map_err(struqture_error_to_py_err)?,
                 })
             }
 
@@ -120,7 +120,7 @@ pub fn productwrapper(
                 ///    ValueError: Input reordering dictionary is not a permutation of the indices.
                 pub fn remap_modes(&self, reordering_dictionary: &Bound<PyAny>) -> PyResult<(#ident, qoqo_calculator_pyo3::CalculatorComplexWrapper)> {
                     let remap_dict = reordering_dictionary.as_ref().extract::<HashMap<usize, usize>>()?;
-                    let (index, value) = self.internal.remap_modes(&remap_dict).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+                    let (index, value) = self.internal.remap_modes(&remap_dict).map_err(struqture_error_to_py_err)?;
                     Ok((#ident{internal: index}, qoqo_calculator_pyo3::CalculatorComplexWrapper{internal: value}))
                 }
 
@@ -144,7 +144,7 @@ pub fn productwrapper(
                 #[classmethod]
                 pub fn create_valid_pair(_cls: Bound<PyType>, creators: Vec<usize>, annihilators: Vec<usize>, value: &Bound<PyAny>) -> PyResult<(#ident, qoqo_calculator_pyo3::CalculatorComplexWrapper)> {
                     let value = qoqo_calculator_pyo3::convert_into_calculator_complex(value).map_err(|_| PyTypeError::new_err("Value is not CalculatorComplex"))?;
-                    let (index, value) = #struct_ident::create_valid_pair(creators, annihilators, value).map_err(|err| PyValueError::new_err(format!("Valid pair could not be constructed: {:?}", err)))?;
+                    let (index, value) = #struct_ident::create_valid_pair(creators, annihilators, value).map_err(struqture_error_to_py_err)?;
                     Ok((#ident{internal: index}, qoqo_calculator_pyo3::CalculatorComplexWrapper{internal: value}))
                 }
         }
@@ -224,7 +224,7 @@ pub fn productwrapper(
                 /// Raises:
                 ///     ValueError: The two objects could not be concatenated.
                 pub fn concatenate(&self, other: #ident) -> PyResult<#ident> {
-                    let concatenated = self.internal.concatenate(other.internal).map_err(|err| PyValueError::new_err(format!("The two objects could not be concatenated: {:?}", err)))?;
+                    let concatenated = self.internal.concatenate(other.internal).map_err(struqture_error_to_py_err)?;
                     Ok(#ident {
                         internal: concatenated
                     })
@@ -404,10 +404,9 @@ pub fn productwrapper(
 
                 Ok(#ident {
                     internal: bincode::deserialize(&bytes[..]).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized from bytes. {}",
-                            err
-                        ))
+                        struqture_error_to_py_err(struqture::StruqtureError::GenericError {
+                            msg: format!("Input cannot be deserialized from bytes. {}", err),
+                        })
                     })?,
                 })
             }
@@ -456,12 +455,7 @@ pub fn productwrapper(
             #[pyo3(text_signature = "(input)")]
             pub fn from_json(input: String) -> PyResult<#ident> {
                 Ok(#ident {
-                    internal: serde_json::from_str(&input).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized {}",
-                            err
-                        ))
-                    })?,
+                    internal: struqture::from_json(&input).map_err(struqture_error_to_py_err)?,
                 })
             }
 
@@ -479,12 +473,7 @@ pub fn productwrapper(
             #[pyo3(text_signature = "(input)")]
             pub fn from_string(input: String) -> PyResult<#ident> {
                 Ok(#ident {
-                    internal: #struct_ident::from_str(&input).map_err(|err| {
-                        PyValueError::new_err(format!(
-                            "Input cannot be deserialized: {}",
-                            err
-                        ))
-                    })?,
+                    internal: #struct_ident::from_str(&input).map_err(struqture_error_to_py_err)?,
                 })
             }
 
@@ -0,0 +1,97 @@
+"Tests for the struqture exceptions raised in the python interface."
+
+import json
+import pytest
+import struqture_py
+from struqture_py.spins import PauliProduct, SpinSystem
+
+
+def _set_data_version(value, minor_version):
+    if isinstance(value, dict):
+        for key, entry in value.items():
+            if key == "_struqture_version":
+                entry["minor_version"] = minor_version
+            else:
+                _set_data_version(entry, minor_version)
+    elif isinstance(value, list):
+        for entry in value:
+            _set_data_version(entry, minor_version)
+
+
+def test_number_spins_exceeded():
+    system = SpinSystem(2)
+    with pytest.raises(struqture_py.NumberSpinsExceededError) as error:
+        system.set(PauliProduct().z(3), 1.0)
+    assert isinstance(error.value, struqture_py.StruqtureError)
+    assert isinstance(error.value, ValueError)
+
+
+def test_version_mismatch():
+    system = SpinSystem(2)
+    system.set(PauliProduct().z(0), 1.0)
+    data = json.loads(system.to_json())
+    _set_data_version(data, 1000)
+    with pytest.raises(struqture_py.VersionMismatchError) as error:
+        SpinSystem.from_json(json.dumps(data))
+    assert error.value.data_major_version == 1
+    assert error.value.data_minor_version == 1000
+    assert error.value.library_major_version == 1
+    assert isinstance(error.value, struqture_py.StruqtureError)
+
+
+def test_generic_error():
+    with pytest.raises(struqture_py.GenericError) as error:
+        SpinSystem.from_json("not json")
+    assert "Input cannot be deserialized" in error.value.msg
+
+
+def test_add_operator_product_number_spins_exceeded():
+    system = SpinSystem(2)
+    with pytest.raises(struqture_py.NumberSpinsExceededError):
+        system.add_operator_product(PauliProduct().x(3), 1.0)
+
+
+def test_from_string_failed():
+    with pytest.raises(struqture_py.StruqtureError) as error:
+        PauliProduct.from_string("0A")
+    assert isinstance(error.value, ValueError)
+
+
+def test_from_bincode_generic_error():
+    with pytest.raises(struqture_py.GenericError):
+        SpinSystem.from_bincode(bytearray([0, 1]))
+
+
+def test_from_bincode_unchecked():
+    system = SpinSystem(2)
+    system.set(PauliProduct().z(0), 1.0)
+    data = bytearray(system.to_bincode())
+    # The minor version is the last field of the serialized SpinSystem
+    data[-4:] = (1000).to_bytes(4, "little")
+    with pytest.raises(struqture_py.GenericError):
+        SpinSystem.from_bincode(data)
+    assert SpinSystem.from_bincode_unchecked(data) == system
+
+
+def test_from_json_unchecked():
+    system = SpinSystem(2)
+    system.set(PauliProduct().z(0), 1.0)
+    data = json.loads(system.to_json())
+    _set_data_version(data, 1000)
+    with pytest.raises(struqture_py.VersionMismatchError):
+        SpinSystem.from_json(json.dumps(data))
+    assert SpinSystem.from_json_unchecked(json.dumps(data)) == system
+
+
+def test_sparse_matrix_empty_system():
+    system = SpinSystem()
+    with pytest.raises(struqture_py.GenericError):
+        system.sparse_matrix_coo()
+    with pytest.raises(struqture_py.GenericError):
+        system.sparse_matrix_csr()
+    with pytest.raises(struqture_py.GenericError):
+        system.sparse_matrix_superoperator_coo()
+    with pytest.raises(struqture_py.GenericError):
+        system.sparse_matrix_superoperator_csr()
+    (values, (rows, columns)) = system.sparse_matrix_coo(2)
+    assert len(values) == 0
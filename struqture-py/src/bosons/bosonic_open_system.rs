@@ -14,6 +14,7 @@ use super::{
     BosonHamiltonianSystemWrapper, BosonLindbladNoiseSystemWrapper, BosonProductWrapper,
     HermitianBosonProductWrapper,
 };
+use crate::errors::struqture_error_to_py_err;
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
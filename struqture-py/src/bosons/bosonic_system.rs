@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use crate::bosons::BosonProductWrapper;
+use crate::errors::struqture_error_to_py_err;
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
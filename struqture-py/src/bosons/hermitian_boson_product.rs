@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use super::BosonProductWrapper;
+use crate::errors::struqture_error_to_py_err;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
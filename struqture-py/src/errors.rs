@@ -0,0 +1,388 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Python exceptions corresponding to the variants of the struqture StruqtureError.
+//!
+//! Every variant of [struqture::StruqtureError] is mapped to its own Python exception class.
+//! All classes derive from `StruqtureError`, which itself derives from `ValueError`, so existing
+//! code catching a `ValueError` keeps working. The fields of the variants are set as attributes
+//! of the raised exception.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+create_exception!(
+    struqture_py,
+    StruqtureError,
+    PyValueError,
+    "Base class of all errors raised by struqture."
+);
+create_exception!(
+    struqture_py,
+    RemappingFailedError,
+    StruqtureError,
+    "The qubit remapping failed. Attributes: key."
+);
+create_exception!(
+    struqture_py,
+    FromStringFailedError,
+    StruqtureError,
+    "Constructing the object from a string failed. Attributes: msg."
+);
+create_exception!(
+    struqture_py,
+    IncorrectPauliEntryError,
+    StruqtureError,
+    "The Pauli matrix is not in the allowed matrices. Attributes: pauli."
+);
+create_exception!(
+    struqture_py,
+    ProductIndexAlreadyOccupiedError,
+    StruqtureError,
+    "The index of the product is already occupied. Attributes: index."
+);
+create_exception!(
+    struqture_py,
+    OperatorIndexAlreadyOccupiedError,
+    StruqtureError,
+    "The index of the operator is already occupied. Attributes: index."
+);
+create_exception!(
+    struqture_py,
+    NumberSpinsExceededError,
+    StruqtureError,
+    "The index of the key exceeds the number of spins of the system."
+);
+create_exception!(
+    struqture_py,
+    MismatchedNumberSpinsError,
+    StruqtureError,
+    "The number of spins of system and noise do not match."
+);
+create_exception!(
+    struqture_py,
+    MismatchedNumberModesError,
+    StruqtureError,
+    "The number of modes of system and noise do not match."
+);
+create_exception!(
+    struqture_py,
+    MismatchedNumberSubsystemsError,
+    StruqtureError,
+    "The number of subsystems of a mixed system does not match. Attributes: target_number_spin_subsystems, target_number_boson_subsystems, target_number_fermion_subsystems, actual_number_spin_subsystems, actual_number_boson_subsystems, actual_number_fermion_subsystems."
+);
+create_exception!(
+    struqture_py,
+    IndicesNotNormalOrderedError,
+    StruqtureError,
+    "The indices are not normal ordered. Attributes: index_i, index_j."
+);
+create_exception!(
+    struqture_py,
+    IndicesContainDoublesError,
+    StruqtureError,
+    "The creators or annihilators contain a double index specification."
+);
+create_exception!(
+    struqture_py,
+    IncorrectlyOrderedIndicesError,
+    StruqtureError,
+    "The creators or annihilators are not normal ordered or contain a double index specification."
+);
+create_exception!(
+    struqture_py,
+    NumberModesExceededError,
+    StruqtureError,
+    "The index of the key exceeds the number of modes of the system."
+);
+create_exception!(
+    struqture_py,
+    CreatorsAnnihilatorsMinimumIndexError,
+    StruqtureError,
+    "The minimum index of the creators is larger than the minimum index of the annihilators. Attributes: creators_min, annihilators_min."
+);
+create_exception!(
+    struqture_py,
+    NonHermitianOperatorError,
+    StruqtureError,
+    "The key is naturally hermitian but its value is not real."
+);
+create_exception!(
+    struqture_py,
+    ParsingError,
+    StruqtureError,
+    "Parsing a string failed. Attributes: target_type, msg."
+);
+create_exception!(
+    struqture_py,
+    VersionMismatchError,
+    StruqtureError,
+    "The data was created with an incompatible version of struqture. Attributes: library_major_version, library_minor_version, data_major_version, data_minor_version."
+);
+create_exception!(
+    struqture_py,
+    CalculatorError,
+    StruqtureError,
+    "An error occured in qoqo_calculator. Attributes: msg."
+);
+create_exception!(
+    struqture_py,
+    InvalidLindbladTermsError,
+    StruqtureError,
+    "The Lindblad operators are not traceless."
+);
+create_exception!(
+    struqture_py,
+    GenericError,
+    StruqtureError,
+    "Generic error in struqture. Attributes: msg."
+);
+
+/// Converts a StruqtureError into the corresponding Python exception.
+///
+/// The fields of the error variant are set as attributes of the exception.
+///
+/// # Arguments
+///
+/// * `err` - The StruqtureError to convert.
+///
+/// # Returns
+///
+/// * `PyErr` - The Python exception of the class corresponding to the error variant.
+pub fn struqture_error_to_py_err(err: struqture::StruqtureError) -> PyErr {
+    let message = err.to_string();
+    Python::with_gil(|py| {
+        let (py_err, attributes): (PyErr, Vec<(&str, PyObject)>) = match err {
+            struqture::StruqtureError::RemappingFailed { key } => (
+                RemappingFailedError::new_err(message),
+                vec![("key", key.into_py(py))],
+            ),
+            struqture::StruqtureError::FromStringFailed { msg } => (
+                FromStringFailedError::new_err(message),
+                vec![("msg", msg.into_py(py))],
+            ),
+            struqture::StruqtureError::IncorrectPauliEntry { pauli } => (
+                IncorrectPauliEntryError::new_err(message),
+                vec![("pauli", pauli.into_py(py))],
+            ),
+            struqture::StruqtureError::ProductIndexAlreadyOccupied { index } => (
+                ProductIndexAlreadyOccupiedError::new_err(message),
+                vec![("index", index.into_py(py))],
+            ),
+            struqture::StruqtureError::OperatorIndexAlreadyOccupied { index } => (
+                OperatorIndexAlreadyOccupiedError::new_err(message),
+                vec![("index", index.into_py(py))],
+            ),
+            struqture::StruqtureError::NumberSpinsExceeded => {
+                (NumberSpinsExceededError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::MissmatchedNumberSpins => {
+                (MismatchedNumberSpinsError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::MissmatchedNumberModes => {
+                (MismatchedNumberModesError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems,
+                target_number_boson_subsystems,
+                target_number_fermion_subsystems,
+                actual_number_spin_subsystems,
+                actual_number_boson_subsystems,
+                actual_number_fermion_subsystems,
+            } => (
+                MismatchedNumberSubsystemsError::new_err(message),
+                vec![
+                    (
+                        "target_number_spin_subsystems",
+                        target_number_spin_subsystems.into_py(py),
+                    ),
+                    (
+                        "target_number_boson_subsystems",
+                        target_number_boson_subsystems.into_py(py),
+                    ),
+                    (
+                        "target_number_fermion_subsystems",
+                        target_number_fermion_subsystems.into_py(py),
+                    ),
+                    (
+                        "actual_number_spin_subsystems",
+                        actual_number_spin_subsystems.into_py(py),
+                    ),
+                    (
+                        "actual_number_boson_subsystems",
+                        actual_number_boson_subsystems.into_py(py),
+                    ),
+                    (
+                        "actual_number_fermion_subsystems",
+                        actual_number_fermion_subsystems.into_py(py),
+                    ),
+                ],
+            ),
+            struqture::StruqtureError::IndicesNotNormalOrdered { index_i, index_j } => (
+                IndicesNotNormalOrderedError::new_err(message),
+                vec![
+                    ("index_i", index_i.into_py(py)),
+                    ("index_j", index_j.into_py(py)),
+                ],
+            ),
+            struqture::StruqtureError::IndicesContainDoubles => {
+                (IndicesContainDoublesError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::IncorrectlyOrderedIndices => {
+                (IncorrectlyOrderedIndicesError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::NumberModesExceeded => {
+                (NumberModesExceededError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::CreatorsAnnihilatorsMinimumIndex {
+                creators_min,
+                annihilators_min,
+            } => (
+                CreatorsAnnihilatorsMinimumIndexError::new_err(message),
+                vec![
+                    ("creators_min", creators_min.into_py(py)),
+                    ("annihilators_min", annihilators_min.into_py(py)),
+                ],
+            ),
+            struqture::StruqtureError::NonHermitianOperator => {
+                (NonHermitianOperatorError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::ParsingError { target_type, msg } => (
+                ParsingError::new_err(message),
+                vec![
+                    ("target_type", target_type.into_py(py)),
+                    ("msg", msg.into_py(py)),
+                ],
+            ),
+            struqture::StruqtureError::VersionMissmatch {
+                library_major_version,
+                library_minor_version,
+                data_major_version,
+                data_minor_version,
+            } => (
+                VersionMismatchError::new_err(message),
+                vec![
+                    ("library_major_version", library_major_version.into_py(py)),
+                    ("library_minor_version", library_minor_version.into_py(py)),
+                    ("data_major_version", data_major_version.into_py(py)),
+                    ("data_minor_version", data_minor_version.into_py(py)),
+                ],
+            ),
+            struqture::StruqtureError::CalculatorError(calculator_error) => (
+                CalculatorError::new_err(message),
+                vec![("msg", calculator_error.to_string().into_py(py))],
+            ),
+            struqture::StruqtureError::InvalidLindbladTerms => {
+                (InvalidLindbladTermsError::new_err(message), vec![])
+            }
+            struqture::StruqtureError::GenericError { msg } => (
+                GenericError::new_err(message),
+                vec![("msg", msg.into_py(py))],
+            ),
+        };
+        let value = py_err.value_bound(py);
+        for (name, attribute) in attributes {
+            value
+                .setattr(name, attribute)
+                .expect("Internal error: Could not set attribute of Python exception.");
+        }
+        py_err
+    })
+}
+
+/// Registers the struqture exception classes in a Python module.
+///
+/// # Arguments
+///
+/// * `py` - The Python GIL token.
+/// * `module` - The module the exception classes are added to.
+///
+/// # Returns
+///
+/// * `Ok(())` - The exception classes have been added to the module.
+/// * `Err(PyErr)` - Adding an exception class failed.
+pub fn register_exceptions(py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add("StruqtureError", py.get_type_bound::<StruqtureError>())?;
+    module.add(
+        "RemappingFailedError",
+        py.get_type_bound::<RemappingFailedError>(),
+    )?;
+    module.add(
+        "FromStringFailedError",
+        py.get_type_bound::<FromStringFailedError>(),
+    )?;
+    module.add(
+        "IncorrectPauliEntryError",
+        py.get_type_bound::<IncorrectPauliEntryError>(),
+    )?;
+    module.add(
+        "ProductIndexAlreadyOccupiedError",
+        py.get_type_bound::<ProductIndexAlreadyOccupiedError>(),
+    )?;
+    module.add(
+        "OperatorIndexAlreadyOccupiedError",
+        py.get_type_bound::<OperatorIndexAlreadyOccupiedError>(),
+    )?;
+    module.add(
+        "NumberSpinsExceededError",
+        py.get_type_bound::<NumberSpinsExceededError>(),
+    )?;
+    module.add(
+        "MismatchedNumberSpinsError",
+        py.get_type_bound::<MismatchedNumberSpinsError>(),
+    )?;
+    module.add(
+        "MismatchedNumberModesError",
+        py.get_type_bound::<MismatchedNumberModesError>(),
+    )?;
+    module.add(
+        "MismatchedNumberSubsystemsError",
+        py.get_type_bound::<MismatchedNumberSubsystemsError>(),
+    )?;
+    module.add(
+        "IndicesNotNormalOrderedError",
+        py.get_type_bound::<IndicesNotNormalOrderedError>(),
+    )?;
+    module.add(
+        "IndicesContainDoublesError",
+        py.get_type_bound::<IndicesContainDoublesError>(),
+    )?;
+    module.add(
+        "IncorrectlyOrderedIndicesError",
+        py.get_type_bound::<IncorrectlyOrderedIndicesError>(),
+    )?;
+    module.add(
+        "NumberModesExceededError",
+        py.get_type_bound::<NumberModesExceededError>(),
+    )?;
+    module.add(
+        "CreatorsAnnihilatorsMinimumIndexError",
+        py.get_type_bound::<CreatorsAnnihilatorsMinimumIndexError>(),
+    )?;
+    module.add(
+        "NonHermitianOperatorError",
+        py.get_type_bound::<NonHermitianOperatorError>(),
+    )?;
+    module.add("ParsingError", py.get_type_bound::<ParsingError>())?;
+    module.add(
+        "VersionMismatchError",
+        py.get_type_bound::<VersionMismatchError>(),
+    )?;
+    module.add("CalculatorError", py.get_type_bound::<CalculatorError>())?;
+    module.add(
+        "InvalidLindbladTermsError",
+        py.get_type_bound::<InvalidLindbladTermsError>(),
+    )?;
+    module.add("GenericError", py.get_type_bound::<GenericError>())?;
+    Ok(())
+}
@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use super::FermionSystemWrapper;
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::HermitianFermionProductWrapper;
 use crate::spins::SpinHamiltonianSystemWrapper;
 use bincode::deserialize;
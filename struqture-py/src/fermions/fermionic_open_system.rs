@@ -14,6 +14,7 @@ use super::{
     FermionHamiltonianSystemWrapper, FermionLindbladNoiseSystemWrapper, FermionProductWrapper,
     HermitianFermionProductWrapper,
 };
+use crate::errors::struqture_error_to_py_err;
 use crate::spins::SpinLindbladOpenSystemWrapper;
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
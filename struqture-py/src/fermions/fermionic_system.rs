@@ -10,6 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionProductWrapper;
 use crate::spins::SpinSystemWrapper;
 use bincode::deserialize;
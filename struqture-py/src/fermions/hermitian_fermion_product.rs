@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use super::FermionProductWrapper;
+use crate::errors::struqture_error_to_py_err;
 use crate::spins::SpinHamiltonianSystemWrapper;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
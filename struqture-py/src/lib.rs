@@ -15,6 +15,7 @@ use pyo3::types::PyDict;
 use pyo3::wrap_pymodule;
 
 pub mod bosons;
+pub mod errors;
 pub mod fermions;
 pub mod mixed_systems;
 pub mod spins;
@@ -68,12 +69,14 @@ fn struqture_py(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
         module.getattr("mixed_systems")?,
     )?;
     system_modules.set_item("struqture_py.bosons", module.getattr("bosons")?)?;
+
+    errors::register_exceptions(_py, module)?;
     Ok(())
 }
 
 use num_complex::Complex64;
 use numpy::{IntoPyArray, PyArray1};
-use struqture::CooSparseMatrix;
+use struqture::{CooSparseMatrix, CsrSparseMatrix};
 // use pyo3::prelude::*;
 
 pub type PyCooMatrix = (
@@ -91,3 +94,20 @@ fn to_py_coo(coo: CooSparseMatrix) -> PyResult<PyCooMatrix> {
         Ok((values, (rows, columns)))
     })
 }
+
+pub type PyCsrMatrix = (
+    Py<PyArray1<Complex64>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+);
+
+// Simple wrapper function to convert internal CSR (or CSC) matrix to a Python compatible form,
+// it expects a CsrSparseMatrix so any error handling should be done before using it.
+fn to_py_csr(csr: CsrSparseMatrix) -> PyResult<PyCsrMatrix> {
+    Python::with_gil(|py| -> PyResult<PyCsrMatrix> {
+        let values: Py<PyArray1<Complex64>> = csr.0.into_pyarray_bound(py).into();
+        let indices: Py<PyArray1<usize>> = csr.1.into_pyarray_bound(py).into();
+        let pointers: Py<PyArray1<usize>> = csr.2.into_pyarray_bound(py).into();
+        Ok((values, indices, pointers))
+    })
+}
@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use super::MixedSystemWrapper;
+use crate::errors::struqture_error_to_py_err;
 use crate::mixed_systems::HermitianMixedProductWrapper;
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -12,6 +12,7 @@
 
 use super::MixedProductWrapper;
 use crate::bosons::*;
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::*;
 use crate::spins::*;
 use num_complex::Complex64;
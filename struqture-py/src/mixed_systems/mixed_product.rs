@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use crate::bosons::*;
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::*;
 use crate::spins::*;
 use num_complex::Complex64;
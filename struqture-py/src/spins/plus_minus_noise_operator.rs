@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use super::SpinLindbladNoiseSystemWrapper;
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionLindbladNoiseSystemWrapper;
 use crate::spins::PlusMinusProductWrapper;
 use bincode::deserialize;
@@ -10,6 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionSystemWrapper;
 use crate::spins::{PlusMinusProductWrapper, SpinSystemWrapper};
 use bincode::deserialize;
@@ -11,9 +11,10 @@
 // limitations under the License.
 
 use super::SpinSystemWrapper;
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionHamiltonianSystemWrapper;
 use crate::spins::PauliProductWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{to_py_coo, to_py_csr, PyCooMatrix, PyCsrMatrix};
 use bincode::deserialize;
 use num_complex::Complex64;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
@@ -10,9 +10,10 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionLindbladNoiseSystemWrapper;
 use crate::spins::DecoherenceProductWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{to_py_coo, to_py_csr, PyCooMatrix, PyCsrMatrix};
 use bincode::deserialize;
 use num_complex::Complex64;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
@@ -12,8 +12,9 @@
 
 use super::{DecoherenceProductWrapper, PauliProductWrapper};
 use super::{SpinHamiltonianSystemWrapper, SpinLindbladNoiseSystemWrapper};
+use crate::errors::struqture_error_to_py_err;
 use crate::fermions::FermionLindbladOpenSystemWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{to_py_coo, to_py_csr, PyCooMatrix, PyCsrMatrix};
 use bincode::deserialize;
 use num_complex::Complex64;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
@@ -24,7 +25,7 @@ use struqture::mappings::JordanWignerSpinToFermion;
 use struqture::spins::{OperateOnSpins, SpinLindbladOpenSystem, ToSparseMatrixSuperOperator};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
-use struqture::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use struqture::{OpenSystem, OperateOnDensityMatrix};
 use struqture_py_macros::{mappings, noisy_system_wrapper};
 
 /// These are representations of noisy systems of spins.
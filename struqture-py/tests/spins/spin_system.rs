@@ -787,6 +787,80 @@ fn test_to_from_json() {
     });
 }
 
+/// Sets every struqture version in the json data to a newer minor version than the library version
+fn bump_struqture_versions(value: &mut serde_json::Value, major_version: u32, minor_version: u32) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, entry) in map.iter_mut() {
+                if key == "_struqture_version" {
+                    entry["major_version"] = major_version.into();
+                    entry["minor_version"] = (minor_version + 1).into();
+                } else {
+                    bump_struqture_versions(entry, major_version, minor_version);
+                }
+            }
+        }
+        serde_json::Value::Array(entries) => {
+            for entry in entries.iter_mut() {
+                bump_struqture_versions(entry, major_version, minor_version);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Test the version checked and unchecked from_json and from_bincode functions of SpinSystem
+#[test]
+fn test_from_json_bincode_unchecked() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let mut version = struqture::STRUQTURE_VERSION.split('.');
+        let major_version: u32 = version.next().unwrap().parse().unwrap();
+        let minor_version: u32 = version.next().unwrap().parse().unwrap();
+
+        let number_spins: Option<usize> = None;
+        let system = new_system(py, number_spins);
+        system
+            .call_method1("add_operator_product", ("0X", 0.1))
+            .unwrap();
+        let new = new_system(py, number_spins);
+
+        let serialised: String = system.call_method0("to_json").unwrap().extract().unwrap();
+        let mut bumped: serde_json::Value = serde_json::from_str(&serialised).unwrap();
+        bump_struqture_versions(&mut bumped, major_version, minor_version);
+        let bumped = bumped.to_string();
+
+        let deserialised = new.call_method1("from_json", (&serialised,)).unwrap();
+        let comparison =
+            bool::extract_bound(&deserialised.call_method1("__eq__", (&system,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(new.call_method1("from_json", (&bumped,)).is_err());
+        let unchecked = new.call_method1("from_json_unchecked", (&bumped,)).unwrap();
+        let comparison =
+            bool::extract_bound(&unchecked.call_method1("__eq__", (&system,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(new.call_method1("from_json_unchecked", ("fails",)).is_err());
+
+        let serialised = system.call_method0("to_bincode").unwrap();
+        let deserialised = new.call_method1("from_bincode", (&serialised,)).unwrap();
+        let comparison =
+            bool::extract_bound(&deserialised.call_method1("__eq__", (&system,)).unwrap()).unwrap();
+        assert!(comparison);
+        let unchecked = new
+            .call_method1("from_bincode_unchecked", (&serialised,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&unchecked.call_method1("__eq__", (&system,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(new
+            .call_method1(
+                "from_bincode_unchecked",
+                (bincode::serialize("fails").unwrap(),)
+            )
+            .is_err());
+    });
+}
+
 /// Test the __repr__ and __format__ functions
 #[test]
 fn test_format_repr() {
@@ -10,10 +10,13 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonOperator, BosonProduct, HermitianBosonProduct, ModeIndex, OperateOnBosons};
+use super::{
+    BosonOperator, BosonProduct, HermitianBosonProduct, ModeIndex, OperateOnBosons,
+    ToSparseMatrixBosonOperator,
+};
 use crate::{
-    GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
@@ -264,6 +267,13 @@ impl OperateOnModes<'_> for BosonHamiltonian {
 
 impl OperateOnBosons<'_> for BosonHamiltonian {}
 
+impl ToSparseMatrixBosonOperator for BosonHamiltonian {
+    // From trait
+    fn sparse_matrix_coo(&self, cutoffs: &[usize]) -> Result<CooSparseMatrix, StruqtureError> {
+        BosonOperator::from(self.clone()).sparse_matrix_coo(cutoffs)
+    }
+}
+
 /// Implements the default function (Default trait) of BosonHamiltonian (an empty BosonHamiltonian).
 ///
 impl Default for BosonHamiltonian {
@@ -307,6 +317,34 @@ impl BosonHamiltonian {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the BosonHamiltonian.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the BosonHamiltonian while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Returns the LaTeX representation of the BosonHamiltonian as a sum of monomials.
+    ///
+    /// The terms are sorted by their index. Creators are rendered as `\hat{a}^\dagger_{i}`,
+    /// annihilators as `\hat{a}_{j}` and the identity as `\mathbb{1}`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The LaTeX representation of the BosonHamiltonian.
+    pub fn to_latex(&self) -> String {
+        BosonOperator::from(self.clone()).to_latex()
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -331,6 +369,111 @@ impl BosonHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Creates the BosonHamiltonian of a driven harmonic oscillator.
+    ///
+    /// The Hamiltonian is given by `frequency * c_mode a_mode + drive * (c_mode + a_mode)`.
+    /// Both parameters are CalculatorFloat values so the resulting coefficients are always real,
+    /// which is required for the Hamiltonian to be hermitian.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The bosonic mode of the oscillator.
+    /// * `frequency` - The frequency of the oscillator (prefactor of the number operator).
+    /// * `drive` - The strength of the drive (prefactor of the displacement term).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonian of the driven oscillator.
+    /// * `Err(StruqtureError)` - Error creating the HermitianBosonProducts or setting the terms.
+    pub fn driven_oscillator(
+        mode: usize,
+        frequency: CalculatorFloat,
+        drive: CalculatorFloat,
+    ) -> Result<Self, StruqtureError> {
+        let mut hamiltonian = Self::with_capacity(2);
+        hamiltonian.add_operator_product(
+            HermitianBosonProduct::new([mode], [mode])?,
+            CalculatorComplex::from(frequency),
+        )?;
+        hamiltonian.add_operator_product(
+            HermitianBosonProduct::new([], [mode])?,
+            CalculatorComplex::from(drive),
+        )?;
+        Ok(hamiltonian)
+    }
+
+    /// Returns the commutator `[self, other] = self * other - other * self` of two BosonHamiltonians.
+    ///
+    /// The HermitianBosonProducts are expanded into BosonProducts and their hermitian conjugates when multiplied,
+    /// so that, like the product of two BosonHamiltonians, the result is a BosonOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonHamiltonian to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The commutator of the two BosonHamiltonians.
+    pub fn commutator(&self, other: &Self) -> BosonOperator {
+        (self.clone() * other.clone()) - (other.clone() * self.clone())
+    }
+
+    /// Returns the anticommutator `{self, other} = self * other + other * self` of two BosonHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonHamiltonian to anticommute with.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The anticommutator of the two BosonHamiltonians.
+    pub fn anticommutator(&self, other: &Self) -> BosonOperator {
+        (self.clone() * other.clone()) + (other.clone() * self.clone())
+    }
+
+    /// Writes the BosonHamiltonian into an HDF5 group.
+    ///
+    /// The HermitianBosonProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonHamiltonian to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonHamiltonian has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a BosonHamiltonian from an HDF5 group written with [BosonHamiltonian::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonHamiltonian from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonian stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a HermitianBosonProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(HermitianBosonProduct, CalculatorComplex)> =
+            crate::hdf5_io::read_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 impl TryFrom<BosonOperator> for BosonHamiltonian {
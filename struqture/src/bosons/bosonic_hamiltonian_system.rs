@@ -10,8 +10,13 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonHamiltonian, BosonSystem, HermitianBosonProduct, ModeIndex, OperateOnBosons};
-use crate::{OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use super::{
+    BosonHamiltonian, BosonSystem, HermitianBosonProduct, ModeIndex, OperateOnBosons,
+    ToSparseMatrixBosonOperator,
+};
+use crate::{
+    CooSparseMatrix, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -197,6 +202,16 @@ impl OperateOnModes<'_> for BosonHamiltonianSystem {
 
 impl OperateOnBosons<'_> for BosonHamiltonianSystem {}
 
+impl ToSparseMatrixBosonOperator for BosonHamiltonianSystem {
+    // From trait
+    fn sparse_matrix_coo(&self, cutoffs: &[usize]) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.number_modes() > cutoffs.len() {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        self.hamiltonian.sparse_matrix_coo(cutoffs)
+    }
+}
+
 /// Functions for the BosonHamiltonianSystem
 ///
 impl BosonHamiltonianSystem {
@@ -233,6 +248,13 @@ impl BosonHamiltonianSystem {
         }
     }
 
+    /// Removes all terms from the BosonHamiltonianSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.hamiltonian.clear();
+    }
+
     /// Returns the BosonHamiltonian of the BosonHamiltonianSystem.
     ///
     /// # Returns
@@ -299,6 +321,46 @@ impl BosonHamiltonianSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the BosonHamiltonianSystem into an HDF5 group.
+    ///
+    /// The hamiltonian of the BosonHamiltonianSystem is written with [BosonHamiltonian::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the BosonHamiltonianSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonHamiltonianSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonHamiltonianSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.hamiltonian.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a BosonHamiltonianSystem from an HDF5 group written with [BosonHamiltonianSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonHamiltonianSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonianSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The hamiltonian could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_hamiltonian(
+            BosonHamiltonian::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of BosonHamiltonianSystem.
@@ -16,7 +16,7 @@ use crate::{
 };
 use qoqo_calculator::CalculatorComplex;
 use serde::{
-    de::{Error, SeqAccess, Visitor},
+    de::{Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeTuple,
     Deserialize, Deserializer, Serialize, Serializer,
 };
@@ -48,7 +48,35 @@ pub struct BosonProduct {
     annihilators: TinyVec<[usize; 2]>,
 }
 
-#[cfg(feature = "json_schema")]
+/// Compact representation of a BosonProduct by integer arrays of creators and annihilators.
+///
+/// Used for human-readable serialization when the `compact_keys` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct BosonProductCompact {
+    /// The ordered list of creator indices.
+    c: Vec<usize>,
+    /// The ordered list of annihilator indices.
+    a: Vec<usize>,
+}
+
+#[cfg(all(feature = "json_schema", feature = "compact_keys"))]
+impl schemars::JsonSchema for BosonProduct {
+    fn schema_name() -> String {
+        "BosonProduct".to_string()
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let tmp_schema = gen.subschema_for::<BosonProductCompact>();
+        let mut obj = tmp_schema.into_object();
+        let meta = obj.metadata();
+        meta.description = Some("Represents products of Bosonic creators and annhilators by the integer arrays of the modes the creators (c) and annihilators (a) are acting on. E.g. {\"c\": [0], \"a\": [1]}.".to_string());
+
+        schemars::schema::Schema::Object(obj)
+    }
+}
+
+#[cfg(all(feature = "json_schema", not(feature = "compact_keys")))]
 impl schemars::JsonSchema for BosonProduct {
     fn schema_name() -> String {
         "BosonProduct".to_string()
@@ -63,13 +91,79 @@ impl schemars::JsonSchema for BosonProduct {
     }
 }
 
-impl crate::MinSupportedVersion for BosonProduct {}
+impl crate::MinSupportedVersion for BosonProduct {
+    /// Returns the minimum version of struqture required to deserialize this BosonProduct.
+    ///
+    /// With the `compact_keys` feature the human-readable serialization uses the compact
+    /// `{"c": [...], "a": [...]}` representation, which can only be read from struqture 1.10 on.
+    ///
+    /// # Returns
+    /// (major_version, minor_version, patch_version)
+    fn min_supported_version_of(&self) -> (usize, usize, usize) {
+        if cfg!(feature = "compact_keys") {
+            (1, 10, 0)
+        } else {
+            Self::min_supported_version()
+        }
+    }
+}
+
+/// Functions for the BosonProduct
+///
+impl BosonProduct {
+    /// Returns whether the BosonProduct is normal ordered.
+    ///
+    /// The BosonProduct is normal ordered when all creators precede all annihilators (which is
+    /// guaranteed by the representation) and both the creator and annihilator indices are
+    /// sorted in ascending order (bosonic indices can appear multiple times).
+    /// All constructors and the deserialization of BosonProduct uphold this invariant,
+    /// this function can be used to assert it before running algorithms that rely on it.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the BosonProduct is normal ordered.
+    pub fn is_normal_ordered(&self) -> bool {
+        self.creators.windows(2).all(|w| w[0] <= w[1])
+            && self.annihilators.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    /// Returns whether the BosonProduct is guaranteed to commute with another BosonProduct.
+    ///
+    /// Bosonic operators only fail to commute when a creator of one product acts on the same mode
+    /// as an annihilator of the other product. If no such shared mode exists (or both products are
+    /// identical) the products commute. Otherwise the products are considered non-commuting,
+    /// even though special cases (e.g. products of number operators) can still commute.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonProduct to compare with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two BosonProducts commute.
+    pub fn commutes_with(&self, other: &BosonProduct) -> bool {
+        if self == other {
+            return true;
+        }
+        !self
+            .creators
+            .iter()
+            .any(|creator| other.annihilators.contains(creator))
+            && !other
+                .creators
+                .iter()
+                .any(|creator| self.annihilators.contains(creator))
+    }
+}
 
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for BosonProduct {
     /// Serialization function for BosonProduct according to string type.
     ///
+    /// With the `compact_keys` feature the human-readable serialization uses integer arrays
+    /// of the form `{"c": [...], "a": [...]}` instead of the string representation.
+    ///
     /// # Arguments
     ///
     /// * `self` - BosonProduct to be serialized.
@@ -85,7 +179,18 @@ impl Serialize for BosonProduct {
     {
         let readable = serializer.is_human_readable();
         if readable {
-            serializer.serialize_str(&self.to_string())
+            #[cfg(feature = "compact_keys")]
+            {
+                BosonProductCompact {
+                    c: self.creators.to_vec(),
+                    a: self.annihilators.to_vec(),
+                }
+                .serialize(serializer)
+            }
+            #[cfg(not(feature = "compact_keys"))]
+            {
+                serializer.serialize_str(&self.to_string())
+            }
         } else {
             let mut tuple = serializer.serialize_tuple(2)?;
             tuple.serialize_element(&self.creators)?;
@@ -100,6 +205,9 @@ impl Serialize for BosonProduct {
 impl<'de> Deserialize<'de> for BosonProduct {
     /// Deserialization function for BosonProduct.
     ///
+    /// The human-readable deserialization accepts both the string representation
+    /// and the compact `{"c": [...], "a": [...]}` representation.
+    ///
     /// # Arguments
     ///
     /// * `self` - Serialized instance of BosonProduct to be deserialized.
@@ -120,7 +228,7 @@ impl<'de> Deserialize<'de> for BosonProduct {
                 type Value = BosonProduct;
 
                 fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                    formatter.write_str("String")
+                    formatter.write_str("String or map of creators (c) and annihilators (a)")
                 }
 
                 fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -136,9 +244,19 @@ impl<'de> Deserialize<'de> for BosonProduct {
                 {
                     BosonProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
                 }
+
+                fn visit_map<M>(self, access: M) -> Result<Self::Value, M::Error>
+                where
+                    M: MapAccess<'de>,
+                {
+                    let compact = BosonProductCompact::deserialize(
+                        serde::de::value::MapAccessDeserializer::new(access),
+                    )?;
+                    BosonProduct::new(compact.c, compact.a).map_err(M::Error::custom)
+                }
             }
 
-            deserializer.deserialize_str(TemporaryVisitor)
+            deserializer.deserialize_any(TemporaryVisitor)
         } else {
             struct BosonProductVisitor;
             impl<'de> serde::de::Visitor<'de> for BosonProductVisitor {
@@ -570,6 +688,41 @@ impl schemars::JsonSchema for HermitianBosonProduct {
 
 impl crate::MinSupportedVersion for HermitianBosonProduct {}
 
+/// Functions for the HermitianBosonProduct
+///
+impl HermitianBosonProduct {
+    /// Creates a new HermitianBosonProduct, swapping creators and annihilators if necessary.
+    ///
+    /// A HermitianBosonProduct represents a product and its hermitian conjugate, where the minimum
+    /// index of the creators must not exceed the minimum index of the annihilators.
+    /// If the input violates this condition, the hermitian conjugate (creators and annihilators swapped)
+    /// is constructed instead, which represents the same HermitianBosonProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The creator indices to have in the HermitianBosonProduct.
+    /// * `annihilators` - The annihilators indices to have in the HermitianBosonProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((HermitianBosonProduct, bool))` - The new HermitianBosonProduct and whether creators and annihilators were swapped.
+    /// * `Err(StruqtureError)` - The HermitianBosonProduct could not be constructed.
+    pub fn new_or_conjugate(
+        creators: impl IntoIterator<Item = usize>,
+        annihilators: impl IntoIterator<Item = usize>,
+    ) -> Result<(Self, bool), StruqtureError> {
+        let creators: TinyVec<[usize; 2]> = creators.into_iter().collect();
+        let annihilators: TinyVec<[usize; 2]> = annihilators.into_iter().collect();
+        match Self::new(creators.clone(), annihilators.clone()) {
+            Ok(product) => Ok((product, false)),
+            Err(StruqtureError::CreatorsAnnihilatorsMinimumIndex { .. }) => {
+                Ok((Self::new(annihilators, creators)?, true))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for HermitianBosonProduct {
@@ -10,11 +10,16 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonProduct, OperateOnBosons};
+use super::{
+    boson_fock_space_dimension, boson_product_matrix_entries, check_fock_space_cutoffs,
+    BosonProduct, OperateOnBosons,
+};
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    add_lindblad_superoperator_entries, sorted_coo_from_entries, CooSparseMatrix, ModeIndex,
+    OperateOnDensityMatrix, OperateOnModes, StruqtureError, StruqtureVersionSerializable,
+    MINIMUM_STRUQTURE_VERSION,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -283,6 +288,22 @@ impl BosonLindbladNoiseOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the BosonLindbladNoiseOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the BosonLindbladNoiseOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -313,6 +334,90 @@ impl BosonLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Constructs the sparse matrix representation of the Lindblad superoperator in a truncated Fock space in COO format.
+    ///
+    /// Each term `((L, R), M)` contributes `M (L p R^dagger - 1/2 {R^dagger L, p})` to the time derivative of the density matrix `p`.
+    /// The superoperator S is defined so that `flatten(dp/dt) = S flatten(p)`, where `flatten` flattens a matrix into
+    /// a vector in row-major form. See [crate::bosons::ToSparseMatrixBosonOperator] for the conventions of the Fock space.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the Lindblad superoperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The BosonLindbladNoiseOperator acts on more modes than cutoffs are given.
+    /// * `Err(StruqtureError::GenericError)` - A cutoff is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_superoperator_coo(
+        &self,
+        cutoffs: &[usize],
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        check_fock_space_cutoffs(self.current_number_modes(), cutoffs)?;
+        let dimension = boson_fock_space_dimension(cutoffs)?;
+        // The superoperator acts on the space of density matrices with dimension^2 entries
+        boson_fock_space_dimension(&[dimension, dimension])?;
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for ((left, right), value) in self.iter() {
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            add_lindblad_superoperator_entries(
+                &mut entries,
+                &boson_product_matrix_entries(left, cutoffs)?,
+                &boson_product_matrix_entries(right, cutoffs)?,
+                Complex64::new(real, imaginary),
+                dimension,
+            );
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
+    /// Writes the BosonLindbladNoiseOperator into an HDF5 group.
+    ///
+    /// The left and right BosonProducts of the sorted terms are stored as strings in the `left_keys` and `right_keys`
+    /// datasets, the real and imaginary parts of the coefficients in the `real` and `imag` datasets.
+    /// The minimum struqture version required to read the data is stored in the `struqture_major_version`
+    /// and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonLindbladNoiseOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonLindbladNoiseOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_noise_terms(group, self.iter())
+    }
+
+    /// Reads a BosonLindbladNoiseOperator from an HDF5 group written with [BosonLindbladNoiseOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonLindbladNoiseOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladNoiseOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a BosonProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<((BosonProduct, BosonProduct), CalculatorComplex)> =
+            crate::hdf5_io::read_noise_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 /// Implements the negative sign function of BosonLindbladNoiseOperator.
@@ -613,6 +718,7 @@ mod test {
 
     /// Test SpinOperator Serialization and Deserialization traits (readable)
     #[test]
+    #[cfg(not(feature = "compact_keys"))]
     fn serde_readable() {
         let pp: BosonProduct = BosonProduct::new([0], [0]).unwrap();
         let sos = BosonLindbladNoiseOperatorSerialize {
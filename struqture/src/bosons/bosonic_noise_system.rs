@@ -227,6 +227,13 @@ impl BosonLindbladNoiseSystem {
         }
     }
 
+    /// Removes all terms from the BosonLindbladNoiseSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the BosonLindbladNoiseOperator of the BosonLindbladNoiseSystem.
     ///
     /// # Returns
@@ -299,6 +306,46 @@ impl BosonLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the BosonLindbladNoiseSystem into an HDF5 group.
+    ///
+    /// The operator of the BosonLindbladNoiseSystem is written with [BosonLindbladNoiseOperator::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the BosonLindbladNoiseSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonLindbladNoiseSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonLindbladNoiseSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a BosonLindbladNoiseSystem from an HDF5 group written with [BosonLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonLindbladNoiseSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladNoiseSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            BosonLindbladNoiseOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of BosonLindbladNoiseSystem.
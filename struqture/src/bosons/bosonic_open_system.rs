@@ -10,8 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonHamiltonianSystem, BosonLindbladNoiseSystem};
-use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use super::{BosonHamiltonianSystem, BosonLindbladNoiseSystem, ToSparseMatrixBosonOperator};
+use crate::{
+    sorted_coo_from_entries, CooSparseMatrix, OpenSystem, OperateOnDensityMatrix, OperateOnModes,
+    StruqtureError,
+};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -170,6 +174,88 @@ impl BosonLindbladOpenSystem {
             noise: BosonLindbladNoiseSystem::new(number_modes),
         }
     }
+
+    /// Constructs the sparse matrix representation of the Lindblad superoperator in a truncated Fock space in COO format.
+    ///
+    /// The superoperator S is defined so that `flatten(dp/dt) = S flatten(p)` for the Lindblad equation
+    /// `dp/dt = -i [H, p] + sum_ij M_ij (L_i p L_j^dagger - 1/2 {L_j^dagger L_i, p})`, where `flatten` flattens
+    /// a matrix into a vector in row-major form. See [crate::bosons::ToSparseMatrixBosonOperator] for the conventions of the Fock space.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the Lindblad superoperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The BosonLindbladOpenSystem has more modes than cutoffs are given.
+    /// * `Err(StruqtureError::GenericError)` - A cutoff is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_superoperator_coo(
+        &self,
+        cutoffs: &[usize],
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.number_modes() > cutoffs.len() {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for (values, (rows, columns)) in [
+            self.system.sparse_matrix_superoperator_coo(cutoffs)?,
+            self.noise
+                .operator()
+                .sparse_matrix_superoperator_coo(cutoffs)?,
+        ] {
+            for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+                *entries
+                    .entry((row, column))
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += value;
+            }
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
+    /// Writes the BosonLindbladOpenSystem into an HDF5 group.
+    ///
+    /// The system and noise parts are written into the subgroups `system` and `noise` with
+    /// [BosonHamiltonianSystem::to_hdf5] and [BosonLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonLindbladOpenSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonLindbladOpenSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::create_open_system_groups(group)?;
+        self.system.to_hdf5(&system_group)?;
+        self.noise.to_hdf5(&noise_group)
+    }
+
+    /// Reads a BosonLindbladOpenSystem from an HDF5 group written with [BosonLindbladOpenSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonLindbladOpenSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladOpenSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The system or noise could not be read or do not have matching sizes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::open_system_groups(group)?;
+        Self::group(
+            BosonHamiltonianSystem::from_hdf5(&system_group)?,
+            BosonLindbladNoiseSystem::from_hdf5(&noise_group)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of BosonLindbladOpenSystem.
@@ -10,9 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{BosonOperator, OperateOnBosons};
+use super::{BosonOperator, OperateOnBosons, ToSparseMatrixBosonOperator};
 use crate::bosons::BosonProduct;
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use crate::{
+    CooSparseMatrix, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState,
+    StruqtureError,
+};
 use qoqo_calculator::CalculatorComplex;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -189,11 +192,25 @@ impl<'a> OperateOnModes<'a> for BosonSystem {
 
 impl OperateOnBosons<'_> for BosonSystem {}
 
+impl ToSparseMatrixBosonOperator for BosonSystem {
+    // From trait
+    fn sparse_matrix_coo(&self, cutoffs: &[usize]) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.number_modes() > cutoffs.len() {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        self.operator.sparse_matrix_coo(cutoffs)
+    }
+}
+
 /// Functions for the BosonSystem
 ///
 impl BosonSystem {
     /// Creates a new BosonSystem.
     ///
+    /// With `Some(number_modes)` the BosonSystem is bounded: setting or adding a BosonProduct that acts on a mode
+    /// with an index of `number_modes` or larger fails with `StruqtureError::NumberModesExceeded`.
+    /// With `None` the BosonSystem accepts BosonProducts acting on arbitrary modes.
+    ///
     /// # Arguments
     ///
     /// * `number_modes` - The number of modes of the BosonSystem.
@@ -225,6 +242,13 @@ impl BosonSystem {
         }
     }
 
+    /// Removes all terms from the BosonSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the BosonOperator of the BosonSystem.
     ///
     /// # Returns
@@ -291,6 +315,46 @@ impl BosonSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the BosonSystem into an HDF5 group.
+    ///
+    /// The operator of the BosonSystem is written with [BosonOperator::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the BosonSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the BosonSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The BosonSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a BosonSystem from an HDF5 group written with [BosonSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the BosonSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            BosonOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of BosonSystem.
@@ -377,7 +441,7 @@ where
     ///
     /// * `Self` - The BosonSystem multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(mut self, other: T) -> Self {
-        self.operator = self.operator * other;
+        self.operator *= other;
         self
     }
 }
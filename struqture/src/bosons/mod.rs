@@ -37,9 +37,14 @@ pub use bosonic_open_system::BosonLindbladOpenSystem;
 pub use bosonic_operator::BosonOperator;
 pub use bosonic_system::BosonSystem;
 
-use crate::{ModeIndex, OperateOnDensityMatrix, SymmetricIndex};
+use crate::{
+    add_commutator_superoperator_entries, sorted_coo_from_entries, CooSparseMatrix, ModeIndex,
+    OperateOnDensityMatrix, StruqtureError, SymmetricIndex,
+};
 pub use bosonic_indices::{BosonProduct, HermitianBosonProduct};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
 
 /// Signal Trait for specifying that a type can be used a bosonic index.
 ///
@@ -94,3 +99,207 @@ where
     &'a Self: IntoIterator,
 {
 }
+
+/// Returns the dimension of the Fock space truncated to `cutoffs[k]` occupation numbers for mode `k`.
+///
+/// # Arguments
+///
+/// * `cutoffs` - The number of occupation numbers of each mode.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension `prod_k cutoffs[k]`.
+/// * `Err(StruqtureError::GenericError)` - The dimension does not fit into usize.
+pub(crate) fn boson_fock_space_dimension(cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+    cutoffs
+        .iter()
+        .try_fold(1usize, |dimension, cutoff| dimension.checked_mul(*cutoff))
+        .ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "The Fock space with the cutoffs {:?} cannot be represented, its dimension exceeds {}.",
+                cutoffs,
+                usize::MAX
+            ),
+        })
+}
+
+/// Computes the non-zero matrix entries of a BosonProduct in a Fock space truncated to `cutoffs[k]` occupation numbers for mode `k`.
+///
+/// The basis state index is `sum_k n_k * prod_{j<k} cutoffs[j]` for the occupation numbers `n_k`.
+/// States that would be created with an occupation number at or above the cutoff are discarded.
+/// The number of cutoffs must be at least the number of modes the product acts on and all cutoffs must be non-zero.
+///
+/// # Arguments
+///
+/// * `product` - The BosonProduct for which to compute the matrix entries.
+/// * `cutoffs` - The number of occupation numbers of each mode.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(usize, usize, f64)>)` - The (row, column, value) entries of the matrix.
+/// * `Err(StruqtureError::GenericError)` - The dimension of the Fock space does not fit into usize.
+pub(crate) fn boson_product_matrix_entries(
+    product: &BosonProduct,
+    cutoffs: &[usize],
+) -> Result<Vec<(usize, usize, f64)>, StruqtureError> {
+    // The strides are the partial products of the cutoffs, so they cannot overflow if the dimension does not
+    let dimension = boson_fock_space_dimension(cutoffs)?;
+    let strides: Vec<usize> = cutoffs
+        .iter()
+        .scan(1usize, |stride, cutoff| {
+            let current = *stride;
+            *stride *= cutoff;
+            Some(current)
+        })
+        .collect();
+    let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+    'columns: for column in 0..dimension {
+        let mut occupations: Vec<usize> = strides
+            .iter()
+            .zip(cutoffs.iter())
+            .map(|(stride, cutoff)| (column / stride) % cutoff)
+            .collect();
+        let mut amplitude = 1.0;
+        // The product is normal ordered, the annihilators act first
+        for annihilator in product.annihilators() {
+            if occupations[*annihilator] == 0 {
+                continue 'columns;
+            }
+            amplitude *= (occupations[*annihilator] as f64).sqrt();
+            occupations[*annihilator] -= 1;
+        }
+        for creator in product.creators() {
+            if occupations[*creator] + 1 >= cutoffs[*creator] {
+                continue 'columns;
+            }
+            occupations[*creator] += 1;
+            amplitude *= (occupations[*creator] as f64).sqrt();
+        }
+        let row = occupations
+            .iter()
+            .zip(strides.iter())
+            .map(|(occupation, stride)| occupation * stride)
+            .sum();
+        entries.push((row, column, amplitude));
+    }
+    Ok(entries)
+}
+
+/// Checks that the Fock space cutoffs can be used for an object acting on `current_number_modes` modes.
+///
+/// # Arguments
+///
+/// * `current_number_modes` - The number of modes the object acts on.
+/// * `cutoffs` - The number of occupation numbers of each mode.
+///
+/// # Returns
+///
+/// * `Ok(())` - The cutoffs are valid.
+/// * `Err(StruqtureError::NumberModesExceeded)` - The object acts on more modes than cutoffs are given.
+/// * `Err(StruqtureError::GenericError)` - A cutoff is zero or the dimension of the Fock space does not fit into usize.
+pub(crate) fn check_fock_space_cutoffs(
+    current_number_modes: usize,
+    cutoffs: &[usize],
+) -> Result<(), StruqtureError> {
+    if current_number_modes > cutoffs.len() {
+        return Err(StruqtureError::NumberModesExceeded);
+    }
+    if cutoffs.contains(&0) {
+        return Err(StruqtureError::GenericError {
+            msg: "The Fock space cutoffs need to be at least one.".to_string(),
+        });
+    }
+    boson_fock_space_dimension(cutoffs)?;
+    Ok(())
+}
+
+/// Trait for constructing sparse matrix representations of bosonic operators in a truncated Fock space.
+///
+/// Mode `k` is truncated to the `cutoffs[k]` lowest occupation numbers. The basis state index is
+/// `sum_k n_k * prod_{j<k} cutoffs[j]` for the occupation numbers `n_k`, so that mode 0 is the least significant.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::bosons::{BosonHamiltonian, HermitianBosonProduct};
+///
+/// let mut bh = BosonHamiltonian::new();
+/// bh.add_operator_product(HermitianBosonProduct::new([0], [0]).unwrap(), CalculatorComplex::from(1.0)).unwrap();
+///
+/// let (_, (rows, columns)) = bh.sparse_matrix_coo(&[3]).unwrap();
+/// assert_eq!(rows, vec![1, 2]);
+/// assert_eq!(columns, vec![1, 2]);
+/// ```
+///
+pub trait ToSparseMatrixBosonOperator {
+    /// Constructs the sparse matrix representation of Self in a truncated Fock space in COO format.
+    ///
+    /// The entries are sorted by row and column, entries that sum to zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of Self.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - Self acts on more modes than cutoffs are given.
+    /// * `Err(StruqtureError::GenericError)` - A cutoff is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo(&self, cutoffs: &[usize]) -> Result<CooSparseMatrix, StruqtureError>;
+
+    /// Constructs the sparse matrix representation of Self in a truncated Fock space as a HashMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix representation of Self.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - Self acts on more modes than cutoffs are given.
+    /// * `Err(StruqtureError::GenericError)` - A cutoff is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix(
+        &self,
+        cutoffs: &[usize],
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let (values, (rows, columns)) = self.sparse_matrix_coo(cutoffs)?;
+        Ok(rows.into_iter().zip(columns).zip(values).collect())
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator of Self in a truncated Fock space in COO format.
+    ///
+    /// The superoperator for the operator H is defined as the Matrix S so that
+    /// `flatten(-i [H, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    /// With this convention `S = -i H.kron(I) + i I.kron(H.T)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers of each mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the superoperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - Self acts on more modes than cutoffs are given.
+    /// * `Err(StruqtureError::GenericError)` - A cutoff is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_coo(
+        &self,
+        cutoffs: &[usize],
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let dimension = boson_fock_space_dimension(cutoffs)?;
+        // The superoperator acts on the space of density matrices with dimension^2 entries
+        boson_fock_space_dimension(&[dimension, dimension])?;
+        let mut entries: HashMap<(usize, usize), Complex64> = HashMap::new();
+        add_commutator_superoperator_entries(
+            &mut entries,
+            &self.sparse_matrix_coo(cutoffs)?,
+            dimension,
+        );
+        Ok(sorted_coo_from_entries(entries))
+    }
+}
@@ -13,11 +13,12 @@
 use super::{
     FermionOperator, FermionProduct, HermitianFermionProduct, ModeIndex, OperateOnFermions,
 };
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::encodings::LadderOperatorImages;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinHamiltonian;
 use crate::{
-    GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
@@ -315,6 +316,104 @@ impl FermionHamiltonian {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the FermionHamiltonian.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the FermionHamiltonian while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Returns the LaTeX representation of the FermionHamiltonian as a sum of monomials.
+    ///
+    /// The terms are sorted by their index. Creators are rendered as `\hat{c}^\dagger_{i}`,
+    /// annihilators as `\hat{c}_{j}` and the identity as `\mathbb{1}`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The LaTeX representation of the FermionHamiltonian.
+    pub fn to_latex(&self) -> String {
+        FermionOperator::from(self.clone()).to_latex()
+    }
+
+    /// Applies a single-particle basis transformation to the FermionHamiltonian.
+    ///
+    /// See [FermionOperator::basis_transform] for the conventions of the transformation.
+    /// The transformed operator is hermitian, only the terms corresponding to the keys of a FermionHamiltonian are kept.
+    /// The imaginary parts of the coefficients of naturally hermitian terms, caused by numerical round-off, are discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `transformation` - The unitary single-particle transformation matrix U.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonian in the transformed basis.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionHamiltonian acts on more modes than the dimension of U.
+    /// * `Err(StruqtureError::GenericError)` - The transformation matrix is not square or not unitary.
+    pub fn basis_transform(
+        &self,
+        transformation: &ndarray::Array2<num_complex::Complex64>,
+    ) -> Result<Self, StruqtureError> {
+        // The hermitian conjugate terms are added with conjugated coefficients before transforming
+        let mut operator = FermionOperator::with_capacity(2 * self.len());
+        for (key, value) in self.iter() {
+            let product =
+                FermionProduct::new(key.creators().copied(), key.annihilators().copied())?;
+            operator.add_operator_product(product.clone(), value.clone())?;
+            if !key.is_natural_hermitian() {
+                let (product_conj, sign) = product.hermitian_conjugate();
+                operator.add_operator_product(product_conj, value.conj() * sign)?;
+            }
+        }
+        let transformed = operator.basis_transform(transformation)?;
+        let mut hamiltonian = Self::with_capacity(transformed.len());
+        for (product, value) in transformed.into_iter() {
+            // Products that are not a valid HermitianFermionProduct are the hermitian conjugates of kept terms
+            if let Ok(key) = HermitianFermionProduct::new(
+                product.creators().copied(),
+                product.annihilators().copied(),
+            ) {
+                let value = if key.is_natural_hermitian() {
+                    CalculatorComplex::new(value.re, 0.0)
+                } else {
+                    value
+                };
+                hamiltonian.add_operator_product(key, value)?;
+            }
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Constructs the sparse matrix representation of the FermionHamiltonian in the occupation number basis in COO format.
+    ///
+    /// See [FermionOperator::sparse_matrix_coo] for the conventions of the basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes for which to construct the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the FermionHamiltonian.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionHamiltonian acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The occupation number basis of `number_modes` modes is too large to be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(
+        &self,
+        number_modes: usize,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        FermionOperator::from(self.clone()).sparse_matrix_coo(number_modes)
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -339,6 +438,92 @@ impl FermionHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Exports the FermionHamiltonian in the string format of OpenFermion's FermionOperator.
+    ///
+    /// The FermionHamiltonian is first converted into a FermionOperator, so that both
+    /// a term and its hermitian conjugate are written out explicitly.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The FermionHamiltonian in OpenFermion format.
+    pub fn to_openfermion_fermion_string(&self) -> String {
+        FermionOperator::from(self.clone()).to_openfermion_fermion_string()
+    }
+
+    /// Returns the commutator `[self, other] = self * other - other * self` of two FermionHamiltonians.
+    ///
+    /// Normal ordering the creators and annihilators of the multiplied products introduces fermionic signs,
+    /// the resulting terms are collected in a FermionOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionHamiltonian to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionOperator)` - The commutator of the two FermionHamiltonians.
+    /// * `Err(StruqtureError)` - The products could not be added to the FermionOperator.
+    pub fn commutator(&self, other: &Self) -> Result<FermionOperator, StruqtureError> {
+        Ok((self.clone() * other.clone())? - (other.clone() * self.clone())?)
+    }
+
+    /// Returns the anticommutator `{self, other} = self * other + other * self` of two FermionHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionHamiltonian to anticommute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionOperator)` - The anticommutator of the two FermionHamiltonians.
+    /// * `Err(StruqtureError)` - The products could not be added to the FermionOperator.
+    pub fn anticommutator(&self, other: &Self) -> Result<FermionOperator, StruqtureError> {
+        Ok((self.clone() * other.clone())? + (other.clone() * self.clone())?)
+    }
+
+    /// Writes the FermionHamiltonian into an HDF5 group.
+    ///
+    /// The HermitianFermionProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionHamiltonian to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionHamiltonian has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a FermionHamiltonian from an HDF5 group written with [FermionHamiltonian::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionHamiltonian from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonian stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a HermitianFermionProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(HermitianFermionProduct, CalculatorComplex)> =
+            crate::hdf5_io::read_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 impl TryFrom<FermionOperator> for FermionHamiltonian {
@@ -678,6 +863,38 @@ impl JordanWignerFermionToSpin for FermionHamiltonian {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionHamiltonian {
+    type Output = SpinHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The spin Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionHamiltonian acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        let ladder_operators = LadderOperatorImages::bravyi_kitaev(number_modes);
+        let mut out = SpinHamiltonian::new();
+        for (hfp, coeff) in self.iter() {
+            out = out
+                + ladder_operators.transform_hermitian_product(
+                    hfp.creators(),
+                    hfp.annihilators(),
+                    hfp.is_natural_hermitian(),
+                    coeff.clone(),
+                )?;
+        }
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -13,7 +13,7 @@
 use super::{
     FermionHamiltonian, FermionSystem, HermitianFermionProduct, ModeIndex, OperateOnFermions,
 };
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinHamiltonianSystem;
 use crate::{OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
@@ -232,6 +232,13 @@ impl FermionHamiltonianSystem {
         }
     }
 
+    /// Removes all terms from the FermionHamiltonianSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.hamiltonian.clear();
+    }
+
     /// Returns the FermionHamiltonian of the FermionHamiltonianSystem.
     ///
     /// # Returns
@@ -298,6 +305,46 @@ impl FermionHamiltonianSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the FermionHamiltonianSystem into an HDF5 group.
+    ///
+    /// The hamiltonian of the FermionHamiltonianSystem is written with [FermionHamiltonian::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the FermionHamiltonianSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionHamiltonianSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionHamiltonianSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.hamiltonian.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a FermionHamiltonianSystem from an HDF5 group written with [FermionHamiltonianSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionHamiltonianSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonianSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The hamiltonian could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_hamiltonian(
+            FermionHamiltonian::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of FermionHamiltonianSystem.
@@ -561,3 +608,27 @@ impl JordanWignerFermionToSpin for FermionHamiltonianSystem {
         .expect("Internal bug in jordan_wigner for FermionHamiltonian. The number of spins in the resulting Hamiltonian should equal the number of modes of the FermionHamiltonian.")
     }
 }
+
+impl BravyiKitaevFermionToSpin for FermionHamiltonianSystem {
+    type Output = SpinHamiltonianSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonianSystem)` - The spin Hamiltonian system with `number_modes` spins that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionHamiltonianSystem has more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        SpinHamiltonianSystem::from_hamiltonian(
+            self.hamiltonian().bravyi_kitaev(number_modes)?,
+            Some(number_modes),
+        )
+    }
+}
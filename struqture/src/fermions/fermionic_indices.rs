@@ -11,7 +11,8 @@
 // limitations under the License.
 
 use super::FermionIndex;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::encodings::LadderOperatorImages;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::prelude::*;
 use crate::spins::{PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator};
 use crate::{
@@ -70,6 +71,170 @@ impl schemars::JsonSchema for FermionProduct {
 
 impl crate::MinSupportedVersion for FermionProduct {}
 
+/// Functions for the FermionProduct
+///
+impl FermionProduct {
+    /// Returns whether the FermionProduct is normal ordered.
+    ///
+    /// The FermionProduct is normal ordered when all creators precede all annihilators (which is
+    /// guaranteed by the representation) and both the creator and annihilator indices are
+    /// strictly increasing (fermionic indices can not appear multiple times).
+    /// All constructors and the deserialization of FermionProduct uphold this invariant,
+    /// this function can be used to assert it before running algorithms that rely on it.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the FermionProduct is normal ordered.
+    pub fn is_normal_ordered(&self) -> bool {
+        self.creators.windows(2).all(|w| w[0] < w[1])
+            && self.annihilators.windows(2).all(|w| w[0] < w[1])
+    }
+
+    /// Returns whether the FermionProduct is guaranteed to commute with another FermionProduct.
+    ///
+    /// Fermionic products acting on disjoint sets of modes commute when at least one of them
+    /// contains an even number of fermionic operators, and anticommute otherwise.
+    /// Products that share modes are considered non-commuting unless they are identical,
+    /// even though special cases (e.g. products of number operators) can still commute.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionProduct to compare with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two FermionProducts commute.
+    pub fn commutes_with(&self, other: &FermionProduct) -> bool {
+        if self == other {
+            return true;
+        }
+        let shares_modes = self
+            .creators
+            .iter()
+            .chain(self.annihilators.iter())
+            .any(|mode| other.creators.contains(mode) || other.annihilators.contains(mode));
+        let self_parity = (self.creators.len() + self.annihilators.len()) % 2;
+        let other_parity = (other.creators.len() + other.annihilators.len()) % 2;
+        !shares_modes && self_parity * other_parity == 0
+    }
+
+    /// Transforms the FermionProduct into a SpinOperator using the Jordan-Wigner mapping with a custom mode ordering.
+    ///
+    /// The fermionic mode `ordering[k]` is mapped onto spin `k`, so the Jordan-Wigner string of
+    /// a mode consists of Z operators on all spins preceding it in the ordering.
+    /// For the ordering `[0, 1, ..., n-1]` the result is identical to `jordan_wigner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ordering` - The permutation of modes defining on which spin each mode is mapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::GenericError)` - The ordering is not a permutation or does not cover all modes of the FermionProduct.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    pub fn jordan_wigner_with_ordering(
+        &self,
+        ordering: &[usize],
+    ) -> Result<SpinOperator, StruqtureError> {
+        let mut spin_of_mode: Vec<Option<usize>> = vec![None; ordering.len()];
+        for (spin, mode) in ordering.iter().enumerate() {
+            match spin_of_mode.get_mut(*mode) {
+                Some(entry @ None) => *entry = Some(spin),
+                _ => {
+                    return Err(StruqtureError::GenericError {
+                        msg: "Input ordering must be a permutation.".to_string(),
+                    })
+                }
+            }
+        }
+        let spin_for =
+            |mode: &usize| -> Result<usize, StruqtureError> {
+                spin_of_mode.get(*mode).copied().flatten().ok_or_else(|| {
+                    StruqtureError::GenericError {
+                        msg: format!("Mode {} is not covered by the input ordering.", mode),
+                    }
+                })
+            };
+
+        let mut spin_operator = SpinOperator::new();
+        spin_operator
+            .add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+            .expect("Internal bug in add_operator_product.");
+        for mode in self.creators() {
+            let spin = spin_for(mode)?;
+            for i in 0..spin {
+                spin_operator = spin_operator * PauliProduct::new().z(i);
+            }
+            spin_operator = spin_operator * _lowering_operator(&spin);
+        }
+        for mode in self.annihilators() {
+            let spin = spin_for(mode)?;
+            for i in 0..spin {
+                spin_operator = spin_operator * PauliProduct::new().z(i);
+            }
+            spin_operator = spin_operator * _raising_operator(&spin);
+        }
+        Ok(spin_operator)
+    }
+
+    /// Verifies the result of the multiplication of two FermionProducts with dense matrices.
+    ///
+    /// Debug helper: the two inputs and the sum of the result terms are represented as dense matrices
+    /// in the Fock space of all modes they act on, and it is checked that `left_matrix * right_matrix`
+    /// is equal to `result_matrix`. The matrix dimension grows as `2^number_modes`, so this function
+    /// is only intended for products acting on a small number of modes.
+    /// Requires the `linalg` feature, which enables the optional nalgebra dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The left factor of the multiplication.
+    /// * `right` - The right factor of the multiplication.
+    /// * `result` - The terms of the product, as returned by `left * right`.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the result is the correct product of left and right.
+    #[cfg(feature = "linalg")]
+    pub fn verify_product(left: &Self, right: &Self, result: &[(FermionProduct, f64)]) -> bool {
+        let number_modes = result
+            .iter()
+            .map(|(product, _)| product.current_number_modes())
+            .chain([left.current_number_modes(), right.current_number_modes()])
+            .max()
+            .unwrap_or(0);
+        let dimension = 2usize.pow(number_modes as u32);
+        let mut result_matrix = nalgebra::DMatrix::<f64>::zeros(dimension, dimension);
+        for (product, coefficient) in result.iter() {
+            result_matrix += product.fock_matrix(number_modes) * *coefficient;
+        }
+        let expected = left.fock_matrix(number_modes) * right.fock_matrix(number_modes);
+        expected
+            .iter()
+            .zip(result_matrix.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-10)
+    }
+
+    /// Constructs the dense matrix of the FermionProduct in the occupation number basis.
+    ///
+    /// The basis state index is the bit string of the occupations, with mode 0 as the least significant bit.
+    /// The fermionic signs are obtained by counting the occupied modes with a lower index.
+    #[cfg(feature = "linalg")]
+    fn fock_matrix(&self, number_modes: usize) -> nalgebra::DMatrix<f64> {
+        let dimension = 2usize.pow(number_modes as u32);
+        let mut matrix = nalgebra::DMatrix::<f64>::zeros(dimension, dimension);
+        for (row, column, value) in super::fermion_product_matrix_entries(self, number_modes)
+            .expect("Internal error: the dense matrix was constructed for the same number of modes")
+        {
+            matrix[(row, column)] += value;
+        }
+        matrix
+    }
+}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for FermionProduct {
@@ -1423,6 +1588,56 @@ transform is hermitian.",
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionProduct {
+    type Output = SpinOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionProduct acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        LadderOperatorImages::bravyi_kitaev(number_modes)
+            .transform_product(self.creators(), self.annihilators())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for HermitianFermionProduct {
+    type Output = SpinHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a HermitianFermionProduct.
+    ///
+    /// If the product is not natural hermitian, the hermitian conjugate is added to the transformed product.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The spin Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The HermitianFermionProduct acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        LadderOperatorImages::bravyi_kitaev(number_modes).transform_hermitian_product(
+            self.creators(),
+            self.annihilators(),
+            self.is_natural_hermitian(),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+    }
+}
+
 fn _lowering_operator(i: &usize) -> SpinOperator {
     let mut out = SpinOperator::new();
     out.add_operator_product(PauliProduct::new().x(*i), CalculatorComplex::new(0.5, 0.0))
@@ -10,14 +10,20 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionOperator, FermionProduct, OperateOnFermions};
-use crate::mappings::JordanWignerFermionToSpin;
+use super::{
+    fermion_basis_dimension, fermion_product_matrix_entries, FermionOperator, FermionProduct,
+    OperateOnFermions,
+};
+use crate::mappings::encodings::LadderOperatorImages;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::{DecoherenceOperator, SpinLindbladNoiseOperator};
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    add_lindblad_superoperator_entries, sorted_coo_from_entries, CooSparseMatrix, ModeIndex,
+    OperateOnDensityMatrix, OperateOnModes, StruqtureError, StruqtureVersionSerializable,
+    MINIMUM_STRUQTURE_VERSION,
 };
 use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -295,6 +301,22 @@ impl FermionLindbladNoiseOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the FermionLindbladNoiseOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the FermionLindbladNoiseOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
     /// Adds all noise entries corresponding to a ((FermionOperator, FermionOperator), CalculatorFloat).
     ///
     /// In the Lindblad equation, Linblad noise operator L_i are not limited to [crate::spins::FermionProduct] style operators.
@@ -367,6 +389,92 @@ impl FermionLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Constructs the sparse matrix representation of the Lindblad superoperator in the occupation number basis in COO format.
+    ///
+    /// Each term `((L, R), M)` contributes `M (L p R^dagger - 1/2 {R^dagger L, p})` to the time derivative of the density matrix `p`.
+    /// The superoperator S is defined so that `flatten(dp/dt) = S flatten(p)`, where `flatten` flattens a matrix into
+    /// a vector in row-major form. See [crate::fermions::FermionOperator::sparse_matrix_coo] for the conventions of the basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes for which to construct the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the Lindblad superoperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionLindbladNoiseOperator acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The space of density matrices of `number_modes` modes is too large to be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_superoperator_coo(
+        &self,
+        number_modes: usize,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        // The superoperator acts on the space of density matrices with dimension^2 entries
+        fermion_basis_dimension(number_modes.saturating_mul(2))?;
+        let dimension = fermion_basis_dimension(number_modes)?;
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for ((left, right), value) in self.iter() {
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            add_lindblad_superoperator_entries(
+                &mut entries,
+                &fermion_product_matrix_entries(left, number_modes)?,
+                &fermion_product_matrix_entries(right, number_modes)?,
+                Complex64::new(real, imaginary),
+                dimension,
+            );
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
+    /// Writes the FermionLindbladNoiseOperator into an HDF5 group.
+    ///
+    /// The left and right FermionProducts of the sorted terms are stored as strings in the `left_keys` and `right_keys`
+    /// datasets, the real and imaginary parts of the coefficients in the `real` and `imag` datasets.
+    /// The minimum struqture version required to read the data is stored in the `struqture_major_version`
+    /// and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionLindbladNoiseOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionLindbladNoiseOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_noise_terms(group, self.iter())
+    }
+
+    /// Reads a FermionLindbladNoiseOperator from an HDF5 group written with [FermionLindbladNoiseOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionLindbladNoiseOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladNoiseOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a FermionProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<((FermionProduct, FermionProduct), CalculatorComplex)> =
+            crate::hdf5_io::read_noise_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladNoiseOperator.
@@ -629,6 +737,42 @@ impl JordanWignerFermionToSpin for FermionLindbladNoiseOperator {
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionLindbladNoiseOperator {
+    type Output = SpinLindbladNoiseOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladNoiseOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladNoiseOperator)` - The spin noise operator that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionLindbladNoiseOperator acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        let ladder_operators = LadderOperatorImages::bravyi_kitaev(number_modes);
+        let mut out = SpinLindbladNoiseOperator::new();
+        for ((left, right), coeff) in self.iter() {
+            let decoherence_operator_left = DecoherenceOperator::from(
+                ladder_operators.transform_product(left.creators(), left.annihilators())?,
+            );
+            let decoherence_operator_right = DecoherenceOperator::from(
+                ladder_operators.transform_product(right.creators(), right.annihilators())?,
+            );
+            out.add_noise_from_full_operators(
+                &decoherence_operator_left,
+                &decoherence_operator_right,
+                coeff.into(),
+            )?;
+        }
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,7 +11,7 @@
 // limitations under the License.
 
 use super::{FermionLindbladNoiseOperator, OperateOnFermions};
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinLindbladNoiseSystem;
 use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
 use qoqo_calculator::CalculatorComplex;
@@ -230,6 +230,13 @@ impl FermionLindbladNoiseSystem {
         }
     }
 
+    /// Removes all terms from the FermionLindbladNoiseSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the FermionLindbladNoiseOperator of the FermionLindbladNoiseSystem.
     ///
     /// # Returns
@@ -302,6 +309,46 @@ impl FermionLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the FermionLindbladNoiseSystem into an HDF5 group.
+    ///
+    /// The operator of the FermionLindbladNoiseSystem is written with [FermionLindbladNoiseOperator::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the FermionLindbladNoiseSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionLindbladNoiseSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionLindbladNoiseSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a FermionLindbladNoiseSystem from an HDF5 group written with [FermionLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionLindbladNoiseSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladNoiseSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            FermionLindbladNoiseOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladNoiseSystem.
@@ -527,3 +574,27 @@ impl JordanWignerFermionToSpin for FermionLindbladNoiseSystem {
             .expect("Internal bug in jordan_wigner for FermionLindbladNoiseOperator. The number of spins in the resulting SpinLindbladNoiseOperator should equal the number of modes of the FermionLindbladNoiseOperator.")
     }
 }
+
+impl BravyiKitaevFermionToSpin for FermionLindbladNoiseSystem {
+    type Output = SpinLindbladNoiseSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladNoiseSystem)` - The spin noise system with `number_modes` spins that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionLindbladNoiseSystem has more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        SpinLindbladNoiseSystem::from_operator(
+            self.operator().bravyi_kitaev(number_modes)?,
+            Some(number_modes),
+        )
+    }
+}
@@ -10,10 +10,14 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionHamiltonianSystem, FermionLindbladNoiseSystem};
-use crate::mappings::JordanWignerFermionToSpin;
+use super::{fermion_basis_dimension, FermionHamiltonianSystem, FermionLindbladNoiseSystem};
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinLindbladOpenSystem;
-use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use crate::{
+    add_commutator_superoperator_entries, sorted_coo_from_entries, CooSparseMatrix, OpenSystem,
+    OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+};
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -172,6 +176,93 @@ impl FermionLindbladOpenSystem {
             noise: FermionLindbladNoiseSystem::new(number_modes),
         }
     }
+
+    /// Constructs the sparse matrix representation of the Lindblad superoperator in the occupation number basis in COO format.
+    ///
+    /// The superoperator S is defined so that `flatten(dp/dt) = S flatten(p)` for the Lindblad equation
+    /// `dp/dt = -i [H, p] + sum_ij M_ij (L_i p L_j^dagger - 1/2 {L_j^dagger L_i, p})`, where `flatten` flattens
+    /// a matrix into a vector in row-major form. See [crate::fermions::FermionOperator::sparse_matrix_coo] for the conventions of the basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes for which to construct the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the Lindblad superoperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionLindbladOpenSystem has more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The space of density matrices of `number_modes` modes is too large to be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_superoperator_coo(
+        &self,
+        number_modes: usize,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        // The superoperator acts on the space of density matrices with dimension^2 entries
+        fermion_basis_dimension(number_modes.saturating_mul(2))?;
+        let dimension = fermion_basis_dimension(number_modes)?;
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        add_commutator_superoperator_entries(
+            &mut entries,
+            &self.system.hamiltonian().sparse_matrix_coo(number_modes)?,
+            dimension,
+        );
+        let (values, (rows, columns)) = self
+            .noise
+            .operator()
+            .sparse_matrix_superoperator_coo(number_modes)?;
+        for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+            *entries
+                .entry((row, column))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += value;
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
+    /// Writes the FermionLindbladOpenSystem into an HDF5 group.
+    ///
+    /// The system and noise parts are written into the subgroups `system` and `noise` with
+    /// [FermionHamiltonianSystem::to_hdf5] and [FermionLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionLindbladOpenSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionLindbladOpenSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::create_open_system_groups(group)?;
+        self.system.to_hdf5(&system_group)?;
+        self.noise.to_hdf5(&noise_group)
+    }
+
+    /// Reads a FermionLindbladOpenSystem from an HDF5 group written with [FermionLindbladOpenSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionLindbladOpenSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladOpenSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The system or noise could not be read or do not have matching sizes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::open_system_groups(group)?;
+        Self::group(
+            FermionHamiltonianSystem::from_hdf5(&system_group)?,
+            FermionLindbladNoiseSystem::from_hdf5(&noise_group)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladOpenSystem.
@@ -305,3 +396,23 @@ impl JordanWignerFermionToSpin for FermionLindbladOpenSystem {
             .expect("Internal bug in jordan_wigner() for FermionHamiltonianSystem or FermionLindbladNoiseSystem. The number of modes in the fermionic system should equal the number of spins in the spin system.")
     }
 }
+
+impl BravyiKitaevFermionToSpin for FermionLindbladOpenSystem {
+    type Output = SpinLindbladOpenSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladOpenSystem)` - The spin open system that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionLindbladOpenSystem has more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        let bk_system = self.system().bravyi_kitaev(number_modes)?;
+        let bk_noise = self.noise().bravyi_kitaev(number_modes)?;
+        SpinLindbladOpenSystem::group(bk_system, bk_noise)
+    }
+}
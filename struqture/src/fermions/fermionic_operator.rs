@@ -11,14 +11,19 @@
 // limitations under the License.
 
 use super::{FermionHamiltonian, OperateOnFermions};
-use crate::fermions::FermionProduct;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::fermions::{fermion_product_matrix_entries, FermionProduct};
+use crate::mappings::encodings::LadderOperatorImages;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinOperator;
+#[cfg(feature = "linalg")]
+use crate::wick_contraction_matrix;
 use crate::{
-    GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    sorted_coo_from_entries, CooSparseMatrix, GetValue, ModeIndex, OperateOnDensityMatrix,
+    OperateOnModes, OperateOnState, StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+    MINIMUM_STRUQTURE_VERSION,
 };
 // use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -159,6 +164,23 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionOperator {
         self.internal_map.remove(key)
     }
 
+    // From trait
+    fn drain_filter<F>(&mut self, predicate: F) -> Vec<(Self::Index, Self::Value)>
+    where
+        F: Fn(&Self::Index, &Self::Value) -> bool,
+    {
+        let mut drained: Vec<(Self::Index, Self::Value)> = Vec::new();
+        self.internal_map.retain(|key, value| {
+            if predicate(key, value) {
+                drained.push((key.clone(), value.clone()));
+                false
+            } else {
+                true
+            }
+        });
+        drained
+    }
+
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
         match capacity {
@@ -269,6 +291,234 @@ impl FermionOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the FermionOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the FermionOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Creates a FermionOperator directly from a HashMap of FermionProducts and coefficients.
+    ///
+    /// In contrast to `FromIterator` the keys of the HashMap are already unique,
+    /// so the map is moved into the internal storage without accumulating duplicate terms.
+    /// Only terms with a coefficient of zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The HashMap of FermionProducts and their coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The FermionOperator containing the terms of the map.
+    pub fn from_hashmap(map: std::collections::HashMap<FermionProduct, CalculatorComplex>) -> Self {
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal_map = map;
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal_map: IndexMap<FermionProduct, CalculatorComplex> =
+            map.into_iter().collect();
+        internal_map.retain(|_, value| *value != CalculatorComplex::ZERO);
+        FermionOperator { internal_map }
+    }
+
+    /// Consumes the FermionOperator and rebuilds it with every FermionProduct transformed by a function.
+    ///
+    /// The coefficients of terms that are mapped to the same FermionProduct are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function transforming the FermionProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator with transformed keys.
+    /// * `Err(StruqtureError)` - A transformed term could not be added to the FermionOperator.
+    pub fn convert_keys<F: Fn(FermionProduct) -> FermionProduct>(
+        self,
+        f: F,
+    ) -> Result<Self, StruqtureError> {
+        let mut converted = self.empty_clone(Some(self.len()));
+        for (key, value) in self.into_iter() {
+            converted.add_operator_product(f(key), value)?;
+        }
+        Ok(converted)
+    }
+
+    /// Writes the FermionOperator into an HDF5 group.
+    ///
+    /// The FermionProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a FermionOperator from an HDF5 group written with [FermionOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a FermionProduct.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(FermionProduct, CalculatorComplex)> = crate::hdf5_io::read_terms(group)?;
+        Ok(terms.into_iter().collect())
+    }
+
+    /// Returns the LaTeX representation of the FermionOperator as a sum of monomials.
+    ///
+    /// The terms are sorted by their index. Creators are rendered as `\hat{c}^\dagger_{i}`,
+    /// annihilators as `\hat{c}_{j}` and the identity as `\mathbb{1}`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The LaTeX representation of the FermionOperator.
+    pub fn to_latex(&self) -> String {
+        crate::mode_terms_to_latex(self.iter(), "c")
+    }
+
+    /// Applies a single-particle basis transformation to the FermionOperator.
+    ///
+    /// Every creation operator is replaced by `c_j^dagger -> sum_k U[k, j] c_k^dagger` and every annihilation operator
+    /// by `c_j -> sum_k conj(U[k, j]) c_k`, where U is the unitary transformation matrix.
+    /// The transformed products are brought into normal order with the corresponding fermionic signs.
+    ///
+    /// # Arguments
+    ///
+    /// * `transformation` - The unitary single-particle transformation matrix U.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator in the transformed basis.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionOperator acts on more modes than the dimension of U.
+    /// * `Err(StruqtureError::GenericError)` - The transformation matrix is not square or not unitary.
+    pub fn basis_transform(
+        &self,
+        transformation: &ndarray::Array2<Complex64>,
+    ) -> Result<Self, StruqtureError> {
+        let (number_rows, number_columns) = transformation.dim();
+        if number_rows != number_columns {
+            return Err(StruqtureError::GenericError {
+                msg: "The basis transformation matrix needs to be square.".to_string(),
+            });
+        }
+        if self.current_number_modes() > number_rows {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        for i in 0..number_rows {
+            for j in 0..number_rows {
+                let overlap: Complex64 = (0..number_rows)
+                    .map(|k| transformation[[i, k]] * transformation[[j, k]].conj())
+                    .sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if (overlap - expected).norm() > 1e-10 {
+                    return Err(StruqtureError::GenericError {
+                        msg: "The basis transformation matrix needs to be unitary.".to_string(),
+                    });
+                }
+            }
+        }
+
+        let transformed_mode = |mode: usize, creator: bool| -> Result<Self, StruqtureError> {
+            let mut operator = Self::with_capacity(number_rows);
+            for k in 0..number_rows {
+                let entry = transformation[[k, mode]];
+                if entry == Complex64::new(0.0, 0.0) {
+                    continue;
+                }
+                let (product, coefficient) = if creator {
+                    (FermionProduct::new([k], [])?, entry)
+                } else {
+                    (FermionProduct::new([], [k])?, entry.conj())
+                };
+                operator.add_operator_product(
+                    product,
+                    CalculatorComplex::new(coefficient.re, coefficient.im),
+                )?;
+            }
+            Ok(operator)
+        };
+
+        let mut transformed = Self::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let mut term = Self::new();
+            term.add_operator_product(FermionProduct::new([], [])?, value.clone())?;
+            for creator in product.creators() {
+                term = term * transformed_mode(*creator, true)?;
+            }
+            for annihilator in product.annihilators() {
+                term = term * transformed_mode(*annihilator, false)?;
+            }
+            transformed += term;
+        }
+        Ok(transformed)
+    }
+
+    /// Constructs the sparse matrix representation of the FermionOperator in the occupation number basis in COO format.
+    ///
+    /// The basis state index is the bit string of the occupations, with mode 0 as the least significant bit.
+    /// The fermionic signs follow the same convention as the Jordan-Wigner transformation, so that the result is
+    /// identical to the sparse matrix of the SpinOperator obtained with `jordan_wigner`.
+    /// The entries are sorted by row and column, entries that sum to zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes for which to construct the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the FermionOperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionOperator acts on more than `number_modes` modes.
+    /// * `Err(StruqtureError::GenericError)` - The occupation number basis of `number_modes` modes is too large to be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(
+        &self,
+        number_modes: usize,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for (product, value) in self.iter() {
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            let coefficient = Complex64::new(real, imaginary);
+            for (row, column, entry) in fermion_product_matrix_entries(product, number_modes)? {
+                *entries
+                    .entry((row, column))
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += coefficient * entry;
+            }
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -293,6 +543,405 @@ impl FermionOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the maximum number of creators and annihilators in any single term of the FermionOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The maximum mode locality of the terms (zero for an empty or pure identity operator).
+    pub fn max_mode_locality(&self) -> usize {
+        self.keys()
+            .map(|product| product.creators().len() + product.annihilators().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Exports the FermionOperator in the string format of OpenFermion's FermionOperator.
+    ///
+    /// Each term is written as `coefficient [indices]` where creators are marked with `^`
+    /// and annihilators are given by their bare index, e.g. `0.5 [0^ 1]`.
+    /// The identity is written as `[]`. Terms are sorted by their FermionProduct and joined with ` +\n`.
+    /// Symbolic coefficients are written as they are and can not be parsed by OpenFermion.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The FermionOperator in OpenFermion format.
+    pub fn to_openfermion_fermion_string(&self) -> String {
+        let mut terms: Vec<(&FermionProduct, &CalculatorComplex)> = self.iter().collect();
+        terms.sort_by_key(|(left, _)| *left);
+        terms
+            .into_iter()
+            .map(|(product, value)| {
+                let indices: Vec<String> = product
+                    .creators()
+                    .map(|index| format!("{}^", index))
+                    .chain(product.annihilators().map(|index| format!("{}", index)))
+                    .collect();
+                format!(
+                    "{} [{}]",
+                    openfermion_coefficient_string(value),
+                    indices.join(" ")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" +\n")
+    }
+
+    /// Creates a FermionOperator from a string in the format of OpenFermion's FermionOperator.
+    ///
+    /// Every term has the form `coefficient [indices]`, where a trailing `^` marks a creator and a bare index an annihilator.
+    /// Terms can be separated by `+`. A missing coefficient is interpreted as 1.0, complex coefficients use the Python format `(1.0+2.0j)`.
+    /// The operators in a term do not need to be normal ordered: they are normal ordered on import,
+    /// tracking the sign of every permutation and adding all terms resulting from anticommuting creators and annihilators.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The string in OpenFermion format.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator parsed from the string.
+    /// * `Err(StruqtureError::ParsingError)` - The string could not be parsed.
+    pub fn from_openfermion_fermion_string(input: &str) -> Result<Self, StruqtureError> {
+        let mut operator = FermionOperator::new();
+        let mut remainder = input;
+        while let Some(start) = remainder.find('[') {
+            let end = remainder[start..]
+                .find(']')
+                .map(|end| start + end)
+                .ok_or_else(|| StruqtureError::ParsingError {
+                    target_type: "FermionOperator".to_string(),
+                    msg: format!("Missing closing bracket in term {}", &remainder[start..]),
+                })?;
+            let coefficient = parse_openfermion_coefficient(&remainder[..start])?;
+
+            let mut products: Vec<(FermionProduct, f64)> = vec![(FermionProduct::default(), 1.0)];
+            for token in remainder[start + 1..end].split_whitespace() {
+                let (index, is_creator) = match token.strip_suffix('^') {
+                    Some(index) => (index, true),
+                    None => (token, false),
+                };
+                let index: usize = index.parse().map_err(|_| StruqtureError::ParsingError {
+                    target_type: "FermionOperator".to_string(),
+                    msg: format!("Could not parse mode index {}", token),
+                })?;
+                let single = if is_creator {
+                    FermionProduct::new([index], [])?
+                } else {
+                    FermionProduct::new([], [index])?
+                };
+                products = products
+                    .into_iter()
+                    .flat_map(|(product, prefactor)| {
+                        (product * single.clone())
+                            .into_iter()
+                            .map(move |(new_product, sign)| (new_product, sign * prefactor))
+                    })
+                    .collect();
+            }
+            for (product, prefactor) in products {
+                operator.add_operator_product(product, coefficient.clone() * prefactor)?;
+            }
+            remainder = &remainder[end + 1..];
+        }
+        if !remainder.trim().trim_start_matches('+').trim().is_empty() {
+            return Err(StruqtureError::ParsingError {
+                target_type: "FermionOperator".to_string(),
+                msg: format!("Coefficient {} without operator term", remainder.trim()),
+            });
+        }
+        Ok(operator)
+    }
+
+    /// Returns the commutator `[self, other] = self * other - other * self` of two FermionOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The commutator of the two FermionOperators.
+    pub fn commutator(&self, other: &Self) -> Self {
+        self.clone() * other.clone() - other.clone() * self.clone()
+    }
+
+    /// Returns the anticommutator `{self, other} = self * other + other * self` of two FermionOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to anticommute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anticommutator of the two FermionOperators.
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        self.clone() * other.clone() + other.clone() * self.clone()
+    }
+
+    /// Returns the sum `self ⊗ I + I ⊗ other` of two FermionOperators acting on disjoint sets of modes.
+    ///
+    /// The mode indices of `other` are shifted by `offset` and the shifted terms are added to the terms of `self`,
+    /// which composes the operator of a larger system from the operators of two subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator acting on the second subsystem.
+    /// * `offset` - The shift applied to the mode indices of `other`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator acting on the combined system.
+    /// * `Err(StruqtureError::GenericError)` - The shifted mode indices of `other` overlap with the mode indices of `self`.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in FermionProduct::new.
+    pub fn tensor(&self, other: &Self, offset: usize) -> Result<Self, StruqtureError> {
+        if other.current_number_modes() > 0 && offset < self.current_number_modes() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The offset {} is smaller than the number of modes {} of the first FermionOperator, the mode indices overlap.",
+                    offset,
+                    self.current_number_modes()
+                ),
+            });
+        }
+        let shifted = other.clone().convert_keys(|product| {
+            FermionProduct::new(
+                product.creators().map(|index| index + offset),
+                product.annihilators().map(|index| index + offset),
+            )
+            .expect("Internal bug in FermionProduct::new, shifting the indices preserves their ordering.")
+        })?;
+        let mut combined = self.clone();
+        for (key, value) in shifted {
+            combined.add_operator_product(key, value)?;
+        }
+        Ok(combined)
+    }
+
+    /// Returns the truncated Taylor series of the exponential of the FermionOperator.
+    ///
+    /// Computes `exp(self) = sum_{k=0}^{order} self^k / k!`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The highest power of the FermionOperator included in the series.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated exponential of the FermionOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    /// * Internal bug in FermionProduct::new.
+    pub fn exp_approx(&self, order: usize) -> Self {
+        let mut term = Self::new();
+        term.add_operator_product(
+            FermionProduct::new([], []).expect("Internal bug in FermionProduct::new."),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+        .expect("Internal bug in add_operator_product.");
+        let mut exponential = term.clone();
+        for k in 1..=order {
+            term = term * self.clone() * (1.0 / k as f64);
+            exponential += term.clone();
+        }
+        exponential
+    }
+
+    /// Transforms the FermionOperator with the exponential of a generator using the truncated Baker-Campbell-Hausdorff expansion.
+    ///
+    /// Computes `exp(generator) * self * exp(-generator) = sum_{k=0}^{order} [generator, self]_k / k!`,
+    /// where `[generator, self]_k` denotes the k-fold nested commutator.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The generator A of the transformation e^{A} B e^{-A}.
+    /// * `order` - The highest number of nested commutators included in the expansion.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The transformed FermionOperator.
+    pub fn heisenberg_transform(&self, generator: &Self, order: usize) -> Self {
+        let mut term = self.clone();
+        let mut transformed = self.clone();
+        for k in 1..=order {
+            term = generator.commutator(&term) * (1.0 / k as f64);
+            if term.is_empty() {
+                break;
+            }
+            transformed += term.clone();
+        }
+        transformed
+    }
+
+    /// Constructs the normal ordered form of a product of fermionic creation and annihilation operators.
+    ///
+    /// The ladder operators are given in arbitrary order as `(mode, is_creator)` pairs and multiplied from left to right.
+    /// The result contains the normal ordered product as well as all terms arising from the contractions, including the signs from the anticommutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `ladder_operators` - The sequence of ladder operators, `true` denotes a creator and `false` an annihilator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The normal ordered FermionOperator equal to the product of the ladder operators.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn normal_ordered(ladder_operators: &[(usize, bool)]) -> Self {
+        let mut operator = Self::new();
+        operator
+            .add_operator_product(
+                FermionProduct::new([], []).expect("Internal bug in ModeIndex::new."),
+                CalculatorComplex::from(1.0),
+            )
+            .expect("Internal bug in add_operator_product.");
+        for (mode, is_creator) in ladder_operators.iter() {
+            let product = if *is_creator {
+                FermionProduct::new([*mode], [])
+            } else {
+                FermionProduct::new([], [*mode])
+            }
+            .expect("Internal bug in ModeIndex::new.");
+            let mut ladder_operator = Self::new();
+            ladder_operator
+                .add_operator_product(product, CalculatorComplex::from(1.0))
+                .expect("Internal bug in add_operator_product.");
+            operator = operator * ladder_operator;
+        }
+        operator
+    }
+
+    /// Computes the expectation value of the FermionOperator in a quasi-free (Gaussian) state using Wick's theorem.
+    ///
+    /// Requires the `linalg` feature, which enables the optional nalgebra dependency.
+    ///
+    /// The state is characterised by its one-body density matrix `D[i, j] = <c_i^dagger c_j>`, anomalous
+    /// contractions are assumed to vanish. The expectation value of a product with equal numbers of creators and annihilators is given by the
+    /// determinant of the matrix of contractions, all other products vanish.
+    ///
+    /// # Arguments
+    ///
+    /// * `one_body_dm` - The one-body density matrix of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value of the FermionOperator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionOperator acts on more modes than the dimension of the one-body density matrix.
+    /// * `Err(StruqtureError::GenericError)` - The one-body density matrix is not square.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    #[cfg(feature = "linalg")]
+    pub fn wick_expectation(
+        &self,
+        one_body_dm: &ndarray::Array2<Complex64>,
+    ) -> Result<Complex64, StruqtureError> {
+        let current_number_modes = self.current_number_modes();
+        let mut expectation = Complex64::new(0.0, 0.0);
+        for (product, value) in self.iter() {
+            let creators: Vec<usize> = product.creators().copied().collect();
+            let annihilators: Vec<usize> = product.annihilators().copied().collect();
+            let contractions = wick_contraction_matrix(
+                one_body_dm,
+                current_number_modes,
+                &creators,
+                &annihilators,
+            )?;
+            if creators.len() != annihilators.len() {
+                continue;
+            }
+            // Reversing the annihilators to c_{j_n} ... c_{j_1} yields the determinant of the contractions
+            let number_pairs = creators.len();
+            let sign = if (number_pairs * number_pairs.saturating_sub(1) / 2) % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            };
+            let contraction = if number_pairs == 0 {
+                Complex64::new(1.0, 0.0)
+            } else {
+                contractions.determinant() * sign
+            };
+            expectation += Complex64::new(*value.re.float()?, *value.im.float()?) * contraction;
+        }
+        Ok(expectation)
+    }
+}
+
+/// Formats a CalculatorComplex as a coefficient in OpenFermion (Python) format.
+fn openfermion_coefficient_string(value: &CalculatorComplex) -> String {
+    match (&value.re, &value.im) {
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => {
+            if *im == 0.0 {
+                format!("{:?}", re)
+            } else if *im < 0.0 {
+                format!("({:?}-{:?}j)", re, im.abs())
+            } else {
+                format!("({:?}+{:?}j)", re, im)
+            }
+        }
+        (re, CalculatorFloat::Float(im)) if *im == 0.0 => format!("{}", re),
+        (re, im) => format!("({}+{}j)", re, im),
+    }
+}
+
+/// Parses a coefficient in OpenFermion (Python) format, e.g. `-0.5`, `2j` or `(1.0-2.0j)`.
+fn parse_openfermion_coefficient(input: &str) -> Result<CalculatorComplex, StruqtureError> {
+    let parsing_error = || StruqtureError::ParsingError {
+        target_type: "FermionOperator".to_string(),
+        msg: format!("Could not parse coefficient {}", input.trim()),
+    };
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let compact = compact.trim_start_matches('+');
+    let (sign, compact) = match compact.strip_prefix('-') {
+        Some(rest) if rest.starts_with('(') => (-1.0, rest),
+        _ => (1.0, compact),
+    };
+    let compact = compact
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(compact);
+    if compact.is_empty() {
+        return Ok(CalculatorComplex::new(sign, 0.0));
+    }
+    let value = match compact.strip_suffix('j') {
+        None => {
+            let re: f64 = compact.parse().map_err(|_| parsing_error())?;
+            CalculatorComplex::new(re, 0.0)
+        }
+        Some(complex) => {
+            // Split at the last sign that is not at the start and not part of an exponent
+            let split = complex
+                .char_indices()
+                .filter(|(position, character)| {
+                    *position > 0
+                        && (*character == '+' || *character == '-')
+                        && !complex[..*position].ends_with(|c| c == 'e' || c == 'E')
+                })
+                .map(|(position, _)| position)
+                .next_back();
+            match split {
+                Some(position) => {
+                    let re: f64 = complex[..position].parse().map_err(|_| parsing_error())?;
+                    let im: f64 = complex[position..]
+                        .trim_start_matches('+')
+                        .parse()
+                        .map_err(|_| parsing_error())?;
+                    CalculatorComplex::new(re, im)
+                }
+                None => {
+                    let im: f64 = complex.parse().map_err(|_| parsing_error())?;
+                    CalculatorComplex::new(0.0, im)
+                }
+            }
+        }
+    };
+    Ok(value * sign)
 }
 
 impl From<FermionHamiltonian> for FermionOperator {
@@ -427,6 +1076,73 @@ where
     }
 }
 
+/// Implements the in-place plus function of FermionOperator by FermionOperator.
+///
+impl<T, V> ops::AddAssign<T> for FermionOperator
+where
+    T: IntoIterator<Item = (FermionProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    /// Implements `+=` (add in place) for two FermionOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to be added.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add_assign(&mut self, other: T) {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))
+                .expect("Internal error in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place minus function of FermionOperator by FermionOperator.
+///
+impl<T, V> ops::SubAssign<T> for FermionOperator
+where
+    T: IntoIterator<Item = (FermionProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    /// Implements `-=` (subtract in place) for two FermionOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The FermionOperator to be subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub_assign(&mut self, other: T) {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)
+                .expect("Internal error in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place multiplication function of FermionOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::MulAssign<T> for FermionOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    /// Implement `*=` (multiply in place) for FermionOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    fn mul_assign(&mut self, other: T) {
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        for value in self.internal_map.values_mut() {
+            *value = value.clone() * other_cc.clone();
+        }
+    }
+}
+
 /// Implements the multiplication function of FermionOperator by FermionOperator.
 ///
 impl ops::Mul<FermionOperator> for FermionOperator {
@@ -577,12 +1293,39 @@ impl JordanWignerFermionToSpin for FermionOperator {
     fn jordan_wigner(&self) -> Self::Output {
         let mut out = SpinOperator::new();
         for fp in self.keys() {
-            out = out + fp.jordan_wigner() * self.get(fp);
+            out += fp.jordan_wigner() * self.get(fp);
         }
         out
     }
 }
 
+impl BravyiKitaevFermionToSpin for FermionOperator {
+    type Output = SpinOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionOperator acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        let ladder_operators = LadderOperatorImages::bravyi_kitaev(number_modes);
+        let mut out = SpinOperator::new();
+        for (fp, coeff) in self.iter() {
+            out += ladder_operators.transform_product(fp.creators(), fp.annihilators())?
+                * coeff.clone();
+        }
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -12,7 +12,7 @@
 
 use super::{FermionOperator, OperateOnFermions};
 use crate::fermions::FermionProduct;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use crate::spins::SpinSystem;
 use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
 use qoqo_calculator::CalculatorComplex;
@@ -226,6 +226,13 @@ impl FermionSystem {
         }
     }
 
+    /// Removes all terms from the FermionSystem while keeping the number of modes and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the FermionOperator of the FermionSystem.
     ///
     /// # Returns
@@ -292,6 +299,46 @@ impl FermionSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the FermionSystem into an HDF5 group.
+    ///
+    /// The operator of the FermionSystem is written with [FermionOperator::to_hdf5], the `number_modes` attribute
+    /// is only written when the number modes of the FermionSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the FermionSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The FermionSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_modes", self.number_modes)
+    }
+
+    /// Reads a FermionSystem from an HDF5 group written with [FermionSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the FermionSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            FermionOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_modes")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of FermionSystem.
@@ -378,7 +425,7 @@ where
     ///
     /// * `Self` - The FermionSystem multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(mut self, other: T) -> Self {
-        self.operator = self.operator * other;
+        self.operator *= other;
         self
     }
 }
@@ -533,3 +580,27 @@ impl JordanWignerFermionToSpin for FermionSystem {
             .expect("Internal bug in jordan_wigner for FermionSystem. The number of spins in the resulting SpinSystem should equal the number of modes of the FermionSystem.")
     }
 }
+
+impl BravyiKitaevFermionToSpin for FermionSystem {
+    type Output = SpinSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinSystem)` - The spin system with `number_modes` spins that results from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionSystem has more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError> {
+        if self.number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        SpinSystem::from_operator(
+            self.operator().bravyi_kitaev(number_modes)?,
+            Some(number_modes),
+        )
+    }
+}
@@ -37,7 +37,7 @@ pub use fermionic_open_system::FermionLindbladOpenSystem;
 pub use fermionic_operator::FermionOperator;
 pub use fermionic_system::FermionSystem;
 
-use crate::{ModeIndex, OperateOnDensityMatrix, SymmetricIndex};
+use crate::{ModeIndex, OperateOnDensityMatrix, StruqtureError, SymmetricIndex};
 pub use fermionic_indices::{FermionProduct, HermitianFermionProduct};
 use qoqo_calculator::CalculatorComplex;
 
@@ -94,3 +94,87 @@ where
     &'a Self: IntoIterator,
 {
 }
+
+/// Returns the dimension of the occupation number basis of `number_modes` modes.
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of modes of the occupation number basis.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension `2^number_modes`.
+/// * `Err(StruqtureError::GenericError)` - The dimension does not fit into usize.
+pub(crate) fn fermion_basis_dimension(number_modes: usize) -> Result<usize, StruqtureError> {
+    u32::try_from(number_modes)
+        .ok()
+        .and_then(|number_modes| 1usize.checked_shl(number_modes))
+        .ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "The occupation number basis of {} modes cannot be represented, at most {} modes are supported.",
+                number_modes,
+                usize::BITS - 1
+            ),
+        })
+}
+
+/// Computes the non-zero matrix entries of a FermionProduct in the occupation number basis of `number_modes` modes.
+///
+/// The basis state index is the bit string of the occupations, with mode 0 as the least significant bit.
+/// The fermionic signs are obtained by counting the occupied modes with a lower index,
+/// which is the same convention as the Jordan-Wigner transformation.
+/// Only the basis states the product does not annihilate are visited: the annihilated modes have to be occupied
+/// and the modes that are only created have to be empty, all other modes can have any occupation.
+/// The number of modes must be at least the number of modes the product acts on.
+///
+/// # Arguments
+///
+/// * `product` - The FermionProduct for which to compute the matrix entries.
+/// * `number_modes` - The number of modes of the occupation number basis.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(usize, usize, f64)>)` - The (row, column, value) entries of the matrix, sorted by column.
+/// * `Err(StruqtureError::GenericError)` - The dimension of the occupation number basis does not fit into usize.
+pub(crate) fn fermion_product_matrix_entries(
+    product: &FermionProduct,
+    number_modes: usize,
+) -> Result<Vec<(usize, usize, f64)>, StruqtureError> {
+    let dimension = fermion_basis_dimension(number_modes)?;
+    let occupied: usize = product
+        .annihilators()
+        .fold(0, |mask, mode| mask | (1 << mode));
+    let empty: usize = product.creators().fold(0, |mask, mode| mask | (1 << mode)) & !occupied;
+    let free = (dimension - 1) & !(occupied | empty);
+    // Enumerates all subsets of the free modes in increasing order
+    let states = std::iter::successors(Some(0usize), |subset| {
+        (*subset != free).then(|| subset.wrapping_sub(free) & free)
+    })
+    .map(|subset| subset | occupied);
+    let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+    'states: for state in states {
+        let mut current = state;
+        let mut sign = 1.0;
+        // The rightmost operator acts first on the state
+        for mode in product.annihilators().rev() {
+            if current & (1 << mode) == 0 {
+                continue 'states;
+            }
+            if (current & ((1 << mode) - 1)).count_ones() % 2 == 1 {
+                sign = -sign;
+            }
+            current ^= 1 << mode;
+        }
+        for mode in product.creators().rev() {
+            if current & (1 << mode) != 0 {
+                continue 'states;
+            }
+            if (current & ((1 << mode) - 1)).count_ones() % 2 == 1 {
+                sign = -sign;
+            }
+            current |= 1 << mode;
+        }
+        entries.push((current, state, sign));
+    }
+    Ok(entries)
+}
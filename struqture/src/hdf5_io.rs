@@ -0,0 +1,518 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helper functions to write operators to and read operators from HDF5 groups.
+//!
+//! The terms of an operator are stored in datasets of equal length:
+//! `keys` (the string representation of the keys, sorted), `real` and `imag` (the real and
+//! imaginary parts of the coefficients). Hamiltonians with real coefficients only store `keys` and `real`,
+//! noise operators store the left and right operators of every term in `left_keys` and `right_keys`.
+//! The minimum struqture version required to read the data is stored in the `struqture_major_version`
+//! and `struqture_minor_version` attributes of the group.
+//!
+//! The number of spins or modes of a system is stored in an attribute that is only present when it is set.
+//! For mixed systems the numbers of every subsystem are stored in datasets, where -1 marks an unset number.
+//! Open systems store their system and noise parts in the subgroups `system` and `noise`.
+
+use crate::{
+    StruqtureError, StruqtureVersion, StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+};
+use hdf5::types::VarLenUnicode;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Converts an error of the hdf5 library into a StruqtureError.
+fn hdf5_error(err: hdf5::Error) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!("HDF5 operation failed: {}", err),
+    }
+}
+
+/// Converts a key into a variable length unicode string that can be stored in HDF5.
+fn to_unicode<K: Display>(key: &K) -> Result<VarLenUnicode, StruqtureError> {
+    VarLenUnicode::from_str(&key.to_string()).map_err(|err| StruqtureError::GenericError {
+        msg: format!("Key {} cannot be stored in HDF5: {}", key, err),
+    })
+}
+
+/// Writes a one-dimensional dataset into an HDF5 group.
+fn write_dataset<T: hdf5::H5Type>(
+    group: &hdf5::Group,
+    name: &str,
+    data: &[T],
+) -> Result<(), StruqtureError> {
+    group
+        .new_dataset_builder()
+        .with_data(data)
+        .create(name)
+        .map_err(hdf5_error)?;
+    Ok(())
+}
+
+/// Reads a one-dimensional dataset from an HDF5 group.
+fn read_dataset<T: hdf5::H5Type>(
+    group: &hdf5::Group,
+    name: &str,
+) -> Result<Vec<T>, StruqtureError> {
+    group
+        .dataset(name)
+        .map_err(hdf5_error)?
+        .read_raw()
+        .map_err(hdf5_error)
+}
+
+/// Writes the minimum struqture version required to read the data into the attributes of an HDF5 group.
+fn write_version(group: &hdf5::Group) -> Result<(), StruqtureError> {
+    group
+        .new_attr::<u32>()
+        .create("struqture_major_version")
+        .map_err(hdf5_error)?
+        .write_scalar(&MINIMUM_STRUQTURE_VERSION.0)
+        .map_err(hdf5_error)?;
+    group
+        .new_attr::<u32>()
+        .create("struqture_minor_version")
+        .map_err(hdf5_error)?
+        .write_scalar(&MINIMUM_STRUQTURE_VERSION.1)
+        .map_err(hdf5_error)?;
+    Ok(())
+}
+
+/// Checks that the data in an HDF5 group was written by a compatible version of struqture.
+fn check_version(group: &hdf5::Group) -> Result<(), StruqtureError> {
+    let major_version: u32 = group
+        .attr("struqture_major_version")
+        .map_err(hdf5_error)?
+        .read_scalar()
+        .map_err(hdf5_error)?;
+    let minor_version: u32 = group
+        .attr("struqture_minor_version")
+        .map_err(hdf5_error)?
+        .read_scalar()
+        .map_err(hdf5_error)?;
+    StruqtureVersion::try_from(StruqtureVersionSerializable {
+        major_version,
+        minor_version,
+    })?;
+    Ok(())
+}
+
+/// Checks that the datasets of the terms of an operator have the same length.
+fn check_lengths(lengths: &[(&str, usize)]) -> Result<(), StruqtureError> {
+    if lengths.iter().any(|(_, length)| *length != lengths[0].1) {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Datasets of HDF5 group have different lengths: {}",
+                lengths
+                    .iter()
+                    .map(|(name, length)| format!("{} {}", length, name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Writes the terms of an operator into an HDF5 group.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the datasets and attributes are written to.
+/// * `terms` - The (key, value) pairs of the operator.
+///
+/// # Returns
+///
+/// * `Ok(())` - The terms have been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+/// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+pub(crate) fn write_terms<'a, K, I>(group: &hdf5::Group, terms: I) -> Result<(), StruqtureError>
+where
+    K: Display + Ord + 'a,
+    I: Iterator<Item = (&'a K, &'a CalculatorComplex)>,
+{
+    let mut terms: Vec<(&K, &CalculatorComplex)> = terms.collect();
+    terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut keys: Vec<VarLenUnicode> = Vec::with_capacity(terms.len());
+    let mut real: Vec<f64> = Vec::with_capacity(terms.len());
+    let mut imag: Vec<f64> = Vec::with_capacity(terms.len());
+    for (key, value) in terms {
+        keys.push(to_unicode(key)?);
+        real.push(value.re.clone().try_into()?);
+        imag.push(value.im.clone().try_into()?);
+    }
+    write_dataset(group, "keys", &keys)?;
+    write_dataset(group, "real", &real)?;
+    write_dataset(group, "imag", &imag)?;
+    write_version(group)
+}
+
+/// Reads the terms of an operator from an HDF5 group written with [write_terms].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the datasets and attributes.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(K, CalculatorComplex)>)` - The (key, value) pairs of the operator.
+/// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed or the datasets do not match.
+/// * `Err(StruqtureError)` - A key could not be parsed.
+pub(crate) fn read_terms<K>(
+    group: &hdf5::Group,
+) -> Result<Vec<(K, CalculatorComplex)>, StruqtureError>
+where
+    K: FromStr<Err = StruqtureError>,
+{
+    check_version(group)?;
+    let keys: Vec<VarLenUnicode> = read_dataset(group, "keys")?;
+    let real: Vec<f64> = read_dataset(group, "real")?;
+    let imag: Vec<f64> = read_dataset(group, "imag")?;
+    check_lengths(&[
+        ("keys", keys.len()),
+        ("real parts", real.len()),
+        ("imaginary parts", imag.len()),
+    ])?;
+    keys.iter()
+        .zip(real.into_iter().zip(imag))
+        .map(|(key, (re, im))| Ok((K::from_str(key.as_str())?, CalculatorComplex::new(re, im))))
+        .collect()
+}
+
+/// Writes the terms of an operator with real coefficients into an HDF5 group.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the datasets and attributes are written to.
+/// * `terms` - The (key, value) pairs of the operator.
+///
+/// # Returns
+///
+/// * `Ok(())` - The terms have been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+/// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+pub(crate) fn write_real_terms<'a, K, I>(
+    group: &hdf5::Group,
+    terms: I,
+) -> Result<(), StruqtureError>
+where
+    K: Display + Ord + 'a,
+    I: Iterator<Item = (&'a K, &'a CalculatorFloat)>,
+{
+    let mut terms: Vec<(&K, &CalculatorFloat)> = terms.collect();
+    terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut keys: Vec<VarLenUnicode> = Vec::with_capacity(terms.len());
+    let mut real: Vec<f64> = Vec::with_capacity(terms.len());
+    for (key, value) in terms {
+        keys.push(to_unicode(key)?);
+        real.push(value.clone().try_into()?);
+    }
+    write_dataset(group, "keys", &keys)?;
+    write_dataset(group, "real", &real)?;
+    write_version(group)
+}
+
+/// Reads the terms of an operator with real coefficients from an HDF5 group written with [write_real_terms].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the datasets and attributes.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(K, CalculatorFloat)>)` - The (key, value) pairs of the operator.
+/// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed or the datasets do not match.
+/// * `Err(StruqtureError)` - A key could not be parsed.
+pub(crate) fn read_real_terms<K>(
+    group: &hdf5::Group,
+) -> Result<Vec<(K, CalculatorFloat)>, StruqtureError>
+where
+    K: FromStr<Err = StruqtureError>,
+{
+    check_version(group)?;
+    let keys: Vec<VarLenUnicode> = read_dataset(group, "keys")?;
+    let real: Vec<f64> = read_dataset(group, "real")?;
+    check_lengths(&[("keys", keys.len()), ("real parts", real.len())])?;
+    keys.iter()
+        .zip(real)
+        .map(|(key, re)| Ok((K::from_str(key.as_str())?, CalculatorFloat::from(re))))
+        .collect()
+}
+
+/// Writes the terms of a noise operator into an HDF5 group.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the datasets and attributes are written to.
+/// * `terms` - The ((left, right), value) pairs of the noise operator.
+///
+/// # Returns
+///
+/// * `Ok(())` - The terms have been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+/// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+pub(crate) fn write_noise_terms<'a, K, I>(
+    group: &hdf5::Group,
+    terms: I,
+) -> Result<(), StruqtureError>
+where
+    K: Display + Ord + 'a,
+    I: Iterator<Item = (&'a (K, K), &'a CalculatorComplex)>,
+{
+    let mut terms: Vec<(&(K, K), &CalculatorComplex)> = terms.collect();
+    terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut left_keys: Vec<VarLenUnicode> = Vec::with_capacity(terms.len());
+    let mut right_keys: Vec<VarLenUnicode> = Vec::with_capacity(terms.len());
+    let mut real: Vec<f64> = Vec::with_capacity(terms.len());
+    let mut imag: Vec<f64> = Vec::with_capacity(terms.len());
+    for ((left, right), value) in terms {
+        left_keys.push(to_unicode(left)?);
+        right_keys.push(to_unicode(right)?);
+        real.push(value.re.clone().try_into()?);
+        imag.push(value.im.clone().try_into()?);
+    }
+    write_dataset(group, "left_keys", &left_keys)?;
+    write_dataset(group, "right_keys", &right_keys)?;
+    write_dataset(group, "real", &real)?;
+    write_dataset(group, "imag", &imag)?;
+    write_version(group)
+}
+
+/// Reads the terms of a noise operator from an HDF5 group written with [write_noise_terms].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the datasets and attributes.
+///
+/// # Returns
+///
+/// * `Ok(Vec<((K, K), CalculatorComplex)>)` - The ((left, right), value) pairs of the noise operator.
+/// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed or the datasets do not match.
+/// * `Err(StruqtureError)` - A key could not be parsed.
+pub(crate) fn read_noise_terms<K>(
+    group: &hdf5::Group,
+) -> Result<Vec<((K, K), CalculatorComplex)>, StruqtureError>
+where
+    K: FromStr<Err = StruqtureError>,
+{
+    check_version(group)?;
+    let left_keys: Vec<VarLenUnicode> = read_dataset(group, "left_keys")?;
+    let right_keys: Vec<VarLenUnicode> = read_dataset(group, "right_keys")?;
+    let real: Vec<f64> = read_dataset(group, "real")?;
+    let imag: Vec<f64> = read_dataset(group, "imag")?;
+    check_lengths(&[
+        ("left keys", left_keys.len()),
+        ("right keys", right_keys.len()),
+        ("real parts", real.len()),
+        ("imaginary parts", imag.len()),
+    ])?;
+    left_keys
+        .iter()
+        .zip(right_keys.iter())
+        .zip(real.into_iter().zip(imag))
+        .map(|((left, right), (re, im))| {
+            Ok((
+                (K::from_str(left.as_str())?, K::from_str(right.as_str())?),
+                CalculatorComplex::new(re, im),
+            ))
+        })
+        .collect()
+}
+
+/// Writes a number into an attribute of an HDF5 group.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the attribute is written to.
+/// * `name` - The name of the attribute.
+/// * `number` - The number to write.
+///
+/// # Returns
+///
+/// * `Ok(())` - The number has been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+pub(crate) fn write_number(
+    group: &hdf5::Group,
+    name: &str,
+    number: usize,
+) -> Result<(), StruqtureError> {
+    group
+        .new_attr::<u64>()
+        .create(name)
+        .map_err(hdf5_error)?
+        .write_scalar(&(number as u64))
+        .map_err(hdf5_error)
+}
+
+/// Reads a number from an attribute of an HDF5 group written with [write_number].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the attribute.
+/// * `name` - The name of the attribute.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number stored in the attribute.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+pub(crate) fn read_number(group: &hdf5::Group, name: &str) -> Result<usize, StruqtureError> {
+    let number: u64 = group
+        .attr(name)
+        .map_err(hdf5_error)?
+        .read_scalar()
+        .map_err(hdf5_error)?;
+    Ok(number as usize)
+}
+
+/// Writes an optional number of spins or modes of a system into an attribute of an HDF5 group.
+///
+/// The attribute is only created when the number is set.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the attribute is written to.
+/// * `name` - The name of the attribute.
+/// * `number` - The optional number to write.
+///
+/// # Returns
+///
+/// * `Ok(())` - The number has been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+pub(crate) fn write_optional_number(
+    group: &hdf5::Group,
+    name: &str,
+    number: Option<usize>,
+) -> Result<(), StruqtureError> {
+    match number {
+        Some(number) => write_number(group, name, number),
+        None => Ok(()),
+    }
+}
+
+/// Reads an optional number of spins or modes of a system written with [write_optional_number].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the attribute.
+/// * `name` - The name of the attribute.
+///
+/// # Returns
+///
+/// * `Ok(Option<usize>)` - The number stored in the attribute, `None` if the attribute is not present.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+pub(crate) fn read_optional_number(
+    group: &hdf5::Group,
+    name: &str,
+) -> Result<Option<usize>, StruqtureError> {
+    let names = group.attr_names().map_err(hdf5_error)?;
+    if names.iter().any(|attribute| attribute == name) {
+        Ok(Some(read_number(group, name)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Writes the optional numbers of spins or modes of the subsystems of a mixed system into a dataset of an HDF5 group.
+///
+/// Unset numbers are stored as -1.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the dataset is written to.
+/// * `name` - The name of the dataset.
+/// * `numbers` - The optional numbers of the subsystems.
+///
+/// # Returns
+///
+/// * `Ok(())` - The numbers have been written to the group.
+/// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+pub(crate) fn write_optional_numbers(
+    group: &hdf5::Group,
+    name: &str,
+    numbers: &[Option<usize>],
+) -> Result<(), StruqtureError> {
+    let numbers: Vec<i64> = numbers
+        .iter()
+        .map(|number| number.map_or(-1, |number| number as i64))
+        .collect();
+    write_dataset(group, name, &numbers)
+}
+
+/// Reads the optional numbers of spins or modes of the subsystems of a mixed system written with [write_optional_numbers].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the dataset.
+/// * `name` - The name of the dataset.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Option<usize>>)` - The optional numbers of the subsystems.
+/// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+pub(crate) fn read_optional_numbers(
+    group: &hdf5::Group,
+    name: &str,
+) -> Result<Vec<Option<usize>>, StruqtureError> {
+    let numbers: Vec<i64> = read_dataset(group, name)?;
+    Ok(numbers
+        .into_iter()
+        .map(|number| {
+            if number < 0 {
+                None
+            } else {
+                Some(number as usize)
+            }
+        })
+        .collect())
+}
+
+/// Creates the subgroups `system` and `noise` of an open system in an HDF5 group.
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group the subgroups are created in.
+///
+/// # Returns
+///
+/// * `Ok((hdf5::Group, hdf5::Group))` - The system and noise subgroups.
+/// * `Err(StruqtureError::GenericError)` - Creating the subgroups failed.
+pub(crate) fn create_open_system_groups(
+    group: &hdf5::Group,
+) -> Result<(hdf5::Group, hdf5::Group), StruqtureError> {
+    Ok((
+        group.create_group("system").map_err(hdf5_error)?,
+        group.create_group("noise").map_err(hdf5_error)?,
+    ))
+}
+
+/// Opens the subgroups `system` and `noise` of an open system written with [create_open_system_groups].
+///
+/// # Arguments
+///
+/// * `group` - The HDF5 group containing the subgroups.
+///
+/// # Returns
+///
+/// * `Ok((hdf5::Group, hdf5::Group))` - The system and noise subgroups.
+/// * `Err(StruqtureError::GenericError)` - Opening the subgroups failed.
+pub(crate) fn open_system_groups(
+    group: &hdf5::Group,
+) -> Result<(hdf5::Group, hdf5::Group), StruqtureError> {
+    Ok((
+        group.group("system").map_err(hdf5_error)?,
+        group.group("noise").map_err(hdf5_error)?,
+    ))
+}
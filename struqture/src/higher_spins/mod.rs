@@ -0,0 +1,92 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Module for representing physical systems of spins with arbitrary spin quantum number S.
+//!
+//! The operators are built from the spin ladder operators S^+, S^- and the spin z operator S^z.
+//! The spin quantum number of every site is only required when constructing the matrix representation,
+//! where each site with spin S contributes a local Hilbert space of dimension 2S + 1.
+
+use crate::StruqtureError;
+
+mod spin_s_product;
+pub use spin_s_product::*;
+
+mod spin_s_operator;
+pub use spin_s_operator::*;
+
+/// Returns the local Hilbert space dimensions `2S + 1` for a list of spin quantum numbers S.
+///
+/// # Arguments
+///
+/// * `spins` - The spin quantum numbers S of the sites.
+///
+/// # Returns
+///
+/// * `Ok(Vec<usize>)` - The local Hilbert space dimensions of the sites.
+/// * `Err(StruqtureError::GenericError)` - A spin quantum number is not a finite positive multiple of 1/2.
+/// * `Err(StruqtureError::GenericError)` - The dimension of the Hilbert space of all sites does not fit into usize.
+pub(crate) fn local_dimensions(spins: &[f64]) -> Result<Vec<usize>, StruqtureError> {
+    let dimensions = spins
+        .iter()
+        .map(|spin| {
+            let twice_spin = 2.0 * spin;
+            if !twice_spin.is_finite()
+                || twice_spin < 1.0
+                || (twice_spin - twice_spin.round()).abs() > 1e-10
+            {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "The spin quantum number {} is not a finite positive multiple of 1/2.",
+                        spin
+                    ),
+                });
+            }
+            // The conversion saturates at usize::MAX, so that the addition fails for too large spins
+            (twice_spin.round() as usize)
+                .checked_add(1)
+                .ok_or_else(|| StruqtureError::GenericError {
+                    msg: format!(
+                        "The local Hilbert space of the spin quantum number {} cannot be represented.",
+                        spin
+                    ),
+                })
+        })
+        .collect::<Result<Vec<usize>, StruqtureError>>()?;
+    hilbert_space_dimension(&dimensions)?;
+    Ok(dimensions)
+}
+
+/// Returns the dimension of the Hilbert space of sites with the given local Hilbert space dimensions.
+///
+/// # Arguments
+///
+/// * `dimensions` - The local Hilbert space dimensions 2S + 1 of all sites.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension `prod_i dimensions[i]`.
+/// * `Err(StruqtureError::GenericError)` - The dimension does not fit into usize.
+pub(crate) fn hilbert_space_dimension(dimensions: &[usize]) -> Result<usize, StruqtureError> {
+    dimensions
+        .iter()
+        .try_fold(1usize, |dimension, local_dimension| {
+            dimension.checked_mul(*local_dimension)
+        })
+        .ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "The Hilbert space with the local dimensions {:?} cannot be represented, its dimension exceeds {}.",
+                dimensions,
+                usize::MAX
+            ),
+        })
+}
@@ -0,0 +1,524 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{local_dimensions, SpinSProduct};
+use crate::{
+    sorted_coo_from_entries, CooSparseMatrix, OperateOnDensityMatrix, OperateOnState,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+};
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::{Entry, Iter, Keys, Values};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::IndexMap;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::{Entry, Iter, Keys, Values};
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::HashMap;
+
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+/// SpinSOperators are combinations of SpinSProducts with specific CalculatorComplex coefficients.
+///
+/// This is a representation of sums of products of spin S operators with weightings, in order to build a full hamiltonian.
+/// The spin quantum numbers of the sites are only specified when constructing the matrix representation.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::higher_spins::{SpinSOperator, SpinSProduct};
+///
+/// let mut so = SpinSOperator::new();
+///
+/// // Representing the spin-1 hamiltonian $ S_0^{+} S_1^{-} + S_0^{-} S_1^{+} + 0.5 (S_0^{z})^2 $
+/// so.add_operator_product(SpinSProduct::new().plus(0).minus(1), CalculatorComplex::from(1.0)).unwrap();
+/// so.add_operator_product(SpinSProduct::new().minus(0).plus(1), CalculatorComplex::from(1.0)).unwrap();
+/// so.add_operator_product(SpinSProduct::new().z(0).z(0), CalculatorComplex::from(0.5)).unwrap();
+///
+/// let (values, (rows, columns)) = so.sparse_matrix_coo(&[1.0, 1.0]).unwrap();
+/// assert_eq!(values.len(), rows.len());
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SpinSOperatorSerialize")]
+#[serde(into = "SpinSOperatorSerialize")]
+pub struct SpinSOperator {
+    // The internal HashMap of SpinSProducts and coefficients (CalculatorComplex)
+    #[cfg(feature = "indexed_map_iterators")]
+    internal_map: IndexMap<SpinSProduct, CalculatorComplex>,
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    internal_map: HashMap<SpinSProduct, CalculatorComplex>,
+}
+
+impl crate::MinSupportedVersion for SpinSOperator {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 10, 0)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for SpinSOperator {
+    fn schema_name() -> String {
+        "SpinSOperator".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <SpinSOperatorSerialize>::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct SpinSOperatorSerialize {
+    items: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)>,
+    _struqture_version: StruqtureVersionSerializable,
+}
+
+impl From<SpinSOperatorSerialize> for SpinSOperator {
+    fn from(value: SpinSOperatorSerialize) -> Self {
+        let new_op: SpinSOperator = value
+            .items
+            .into_iter()
+            .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
+            .collect();
+        new_op
+    }
+}
+
+impl From<SpinSOperator> for SpinSOperatorSerialize {
+    fn from(value: SpinSOperator) -> Self {
+        let new_op: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)> = value
+            .into_iter()
+            .map(|(key, val)| (key, val.re, val.im))
+            .collect();
+        let current_version = StruqtureVersionSerializable {
+            major_version: 1,
+            minor_version: 10,
+        };
+        Self {
+            items: new_op,
+            _struqture_version: current_version,
+        }
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for SpinSOperator {
+    type IteratorType = Iter<'a, Self::Index, Self::Value>;
+    type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
+    type ValueIteratorType = Values<'a, Self::Index, Self::Value>;
+    type Value = CalculatorComplex;
+    type Index = SpinSProduct;
+
+    // From trait
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> Self::IteratorType {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> Self::KeyIteratorType {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> Self::ValueIteratorType {
+        self.internal_map.values()
+    }
+
+    #[cfg(feature = "indexed_map_iterators")]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.shift_remove(key)
+    }
+
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.remove(key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        }
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the SpinSOperator with the given (SpinSProduct key, CalculatorComplex value) pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The SpinSProduct key to set in the SpinSOperator.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        if value != CalculatorComplex::ZERO {
+            Ok(self.internal_map.insert(key, value))
+        } else {
+            match self.internal_map.entry(key) {
+                #[cfg(feature = "indexed_map_iterators")]
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                #[cfg(not(feature = "indexed_map_iterators"))]
+                Entry::Occupied(val) => Ok(Some(val.remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+}
+
+impl OperateOnState<'_> for SpinSOperator {
+    // From trait
+    fn hermitian_conjugate(&self) -> Self {
+        let mut new_operator = Self::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let (new_product, prefactor) = product.hermitian_conjugate();
+            new_operator
+                .add_operator_product(new_product, value.conj() * prefactor)
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+}
+
+/// Implements the default function (Default trait) of SpinSOperator (an empty SpinSOperator).
+///
+impl Default for SpinSOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the SpinSOperator
+///
+impl SpinSOperator {
+    /// Creates a new SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSOperator.
+    pub fn new() -> Self {
+        SpinSOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::new(),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::new(),
+        }
+    }
+
+    /// Creates a new SpinSOperator with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the operator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSOperator.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpinSOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::with_capacity(capacity),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of spins the SpinSOperator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Maximum spin index plus one.
+    pub fn current_number_spins(&self) -> usize {
+        self.internal_map
+            .keys()
+            .map(|key| key.current_number_spins())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Constructs the sparse matrix representation of the SpinSOperator in COO format.
+    ///
+    /// Every site i with spin quantum number S_i contributes a local Hilbert space of dimension 2S_i + 1
+    /// with the basis |S_i>, |S_i - 1>, ..., |-S_i>. Site 0 corresponds to the least significant digit of the
+    /// basis state index, the full matrix has dimension prod_i (2S_i + 1).
+    ///
+    /// # Arguments
+    ///
+    /// * `spins` - The spin quantum numbers S of all sites (e.g. 0.5, 1.0, 1.5).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the operator.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The SpinSOperator acts on more sites than spin quantum numbers are given.
+    /// * `Err(StruqtureError::GenericError)` - A spin quantum number is not a finite positive multiple of 1/2.
+    /// * `Err(StruqtureError::GenericError)` - The dimension of the matrix does not fit into usize.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(&self, spins: &[f64]) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.current_number_spins() > spins.len() {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimensions = local_dimensions(spins)?;
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for (product, value) in self.iter() {
+            let coefficient = Complex64::new(*value.re.float()?, *value.im.float()?);
+            for (row, column, matrix_value) in product.matrix_entries(&dimensions)? {
+                *entries
+                    .entry((row, column))
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += coefficient * matrix_value;
+            }
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+}
+
+/// Implements the negative sign function of SpinSOperator.
+///
+impl ops::Neg for SpinSOperator {
+    type Output = SpinSOperator;
+    /// Implement minus sign for SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSOperator * -1.
+    fn neg(self) -> Self {
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key.clone(), val.neg());
+        }
+        SpinSOperator {
+            internal_map: internal,
+        }
+    }
+}
+
+/// Implements the plus function of SpinSOperator by SpinSOperator.
+///
+impl<T, V> ops::Add<T> for SpinSOperator
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `+` (add) for two SpinSOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSOperator to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two SpinSOperators added together.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the minus function of SpinSOperator by SpinSOperator.
+///
+impl<T, V> ops::Sub<T> for SpinSOperator
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `-` (subtract) for two SpinSOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSOperator to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two SpinSOperators subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the multiplication function of SpinSOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::Mul<T> for SpinSOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implement `*` for SpinSOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSOperator multiplied by the CalculatorComplex/CalculatorFloat.
+    fn mul(self, other: T) -> Self {
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val * other_cc.clone());
+        }
+        SpinSOperator {
+            internal_map: internal,
+        }
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of SpinSOperator.
+///
+impl IntoIterator for SpinSOperator {
+    type Item = (SpinSProduct, CalculatorComplex);
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    type IntoIter = std::collections::hash_map::IntoIter<SpinSProduct, CalculatorComplex>;
+    #[cfg(feature = "indexed_map_iterators")]
+    type IntoIter = indexmap::map::IntoIter<SpinSProduct, CalculatorComplex>;
+
+    /// Returns the SpinSOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The SpinSOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference SpinSOperator.
+///
+impl<'a> IntoIterator for &'a SpinSOperator {
+    type Item = (&'a SpinSProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, SpinSProduct, CalculatorComplex>;
+
+    /// Returns the reference SpinSOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference SpinSOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of SpinSOperator.
+///
+impl FromIterator<(SpinSProduct, CalculatorComplex)> for SpinSOperator {
+    /// Returns the object in SpinSOperator form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in SpinSOperator form.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn from_iter<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut so = SpinSOperator::new();
+        for (sp, cc) in iter {
+            so.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+        so
+    }
+}
+
+/// Implements the extend function (Extend trait) of SpinSOperator.
+///
+impl Extend<(SpinSProduct, CalculatorComplex)> for SpinSOperator {
+    /// Extends the SpinSOperator by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the SpinSOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn extend<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (sp, cc) in iter {
+            self.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of SpinSOperator.
+///
+impl fmt::Display for SpinSOperator {
+    /// Formats the SpinSOperator using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinSOperator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "SpinSOperator{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
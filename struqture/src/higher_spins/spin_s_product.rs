@@ -0,0 +1,696 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::hilbert_space_dimension;
+use crate::{StruqtureError, SymmetricIndex};
+use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{FromIterator, IntoIterator};
+use std::str::FromStr;
+use tinyvec::{TinyVec, TinyVecIterator};
+
+/// Single site operators for SpinSProducts.
+///
+/// The operators act on the 2S + 1 dimensional Hilbert space of a spin with spin quantum number S,
+/// spanned by the eigenstates |m> of S^z with m = S, S - 1, ..., -S (in this order).
+///
+/// I: identity matrix
+///
+/// Plus: spin raising operator S^+ with $ S^+ |m> = \sqrt{S(S+1) - m(m+1)} |m+1> $
+///
+/// Minus: spin lowering operator S^- with $ S^- |m> = \sqrt{S(S+1) - m(m-1)} |m-1> $
+///
+/// Z: spin z operator S^z with $ S^z |m> = m |m> $
+///
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SingleSpinSOperator {
+    Identity,
+    Plus,
+    Minus,
+    Z,
+}
+
+/// Creates a SingleSpinSOperator from an &str representation.
+///
+/// # Arguments
+///
+/// * `s` - The string (&str) to be converted to a SingleSpinSOperator.
+///
+/// # Returns
+///
+/// * `Ok(Self)` - The SingleSpinSOperator of the input string.
+/// * `Err(StruqtureError::IncorrectPauliEntry)` - The operator being set is not in [\"I\", \"+\", \"-\", \"Z\"].
+///
+impl FromStr for SingleSpinSOperator {
+    type Err = StruqtureError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "I" => Ok(SingleSpinSOperator::Identity),
+            "+" => Ok(SingleSpinSOperator::Plus),
+            "-" => Ok(SingleSpinSOperator::Minus),
+            "Z" => Ok(SingleSpinSOperator::Z),
+            _ => Err(StruqtureError::IncorrectPauliEntry {
+                pauli: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Implements the default function (Default trait) of SingleSpinSOperator (an Identity SingleSpinSOperator).
+///
+impl Default for SingleSpinSOperator {
+    fn default() -> Self {
+        SingleSpinSOperator::Identity
+    }
+}
+
+/// Implements the fmt function (Display trait) of SingleSpinSOperator.
+///
+impl fmt::Display for SingleSpinSOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SingleSpinSOperator::Identity => write!(f, "I"),
+            SingleSpinSOperator::Plus => write!(f, "+"),
+            SingleSpinSOperator::Minus => write!(f, "-"),
+            SingleSpinSOperator::Z => write!(f, "Z"),
+        }
+    }
+}
+
+/// Functions for the SingleSpinSOperator
+///
+impl SingleSpinSOperator {
+    /// Returns the hermitian conjugate of the SingleSpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian conjugate (S^+ and S^- are exchanged).
+    pub fn hermitian_conjugate(&self) -> Self {
+        match self {
+            SingleSpinSOperator::Plus => SingleSpinSOperator::Minus,
+            SingleSpinSOperator::Minus => SingleSpinSOperator::Plus,
+            x => *x,
+        }
+    }
+
+    /// Returns the dense matrix of the SingleSpinSOperator on a site with the given local dimension 2S + 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - The local Hilbert space dimension 2S + 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Vec<f64>>` - The (row-major) matrix of the operator in the basis |S>, |S - 1>, ..., |-S>.
+    pub fn local_matrix(&self, dimension: usize) -> Vec<Vec<f64>> {
+        let spin = (dimension as f64 - 1.0) / 2.0;
+        let mut matrix = vec![vec![0.0; dimension]; dimension];
+        for (k, row) in matrix.iter_mut().enumerate() {
+            let m = spin - k as f64;
+            match self {
+                SingleSpinSOperator::Identity => row[k] = 1.0,
+                SingleSpinSOperator::Z => row[k] = m,
+                // <m| S^+ |m - 1> with |m - 1> being the basis state k + 1
+                SingleSpinSOperator::Plus => {
+                    if k + 1 < dimension {
+                        row[k + 1] = (spin * (spin + 1.0) - m * (m - 1.0)).sqrt();
+                    }
+                }
+                // <m| S^- |m + 1> with |m + 1> being the basis state k - 1
+                SingleSpinSOperator::Minus => {
+                    if k > 0 {
+                        row[k - 1] = (spin * (spin + 1.0) - m * (m + 1.0)).sqrt();
+                    }
+                }
+            }
+        }
+        matrix
+    }
+}
+
+/// SpinSProducts are products of SingleSpinSOperators acting on spins with arbitrary spin quantum number S.
+///
+/// In contrast to products of Pauli matrices, products of spin operators acting on the same site
+/// can not be reduced to a single operator without knowing the spin quantum number S.
+/// Several operators can therefore act on the same site. The entries are sorted by site,
+/// operators on the same site are multiplied in the order in which they were added.
+/// For instance, to represent the term $ S_0^{+} (S_1^{z})^2 $ :
+/// ` SpinSProduct::new().plus(0).z(1).z(1) `
+///
+/// # Example
+///
+/// ```
+/// use struqture::higher_spins::{SingleSpinSOperator, SpinSProduct};
+///
+/// let sp = SpinSProduct::new().plus(0).z(1).z(1);
+/// assert_eq!(sp.to_string(), "0+1Z1Z");
+/// assert_eq!(sp.get(&1), vec![SingleSpinSOperator::Z, SingleSpinSOperator::Z]);
+/// ```
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SpinSProduct {
+    /// The internal list of sites and single site operators (Plus, Minus, Z)
+    items: TinyVec<[(usize, SingleSpinSOperator); 5]>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for SpinSProduct {
+    fn schema_name() -> String {
+        "struqture::higher_spins::SpinSProduct".to_string()
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let tmp_schema = gen.subschema_for::<String>();
+        let mut obj = tmp_schema.into_object();
+        let meta = obj.metadata();
+        meta.description = Some("Represents products of spin S operators (Plus, Minus, Z) by a string of spin numbers followed by the operators. E.g. 0+1Z1Z3-.".to_string());
+
+        schemars::schema::Schema::Object(obj)
+    }
+}
+
+impl crate::MinSupportedVersion for SpinSProduct {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 10, 0)
+    }
+}
+
+/// Implementing serde serialization writing directly to string.
+///
+impl Serialize for SpinSProduct {
+    /// Serialization function for SpinSProduct according to string type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinSProduct to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinSProduct.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let readable = serializer.is_human_readable();
+        if readable {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut sequence = serializer.serialize_seq(Some(self.items.len()))?;
+            for item in self.items.iter() {
+                sequence.serialize_element(item)?;
+            }
+            sequence.end()
+        }
+    }
+}
+
+/// Deserializing directly from string.
+///
+impl<'de> Deserialize<'de> for SpinSProduct {
+    /// Deserialization function for SpinSProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Serialized instance of SpinSProduct to be deserialized.
+    /// * `deserializer` - Deserializer used for deserialization.
+    ///
+    /// # Returns
+    ///
+    /// `SpinSProduct` - Deserialized instance of SpinSProduct.
+    /// `D::Error` - Error in the deserialization process.
+    fn deserialize<D>(deserializer: D) -> Result<SpinSProduct, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+        if human_readable {
+            struct TemporaryVisitor;
+            impl<'de> Visitor<'de> for TemporaryVisitor {
+                type Value = SpinSProduct;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("String")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    SpinSProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+
+                fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+                where
+                    E: Error,
+                {
+                    SpinSProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+            }
+
+            deserializer.deserialize_str(TemporaryVisitor)
+        } else {
+            struct SpinSProductVisitor;
+            impl<'de> serde::de::Visitor<'de> for SpinSProductVisitor {
+                type Value = SpinSProduct;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Formatter::write_str(formatter, "Identifier of SpinSProduct variant")
+                }
+                // when variants are marked by String values
+                fn visit_seq<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+                where
+                    M: SeqAccess<'de>,
+                {
+                    let mut sp = SpinSProduct::new();
+                    while let Some(item) = access.next_element()? {
+                        let entry: Entry = item;
+                        sp = sp.add_operator(entry.0 .0, entry.0 .1);
+                    }
+                    Ok(sp)
+                }
+            }
+            #[derive(Deserialize)]
+            #[serde(transparent)]
+            struct Entry((usize, SingleSpinSOperator));
+            let sp_visitor = SpinSProductVisitor;
+
+            deserializer.deserialize_seq(sp_visitor)
+        }
+    }
+}
+
+/// Functions for the SpinSProduct
+///
+impl SpinSProduct {
+    /// Creates a new Self typed object.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) instance of type `Self`.
+    pub fn new() -> Self {
+        SpinSProduct {
+            items: TinyVec::<[(usize, SingleSpinSOperator); 5]>::with_capacity(5),
+        }
+    }
+
+    /// Creates a new SpinSProduct with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The capacity of the SpinSProduct to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSProduct.
+    pub fn with_capacity(cap: usize) -> Self {
+        SpinSProduct {
+            items: TinyVec::<[(usize, SingleSpinSOperator); 5]>::with_capacity(cap),
+        }
+    }
+
+    /// Multiplies Self from the right with a single site operator. This function consumes Self.
+    ///
+    /// The operator is placed after all operators already acting on the same site.
+    /// Identity operators are not stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The site the operator acts on.
+    /// * `operator` - The single site operator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSProduct with the operator added.
+    pub fn add_operator(self, index: usize, operator: SingleSpinSOperator) -> Self {
+        let mut sp = self;
+        if operator != SingleSpinSOperator::Identity {
+            let position = sp
+                .items
+                .iter()
+                .position(|(inner_index, _)| inner_index > &index)
+                .unwrap_or(sp.items.len());
+            sp.items.insert(position, (index, operator));
+        }
+        sp
+    }
+
+    /// Multiplies Self from the right with the spin raising operator S^+ on a site. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The site the operator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSProduct with the operator added.
+    pub fn plus(self, index: usize) -> Self {
+        self.add_operator(index, SingleSpinSOperator::Plus)
+    }
+
+    /// Multiplies Self from the right with the spin lowering operator S^- on a site. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The site the operator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSProduct with the operator added.
+    pub fn minus(self, index: usize) -> Self {
+        self.add_operator(index, SingleSpinSOperator::Minus)
+    }
+
+    /// Multiplies Self from the right with the spin z operator S^z on a site. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The site the operator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSProduct with the operator added.
+    pub fn z(self, index: usize) -> Self {
+        self.add_operator(index, SingleSpinSOperator::Z)
+    }
+
+    /// Gets the operators acting on a site, in the order in which they are multiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The site to get the operators for.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SingleSpinSOperator>` - The operators acting on the site, empty for the identity.
+    pub fn get(&self, index: &usize) -> Vec<SingleSpinSOperator> {
+        self.items
+            .iter()
+            .filter(|(key, _)| key == index)
+            .map(|(_, operator)| *operator)
+            .collect()
+    }
+
+    /// Returns the iterator form of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Iter<(usize, SingleSpinSOperator)>` - The iterator form of Self.
+    pub fn iter(&self) -> std::slice::Iter<(usize, SingleSpinSOperator)> {
+        match &self.items {
+            TinyVec::Heap(x) => x.iter(),
+            TinyVec::Inline(x) => x.iter(),
+        }
+    }
+
+    /// Returns maximum index in Self.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Maximum index.
+    pub fn current_number_spins(&self) -> usize {
+        if let Some((max, _)) = self.iter().last() {
+            *max + 1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of single site operators in the SpinSProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of single site operators.
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+
+    /// Returns whether the SpinSProduct is empty (the identity) or not.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the SpinSProduct is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.iter().len() == 0
+    }
+
+    /// Returns the entries of the matrix representation of the SpinSProduct.
+    ///
+    /// Site 0 corresponds to the least significant digit of the basis state index, the local basis of a site
+    /// with dimension 2S + 1 is |S>, |S - 1>, ..., |-S>.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimensions` - The local Hilbert space dimensions 2S + 1 of all sites.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(usize, usize, f64)>)` - The non-zero (row, column, value) entries of the matrix.
+    /// * `Err(StruqtureError::GenericError)` - The dimension of the Hilbert space does not fit into usize.
+    pub(crate) fn matrix_entries(
+        &self,
+        dimensions: &[usize],
+    ) -> Result<Vec<(usize, usize, f64)>, StruqtureError> {
+        // The strides are the partial products of the local dimensions, so they cannot overflow if the dimension does not
+        let dimension = hilbert_space_dimension(dimensions)?;
+        let mut strides: Vec<usize> = Vec::with_capacity(dimensions.len());
+        let mut stride = 1;
+        for local_dimension in dimensions.iter() {
+            strides.push(stride);
+            stride *= local_dimension;
+        }
+        // The product of all operators acting on the same site
+        let mut local_matrices: Vec<(usize, Vec<Vec<f64>>)> = Vec::new();
+        for (index, operator) in self.iter() {
+            let local_dimension = dimensions[*index];
+            let operator_matrix = operator.local_matrix(local_dimension);
+            match local_matrices.last_mut() {
+                Some((last_index, matrix)) if last_index == index => {
+                    let mut product = vec![vec![0.0; local_dimension]; local_dimension];
+                    for (row, product_row) in product.iter_mut().enumerate() {
+                        for (column, entry) in product_row.iter_mut().enumerate() {
+                            *entry = (0..local_dimension)
+                                .map(|k| matrix[row][k] * operator_matrix[k][column])
+                                .sum();
+                        }
+                    }
+                    *matrix = product;
+                }
+                _ => local_matrices.push((*index, operator_matrix)),
+            }
+        }
+
+        let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+        for column in 0..dimension {
+            let mut column_entries: Vec<(usize, f64)> = vec![(column, 1.0)];
+            for (index, matrix) in local_matrices.iter() {
+                let local_column = (column / strides[*index]) % dimensions[*index];
+                let mut new_entries: Vec<(usize, f64)> = Vec::new();
+                for (row, value) in column_entries.iter() {
+                    for (local_row, matrix_row) in matrix.iter().enumerate() {
+                        let local_value = matrix_row[local_column];
+                        if local_value != 0.0 {
+                            new_entries.push((
+                                row - local_column * strides[*index] + local_row * strides[*index],
+                                value * local_value,
+                            ));
+                        }
+                    }
+                }
+                column_entries = new_entries;
+            }
+            entries.extend(
+                column_entries
+                    .into_iter()
+                    .map(|(row, value)| (row, column, value)),
+            );
+        }
+        Ok(entries)
+    }
+}
+
+/// Implements Ord for SpinSProduct; length then lexicographic sorting
+///
+/// # Arguments
+///
+/// * `self` - SpinSProduct to be ordered.
+///
+/// # Returns
+///
+/// `Ordering` - The ordering result
+impl Ord for SpinSProduct {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let me: &TinyVec<[(usize, SingleSpinSOperator); 5]> = &(self.items);
+        let them: &TinyVec<[(usize, SingleSpinSOperator); 5]> = &(other.items);
+
+        match me.len().cmp(&them.len()) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => me.cmp(them), // If lengths are equal use lexicographic
+            Ordering::Greater => Ordering::Greater,
+        }
+    }
+}
+
+/// This method returns an ordering between `self` and `other` values if one exists.
+impl PartialOrd for SpinSProduct {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl SymmetricIndex for SpinSProduct {
+    // From trait
+    fn hermitian_conjugate(&self) -> (Self, f64) {
+        // The order of the operators on each site is reversed, the sites themselves stay sorted
+        let mut new_product = SpinSProduct::with_capacity(self.items.len());
+        let mut start = 0;
+        while start < self.items.len() {
+            let index = self.items[start].0;
+            let end = self.items[start..]
+                .iter()
+                .position(|(inner_index, _)| inner_index != &index)
+                .map_or(self.items.len(), |offset| start + offset);
+            for (_, operator) in self.items[start..end].iter().rev() {
+                new_product
+                    .items
+                    .push((index, operator.hermitian_conjugate()));
+            }
+            start = end;
+        }
+        (new_product, 1.0)
+    }
+
+    // From trait
+    fn is_natural_hermitian(&self) -> bool {
+        self.hermitian_conjugate().0 == *self
+    }
+}
+
+/// Implements the default function (Default trait) of SpinSProduct (an empty SpinSProduct).
+///
+impl Default for SpinSProduct {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for SpinSProduct {
+    type Err = StruqtureError;
+    /// Constructs a SpinSProduct from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The successfully converted SpinSProduct.
+    /// * `Err(StruqtureError::IncorrectPauliEntry)` - The operator being set is not in [\"I\", \"+\", \"-\", \"Z\"].
+    /// * `Err(StruqtureError::FromStringFailed)` - Using {} instead of unsigned integer as spin index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "I" || s.is_empty() {
+            Ok(Self::new()) // If the string is identity then it's an empty SpinSProduct
+        } else {
+            if !s.starts_with(char::is_numeric) {
+                return Err(StruqtureError::FromStringFailed {
+                    msg: format!("Missing spin index in the following SpinSProduct: {}", s),
+                });
+            }
+            let mut sp = Self::new();
+            let value = s.to_string();
+            let vec_operators = value.split(char::is_numeric).filter(|s| !s.is_empty());
+            let vec_indices = value
+                .split(|c| char::is_alphabetic(c) || char::is_ascii_punctuation(&c))
+                .filter(|s| !s.is_empty());
+            for (index, operator) in vec_indices.zip(vec_operators) {
+                match index.parse() {
+                    Ok(num) => {
+                        sp = sp.add_operator(num, SingleSpinSOperator::from_str(operator)?);
+                    }
+                    Err(_) => {
+                        return Err(StruqtureError::FromStringFailed {
+                            msg: format!(
+                                "Using {} instead of unsigned integer as spin index",
+                                index
+                            ),
+                        })
+                    }
+                }
+            }
+            Ok(sp)
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of SpinSProduct.
+///
+impl fmt::Display for SpinSProduct {
+    /// Formats the SpinSProduct using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinSProduct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string: String = String::new();
+        if self.items.is_empty() {
+            string.push('I');
+        } else {
+            for (index, operator) in self.items.iter() {
+                string.push_str(format!("{}", index).as_str());
+                string.push_str(format!("{}", operator).as_str());
+            }
+        }
+        write!(f, "{}", string)
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of SpinSProduct.
+///
+impl IntoIterator for SpinSProduct {
+    type Item = (usize, SingleSpinSOperator);
+    type IntoIter = TinyVecIterator<[(usize, SingleSpinSOperator); 5]>;
+    /// Returns the SpinSProduct in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The SpinSProduct in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of SpinSProduct.
+///
+impl FromIterator<(usize, SingleSpinSOperator)> for SpinSProduct {
+    /// Returns the object in SpinSProduct form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the SpinSProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in SpinSProduct form.
+    fn from_iter<I: IntoIterator<Item = (usize, SingleSpinSOperator)>>(iter: I) -> Self {
+        let mut sp = SpinSProduct::new();
+        for (index, operator) in iter {
+            sp = sp.add_operator(index, operator);
+        }
+        sp
+    }
+}
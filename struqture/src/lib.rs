@@ -18,6 +18,7 @@
 // #![deny(missing_debug_implementations)]
 
 use num_complex::Complex64;
+use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator::CalculatorError;
 use qoqo_calculator::CalculatorFloat;
@@ -47,18 +48,7 @@ use tinyvec::TinyVec;
 #[serde(into = "StruqtureVersionSerializable")]
 struct StruqtureVersion;
 
-#[derive(
-    Clone,
-    Copy,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Debug,
-    Default,
-    serde::Serialize,
-    serde::Deserialize,
-)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, serde::Serialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 /// # StruqtureVersion
 ///
@@ -70,6 +60,72 @@ struct StruqtureVersionSerializable {
     minor_version: u32,
 }
 
+/// The fields of a StruqtureVersionSerializable as they are deserialized, before the optional version check.
+#[derive(serde::Deserialize)]
+#[serde(rename = "StruqtureVersionSerializable")]
+struct StruqtureVersionFields {
+    major_version: u32,
+    minor_version: u32,
+}
+
+impl<'de> serde::Deserialize<'de> for StruqtureVersionSerializable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let fields = StruqtureVersionFields::deserialize(deserializer)?;
+        let version = StruqtureVersionSerializable {
+            major_version: fields.major_version,
+            minor_version: fields.minor_version,
+        };
+        if VERSION_CHECK_ENABLED.with(|enabled| enabled.get()) {
+            StruqtureVersion::try_from(version).map_err(serde::de::Error::custom)?;
+        }
+        Ok(version)
+    }
+}
+
+thread_local! {
+    /// Whether the struqture versions are checked while deserializing on the current thread.
+    static VERSION_CHECK_ENABLED: std::cell::Cell<bool> = std::cell::Cell::new(false);
+}
+
+/// Resets the version check of the current thread to its previous state when dropped.
+struct VersionCheckGuard {
+    previous: bool,
+}
+
+impl Drop for VersionCheckGuard {
+    fn drop(&mut self) {
+        VERSION_CHECK_ENABLED.with(|enabled| enabled.set(self.previous));
+    }
+}
+
+/// Runs a deserialization with a check of the struqture versions stored in the data.
+///
+/// By default the serde implementations of struqture do not check the struqture version of the data.
+/// While `deserialize` runs, every struqture version in the data is checked against the version of the
+/// library and deserialization fails for data written by an incompatible version of struqture.
+/// This allows checking the versions for formats that are not self-describing, like bincode.
+///
+/// # Arguments
+///
+/// * `deserialize` - The function deserializing the object, e.g. `|| bincode::deserialize(&bytes)`.
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized object.
+/// * `Err(E)` - The deserialization failed, also when the data was created with an incompatible version of struqture.
+pub fn deserialize_with_version_check<T, E, F>(deserialize: F) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E>,
+{
+    let _guard = VersionCheckGuard {
+        previous: VERSION_CHECK_ENABLED.with(|enabled| enabled.replace(true)),
+    };
+    deserialize()
+}
+
 impl TryFrom<StruqtureVersionSerializable> for StruqtureVersion {
     type Error = StruqtureError;
 
@@ -139,6 +195,95 @@ impl From<StruqtureVersion> for StruqtureVersionSerializable {
     }
 }
 
+/// Deserializes a struqture object from its json representation.
+///
+/// Before the object is constructed, every `_struqture_version` entry in the json data is checked
+/// against the version of the library. Data written by an incompatible (e.g. newer minor) version
+/// of struqture is rejected.
+/// Requires the `json` feature, which enables the optional serde_json dependency.
+///
+/// # Arguments
+///
+/// * `input` - The json representation of the object.
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized object.
+/// * `Err(StruqtureError::VersionMissmatch)` - The data was created with an incompatible version of struqture.
+/// * `Err(StruqtureError::GenericError)` - The input could not be deserialized.
+#[cfg(feature = "json")]
+pub fn from_json<T>(input: &str) -> Result<T, StruqtureError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value: serde_json::Value =
+        serde_json::from_str(input).map_err(|err| StruqtureError::GenericError {
+            msg: format!("Input cannot be deserialized: {}", err),
+        })?;
+    check_json_struqture_versions(&value)?;
+    serde_json::from_value(value).map_err(|err| StruqtureError::GenericError {
+        msg: format!("Input cannot be deserialized: {}", err),
+    })
+}
+
+/// Deserializes a struqture object from its json representation without checking the struqture version.
+///
+/// The payload is parsed as usual, but the `_struqture_version` entries are not compared with the
+/// version of the library.
+///
+/// Use with care: data written by a newer version of struqture can contain features that are
+/// silently dropped or misinterpreted by this version of the library. This function is only
+/// intended for controlled pipelines where the compatibility of the data is known.
+/// Requires the `json` feature, which enables the optional serde_json dependency.
+///
+/// # Arguments
+///
+/// * `input` - The json representation of the object.
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized object.
+/// * `Err(StruqtureError::GenericError)` - The input could not be deserialized.
+#[cfg(feature = "json")]
+pub fn from_json_unchecked<T>(input: &str) -> Result<T, StruqtureError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(input).map_err(|err| StruqtureError::GenericError {
+        msg: format!("Input cannot be deserialized: {}", err),
+    })
+}
+
+/// Recursively checks all `_struqture_version` entries of a json value against the library version.
+#[cfg(feature = "json")]
+fn check_json_struqture_versions(value: &serde_json::Value) -> Result<(), StruqtureError> {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, entry) in map.iter() {
+                if key == "_struqture_version" {
+                    let version: StruqtureVersionSerializable =
+                        serde_json::from_value(entry.clone()).map_err(|err| {
+                            StruqtureError::GenericError {
+                                msg: format!("Input cannot be deserialized: {}", err),
+                            }
+                        })?;
+                    StruqtureVersion::try_from(version)?;
+                } else {
+                    check_json_struqture_versions(entry)?;
+                }
+            }
+            Ok(())
+        }
+        serde_json::Value::Array(entries) => {
+            for entry in entries.iter() {
+                check_json_struqture_versions(entry)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Errors that can occur in struqture.
 #[derive(Debug, Error, PartialEq)]
 pub enum StruqtureError {
@@ -210,7 +355,7 @@ pub enum StruqtureError {
     #[error("Index of (Hermitian)BosonProduct exceeds that of the Boson(Hamiltonian)System")]
     NumberModesExceeded,
     /// Error when the minimum index of the creators of the object is larger than the minimum index of the annihilators object.
-    #[error("The minimum index of the creators {creators_min:?} is larger than the minimum index of the annihilators {annihilators_min:?}")]
+    #[error("The minimum index of the creators {creators_min:?} is larger than the minimum index of the annihilators {annihilators_min:?}: creators' minimum index must not exceed annihilators' minimum index; swap creators and annihilators")]
     CreatorsAnnihilatorsMinimumIndex {
         /// Minimum index of the creators.
         creators_min: Option<usize>,
@@ -261,6 +406,178 @@ pub type CooSparseMatrix = (Vec<Complex64>, (Vec<usize>, Vec<usize>));
 /// Input in the form (value_vector, (row_index_vector, column_index_vector))
 pub type CooSparseMatrixReal = (Vec<f64>, (Vec<usize>, Vec<usize>));
 
+/// Complex sparse matrix in compressed sparse row (CSR) or compressed sparse column (CSC) format.
+///
+/// Input in the form (value_vector, index_vector, pointer_vector), where for CSR the index vector contains
+/// the column indices and the entries of row `i` are stored at positions `pointer_vector[i]..pointer_vector[i+1]`.
+/// For CSC the roles of rows and columns are exchanged.
+pub type CsrSparseMatrix = (Vec<Complex64>, Vec<usize>, Vec<usize>);
+
+/// Lazy iterator over the entries of a complex sparse matrix.
+///
+/// Yields the entries in the form (row_index, column_index, value).
+pub type SparseMatrixEntries<'a> = Box<dyn Iterator<Item = (usize, usize, Complex64)> + 'a>;
+
+/// Converts a map of matrix entries into a CooSparseMatrix sorted by row and column.
+///
+/// Entries that are exactly zero are removed.
+///
+/// # Arguments
+///
+/// * `entries` - The (row, column) indexed entries of the matrix.
+///
+/// # Returns
+///
+/// * `CooSparseMatrix` - The sorted matrix in COO form.
+pub(crate) fn sorted_coo_from_entries(
+    entries: HashMap<(usize, usize), Complex64>,
+) -> CooSparseMatrix {
+    let mut entries: Vec<((usize, usize), Complex64)> = entries
+        .into_iter()
+        .filter(|(_, entry)| *entry != Complex64::new(0.0, 0.0))
+        .collect();
+    entries.sort_by_key(|(key, _)| *key);
+    let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+    let mut rows: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+    for ((row, column), entry) in entries.into_iter() {
+        values.push(entry);
+        rows.push(row);
+        columns.push(column);
+    }
+    (values, (rows, columns))
+}
+
+/// Adds the entries of the superoperator `p -> -i [H, p]` to a map of superoperator entries.
+///
+/// The superoperator acts on density matrices flattened in row-major form,
+/// so that its matrix is `-i H.kron(I) + i I.kron(H.T)`.
+///
+/// # Arguments
+///
+/// * `entries` - The (row, column) indexed entries of the superoperator.
+/// * `matrix` - The matrix H in COO form.
+/// * `dimension` - The dimension of H.
+pub(crate) fn add_commutator_superoperator_entries(
+    entries: &mut HashMap<(usize, usize), Complex64>,
+    matrix: &CooSparseMatrix,
+    dimension: usize,
+) {
+    let (values, (rows, columns)) = matrix;
+    for (value, (row, column)) in values.iter().zip(rows.iter().zip(columns.iter())) {
+        for k in 0..dimension {
+            // -i H p => -i H.kron(I) flatten(p)
+            *entries
+                .entry((row * dimension + k, column * dimension + k))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += Complex64::new(0.0, -1.0) * value;
+            // i p H => i I.kron(H.T) flatten(p)
+            *entries
+                .entry((k * dimension + column, k * dimension + row))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += Complex64::new(0.0, 1.0) * value;
+        }
+    }
+}
+
+/// Adds the entries of the Lindblad superoperator `p -> M (L p R^dagger - 1/2 {R^dagger L, p})` to a map of superoperator entries.
+///
+/// The superoperator acts on density matrices flattened in row-major form.
+/// The matrices L and R are given as lists of real (row, column, value) entries.
+///
+/// # Arguments
+///
+/// * `entries` - The (row, column) indexed entries of the superoperator.
+/// * `left_entries` - The entries of the matrix L.
+/// * `right_entries` - The entries of the matrix R.
+/// * `rate` - The prefactor M of the Lindblad term.
+/// * `dimension` - The dimension of L and R.
+pub(crate) fn add_lindblad_superoperator_entries(
+    entries: &mut HashMap<(usize, usize), Complex64>,
+    left_entries: &[(usize, usize, f64)],
+    right_entries: &[(usize, usize, f64)],
+    rate: Complex64,
+    dimension: usize,
+) {
+    // L p R^dagger => L.kron(conj(R)) flatten(p), the matrix entries of R are real
+    for (left_row, left_column, left_value) in left_entries.iter() {
+        for (right_row, right_column, right_value) in right_entries.iter() {
+            *entries
+                .entry((
+                    left_row * dimension + right_row,
+                    left_column * dimension + right_column,
+                ))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += rate * left_value * right_value;
+        }
+    }
+    // R^dagger L with (R^dagger L)[x, y] = sum_k R[k, x] L[k, y]
+    let mut left_by_row: HashMap<usize, Vec<(usize, f64)>> = HashMap::new();
+    for (left_row, left_column, left_value) in left_entries.iter() {
+        left_by_row
+            .entry(*left_row)
+            .or_default()
+            .push((*left_column, *left_value));
+    }
+    let mut product: HashMap<(usize, usize), f64> = HashMap::new();
+    for (right_row, right_column, right_value) in right_entries.iter() {
+        if let Some(left_row_entries) = left_by_row.get(right_row) {
+            for (left_column, left_value) in left_row_entries.iter() {
+                *product.entry((*right_column, *left_column)).or_insert(0.0) +=
+                    right_value * left_value;
+            }
+        }
+    }
+    // -1/2 {R^dagger L, p} => -1/2 (R^dagger L).kron(I) - 1/2 I.kron((R^dagger L).T)
+    for ((row, column), product_value) in product.into_iter() {
+        for k in 0..dimension {
+            *entries
+                .entry((row * dimension + k, column * dimension + k))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += rate * (-0.5 * product_value);
+            *entries
+                .entry((k * dimension + column, k * dimension + row))
+                .or_insert_with(|| Complex64::new(0.0, 0.0)) += rate * (-0.5 * product_value);
+        }
+    }
+}
+
+/// Constructs the matrix of single contractions used in Wick's theorem for a quasi-free state.
+///
+/// The entry `[a, b]` of the matrix is the expectation value `<c_{creators[a]}^dagger c_{annihilators[b]}>`
+/// taken from the one-body density matrix of the state.
+///
+/// # Arguments
+///
+/// * `one_body_dm` - The one-body density matrix `D[i, j] = <c_i^dagger c_j>` of the state.
+/// * `current_number_modes` - The number of modes the operator acts on.
+/// * `creators` - The creator indices of the product.
+/// * `annihilators` - The annihilator indices of the product.
+///
+/// # Returns
+///
+/// * `Ok(nalgebra::DMatrix<Complex64>)` - The matrix of contractions.
+/// * `Err(StruqtureError::GenericError)` - The one-body density matrix is not square.
+/// * `Err(StruqtureError::NumberModesExceeded)` - The operator acts on more modes than the dimension of the one-body density matrix.
+#[cfg(feature = "linalg")]
+pub(crate) fn wick_contraction_matrix(
+    one_body_dm: &ndarray::Array2<Complex64>,
+    current_number_modes: usize,
+    creators: &[usize],
+    annihilators: &[usize],
+) -> Result<nalgebra::DMatrix<Complex64>, StruqtureError> {
+    let (number_rows, number_columns) = one_body_dm.dim();
+    if number_rows != number_columns {
+        return Err(StruqtureError::GenericError {
+            msg: "The one-body density matrix needs to be square.".to_string(),
+        });
+    }
+    if current_number_modes > number_rows {
+        return Err(StruqtureError::NumberModesExceeded);
+    }
+    Ok(nalgebra::DMatrix::from_fn(
+        creators.len(),
+        annihilators.len(),
+        |row, column| one_body_dm[[creators[row], annihilators[column]]],
+    ))
+}
+
 /// Trait for all hermitian indices
 pub trait SymmetricIndex:
     std::hash::Hash + Eq + Sized + Clone + std::fmt::Debug + std::fmt::Display + FromStr + Default
@@ -314,6 +631,9 @@ where
 
     /// Sets a new entry in Self. This function consumes Self.
     ///
+    /// Setting the identity removes the entry at the index (or does nothing if there is none),
+    /// so that identities are never stored and e.g. `X0 I1` is equal to `X0`.
+    ///
     /// # Arguments
     ///
     /// * `index` - Index of set object.
@@ -576,6 +896,108 @@ pub trait CorrespondsTo<T> {
     fn corresponds_to(&self) -> T;
 }
 
+/// Renders a sum of creation and annihilation operator products as a LaTeX string.
+///
+/// The terms are sorted by their index. Creators are rendered as `\hat{symbol}^\dagger_{i}`,
+/// annihilators as `\hat{symbol}_{j}` and the identity as `\mathbb{1}`.
+///
+/// # Arguments
+///
+/// * `terms` - The (index, coefficient) pairs of the sum.
+/// * `symbol` - The symbol of the creation and annihilation operators.
+///
+/// # Returns
+///
+/// * `String` - The LaTeX representation of the sum, `0` for an empty sum.
+pub(crate) fn mode_terms_to_latex<'a, I>(
+    terms: impl Iterator<Item = (&'a I, &'a CalculatorComplex)>,
+    symbol: &str,
+) -> String
+where
+    I: ModeIndex + Ord + 'a,
+{
+    let mut terms: Vec<(&I, &CalculatorComplex)> = terms.collect();
+    terms.sort_by_key(|(a, _)| *a);
+    let mut latex = String::new();
+    for (position, (index, value)) in terms.into_iter().enumerate() {
+        let mut factors: Vec<String> = index
+            .creators()
+            .map(|c| format!("\\hat{{{}}}^\\dagger_{{{}}}", symbol, c))
+            .collect();
+        factors.extend(
+            index
+                .annihilators()
+                .map(|a| format!("\\hat{{{}}}_{{{}}}", symbol, a)),
+        );
+        let product = if factors.is_empty() {
+            "\\mathbb{1}".to_string()
+        } else {
+            factors.join(" ")
+        };
+        let (negative, coefficient) = latex_coefficient(value);
+        match (position, negative) {
+            (0, true) => latex.push('-'),
+            (0, false) => (),
+            (_, true) => latex.push_str(" - "),
+            (_, false) => latex.push_str(" + "),
+        }
+        if !coefficient.is_empty() {
+            latex.push_str(&coefficient);
+            latex.push(' ');
+        }
+        latex.push_str(&product);
+    }
+    if latex.is_empty() {
+        latex.push('0');
+    }
+    latex
+}
+
+/// Splits a coefficient into its sign and its LaTeX representation.
+///
+/// Real and purely imaginary numeric coefficients are rendered by their absolute value with the sign returned
+/// separately, a magnitude of one is omitted. General complex and symbolic coefficients are wrapped in brackets.
+fn latex_coefficient(value: &CalculatorComplex) -> (bool, String) {
+    let float_to_string = |float: &CalculatorFloat| match float {
+        CalculatorFloat::Float(x) => format!("{}", x),
+        CalculatorFloat::Str(x) => x.clone(),
+    };
+    match (&value.re, &value.im) {
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) if *im == 0.0 => {
+            let magnitude = if re.abs() == 1.0 {
+                String::new()
+            } else {
+                format!("{}", re.abs())
+            };
+            (*re < 0.0, magnitude)
+        }
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) if *re == 0.0 => {
+            let magnitude = if im.abs() == 1.0 {
+                "i".to_string()
+            } else {
+                format!("{}i", im.abs())
+            };
+            (*im < 0.0, magnitude)
+        }
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => (
+            false,
+            format!(
+                "({} {} {}i)",
+                re,
+                if *im < 0.0 { "-" } else { "+" },
+                im.abs()
+            ),
+        ),
+        (re, CalculatorFloat::Float(im)) if *im == 0.0 => {
+            (false, format!("({})", float_to_string(re)))
+        }
+        (re, im) => (
+            false,
+            format!("({} + ({})i)", float_to_string(re), float_to_string(im)),
+        ),
+    }
+}
+
 /// Helper trait to allow truncation of values below threshold.
 /// Should eventually be ported to qoqo_calculator like this
 /// and be implemented for CalculatorFloat, CaclulatorComplex, f64 and Complexf64
@@ -655,6 +1077,174 @@ impl ConjugationTrait for CalculatorFloat {
     }
 }
 
+/// Helper trait to allow replacing values by rational approximations.
+///
+/// Implemented for CalculatorFloat and CalculatorComplex, symbolic values are left untouched.
+pub trait RationalApproximationTrait: Sized {
+    /// Replaces all numeric values in Self by the nearest fraction with a denominator of at most `denominator_limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `denominator_limit` - The maximal denominator of the fractions.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The rounded version of Self.
+    fn round_to_rational(&self, denominator_limit: u64) -> Self;
+}
+
+/// Returns the fraction closest to `value` with a denominator of at most `denominator_limit`.
+///
+/// The fraction is determined with the continued fraction expansion of `value`,
+/// taking the best semiconvergent into account once the denominator limit is reached.
+/// Values that are not finite, exceed the precision of an integer stored as f64 or a limit of zero
+/// leave `value` unchanged.
+fn rational_approximation(value: f64, denominator_limit: u64) -> f64 {
+    if denominator_limit == 0 || !value.is_finite() || value.abs() >= 2f64.powi(53) {
+        return value;
+    }
+    let limit = denominator_limit as f64;
+    let mut remainder = value.abs();
+    // Convergents h/k of the continued fraction, starting with h_{-2}/k_{-2} = 0/1 and h_{-1}/k_{-1} = 1/0
+    let (mut h_previous, mut k_previous) = (0.0, 1.0);
+    let (mut h_current, mut k_current) = (1.0, 0.0);
+    // A continued fraction of an f64 terminates after a bounded number of terms
+    for _ in 0..64 {
+        let a = remainder.floor();
+        let h_next = a * h_current + h_previous;
+        let k_next = a * k_current + k_previous;
+        if k_next > limit {
+            // The best approximation is either the last convergent or the largest admissible semiconvergent
+            let t = ((limit - k_previous) / k_current).floor();
+            let h_semi = t * h_current + h_previous;
+            let k_semi = t * k_current + k_previous;
+            if (h_semi / k_semi - value.abs()).abs() < (h_current / k_current - value.abs()).abs() {
+                h_current = h_semi;
+                k_current = k_semi;
+            }
+            break;
+        }
+        h_previous = h_current;
+        k_previous = k_current;
+        h_current = h_next;
+        k_current = k_next;
+        let fractional_part = remainder - a;
+        if fractional_part <= f64::EPSILON * remainder.max(1.0) {
+            break;
+        }
+        remainder = 1.0 / fractional_part;
+    }
+    value.signum() * h_current / k_current
+}
+
+impl RationalApproximationTrait for CalculatorFloat {
+    fn round_to_rational(&self, denominator_limit: u64) -> Self {
+        match self {
+            CalculatorFloat::Float(f) => {
+                CalculatorFloat::Float(rational_approximation(*f, denominator_limit))
+            }
+            CalculatorFloat::Str(_) => self.clone(),
+        }
+    }
+}
+
+impl RationalApproximationTrait for CalculatorComplex {
+    fn round_to_rational(&self, denominator_limit: u64) -> Self {
+        CalculatorComplex {
+            re: self.re.round_to_rational(denominator_limit),
+            im: self.im.round_to_rational(denominator_limit),
+        }
+    }
+}
+
+/// Helper trait to obtain the absolute value of numeric values.
+///
+/// Implemented for CalculatorFloat and CalculatorComplex.
+pub trait AbsoluteValueTrait: Sized {
+    /// Returns the absolute value of Self if Self is numeric.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The absolute value of Self.
+    /// * `None` - Self is symbolic.
+    fn numeric_abs(&self) -> Option<f64>;
+}
+
+impl AbsoluteValueTrait for CalculatorFloat {
+    fn numeric_abs(&self) -> Option<f64> {
+        match self {
+            CalculatorFloat::Float(f) => Some(f.abs()),
+            CalculatorFloat::Str(_) => None,
+        }
+    }
+}
+
+impl AbsoluteValueTrait for CalculatorComplex {
+    fn numeric_abs(&self) -> Option<f64> {
+        match (&self.re, &self.im) {
+            (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => {
+                Some(Complex64::new(*re, *im).norm())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Helper trait to allow replacing symbolic values by numeric values.
+///
+/// Implemented for CalculatorFloat and CalculatorComplex.
+pub trait SubstituteParametersTrait: Sized {
+    /// Replaces all symbolic values in Self by the values obtained with the variables set in the Calculator.
+    ///
+    /// # Arguments
+    ///
+    /// * `calculator` - The Calculator holding the values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The fully numeric version of Self.
+    /// * `Err(StruqtureError::CalculatorError)` - A symbolic expression could not be evaluated, e.g. a parameter is missing.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError>;
+}
+
+impl SubstituteParametersTrait for CalculatorFloat {
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError> {
+        match self {
+            CalculatorFloat::Float(_) => Ok(self.clone()),
+            CalculatorFloat::Str(expression) => {
+                Ok(CalculatorFloat::Float(calculator.parse_str(expression)?))
+            }
+        }
+    }
+}
+
+impl SubstituteParametersTrait for CalculatorComplex {
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, StruqtureError> {
+        Ok(CalculatorComplex {
+            re: self.re.substitute_parameters(calculator)?,
+            im: self.im.substitute_parameters(calculator)?,
+        })
+    }
+}
+
+/// Summary statistics of the coefficients of an operator.
+///
+/// The magnitude statistics only take numeric coefficients into account,
+/// symbolic coefficients are counted separately.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoefficientStats {
+    /// The total number of terms.
+    pub num_terms: usize,
+    /// The largest absolute value of the numeric coefficients (0.0 if there are none).
+    pub max_abs: f64,
+    /// The smallest absolute value of the numeric coefficients (0.0 if there are none).
+    pub min_abs: f64,
+    /// The mean absolute value of the numeric coefficients (0.0 if there are none).
+    pub mean_abs: f64,
+    /// The number of symbolic coefficients.
+    pub num_symbolic: usize,
+}
+
 /// Trait for all objects that can act on a quantum density matrix like a superoperator.
 ///
 /// # Example
@@ -750,6 +1340,9 @@ where
 
     /// Returns number of entries in object.
     ///
+    /// The operators also provide an inherent `number_of_terms` function, which returns the same value
+    /// but does not require the lifetime bound of OperateOnDensityMatrix.
+    ///
     /// # Returns
     ///
     /// * `usize` - The length of the object's internal_map.
@@ -807,6 +1400,187 @@ where
         Ok(())
     }
 
+    /// Sets a new entry in Self, refusing to overwrite an existing entry.
+    ///
+    /// In contrast to `set`, which silently overwrites existing entries, this function
+    /// returns an error if the key is already present in Self with a nonzero value.
+    /// The key is checked before Self is modified, so Self is left unchanged in that case.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The Self::Index key to set in Self.
+    /// * `value` - The corresponding Self::Value value to set for the key in Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The key did not exist, it has been set with its corresponding value.
+    /// * `Err(StruqtureError::OperatorIndexAlreadyOccupied)` - The key already exists in Self.
+    /// * `Err(StruqtureError)` - Error propagated from `set`.
+    fn set_new(&mut self, key: Self::Index, value: Self::Value) -> Result<(), StruqtureError>
+    where
+        Self::Index: std::fmt::Debug,
+        Self::Value: PartialEq + From<f64>,
+    {
+        // Absent keys return a zero value and set never stores zero values
+        if self.get(&key) != &Self::Value::from(0.0) {
+            return Err(StruqtureError::OperatorIndexAlreadyOccupied {
+                index: format!("{:?}", key),
+            });
+        }
+        self.set(key, value)?;
+        Ok(())
+    }
+
+    /// Adds a batch of (key, value) pairs to Self in a single transaction.
+    ///
+    /// Values of repeated keys in the batch are first summed up in a temporary map, so each key
+    /// is only looked up once in Self. The resulting values of the merged terms are first validated
+    /// on an empty copy of Self and only added to Self if all of them are valid.
+    /// If any term is invalid, an error is returned and Self is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The (Self::Index, Self::Value) pairs to add to Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All terms were added to Self.
+    /// * `Err(StruqtureError)` - Error propagated from `add_operator_product`, Self is unchanged.
+    fn add_operator_products_batch<I>(&mut self, terms: I) -> Result<(), StruqtureError>
+    where
+        I: IntoIterator<Item = (Self::Index, Self::Value)>,
+        Self::Index: Eq + std::hash::Hash,
+    {
+        let mut positions: HashMap<Self::Index, usize> = HashMap::new();
+        let mut merged: Vec<(Self::Index, Self::Value)> = Vec::new();
+        for (key, value) in terms {
+            match positions.get(&key) {
+                Some(position) => {
+                    let entry = &mut merged[*position];
+                    entry.1 = entry.1.clone() + value;
+                }
+                None => {
+                    positions.insert(key.clone(), merged.len());
+                    merged.push((key, value));
+                }
+            }
+        }
+        // Validate the new values of all terms on an empty copy of Self before Self is changed
+        let mut validation = self.empty_clone(Some(merged.len()));
+        for (key, value) in merged.iter() {
+            validation.add_operator_product(key.clone(), self.get(key).clone() + value.clone())?;
+        }
+        for (key, value) in merged {
+            self.add_operator_product(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Serializes Self to JSON lines with one term per line, sorted by key.
+    ///
+    /// The first line contains Self without any terms (storing the struqture version and
+    /// any additional properties of Self). Each following line contains one (key, value) pair.
+    /// The terms are sorted by the JSON representation of their key, so adding or removing a
+    /// term only changes a single line. This is useful when storing operators in version control.
+    /// Requires the `json` feature, which enables the optional serde_json dependency.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The JSON lines representation of Self.
+    #[cfg(feature = "json")]
+    fn to_sorted_json_lines(&'a self) -> String
+    where
+        Self::Index: serde::Serialize,
+        Self::Value: serde::Serialize,
+    {
+        let header = serde_json::to_string(&self.empty_clone(None))
+            .expect("Internal error: Unexpected failure serializing empty object to json.");
+        let mut terms: Vec<(String, String)> = self
+            .iter()
+            .map(|(key, value)| {
+                let key_string = serde_json::to_string(key)
+                    .expect("Internal error: Unexpected failure serializing key to json.");
+                let term_string = serde_json::to_string(&(key, value))
+                    .expect("Internal error: Unexpected failure serializing term to json.");
+                (key_string, term_string)
+            })
+            .collect();
+        terms.sort();
+        let mut lines: Vec<String> = Vec::with_capacity(terms.len() + 1);
+        lines.push(header);
+        lines.extend(terms.into_iter().map(|(_, term_string)| term_string));
+        lines.join("\n")
+    }
+
+    /// Deserializes Self from JSON lines created with `to_sorted_json_lines`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The JSON lines representation of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The deserialized object.
+    /// * `Err(StruqtureError::ParsingError)` - A line of the input could not be deserialized.
+    /// * `Err(StruqtureError)` - Error propagated from `add_operator_product`.
+    #[cfg(feature = "json")]
+    fn from_sorted_json_lines(input: &'a str) -> Result<Self, StruqtureError>
+    where
+        Self::Index: serde::de::DeserializeOwned,
+        Self::Value: serde::de::DeserializeOwned,
+    {
+        let parsing_error = |err: serde_json::Error| StruqtureError::ParsingError {
+            target_type: std::any::type_name::<Self>().to_string(),
+            msg: err.to_string(),
+        };
+        let mut lines = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty());
+        let header = lines.next().ok_or_else(|| StruqtureError::ParsingError {
+            target_type: std::any::type_name::<Self>().to_string(),
+            msg: "Input does not contain any lines".to_string(),
+        })?;
+        let mut new_self: Self = serde_json::from_str(header).map_err(parsing_error)?;
+        for line in lines {
+            let (key, value): (Self::Index, Self::Value) =
+                serde_json::from_str(line).map_err(parsing_error)?;
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+
+    /// Removes all entries matching a predicate from Self and returns them.
+    ///
+    /// In contrast to filtering into a copy, Self is modified in place and only keeps
+    /// the entries for which the predicate returns false, in their previous order.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Function deciding for each (key, value) pair whether it is removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(Self::Index, Self::Value)>` - The removed (key, value) pairs.
+    fn drain_filter<F>(&mut self, predicate: F) -> Vec<(Self::Index, Self::Value)>
+    where
+        F: Fn(&Self::Index, &Self::Value) -> bool,
+    {
+        let empty = self.empty_clone(None);
+        let old = std::mem::replace(self, empty);
+        let mut drained: Vec<(Self::Index, Self::Value)> = Vec::new();
+        let mut kept: Vec<(Self::Index, Self::Value)> = Vec::new();
+        for (key, value) in old {
+            if predicate(&key, &value) {
+                drained.push((key, value));
+            } else {
+                kept.push((key, value));
+            }
+        }
+        self.extend(kept);
+        drained
+    }
+
     /// Truncates Self by returning a copy without entries under a threshold.
     ///
     /// Entries with an absolute value under the threshold are removed from the copy of the object that is returned.
@@ -828,6 +1602,163 @@ where
         }));
         new_self
     }
+
+    /// Truncates Self and returns the Frobenius norm of the discarded part.
+    ///
+    /// The truncation is the same as in [OperateOnDensityMatrix::truncate]. While filtering the terms,
+    /// the squared magnitudes of the numeric parts removed from the coefficients are accumulated.
+    /// Symbolic coefficients are never truncated and do not contribute to the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `(Self, f64)` - The truncated version of Self and the square root of the discarded weight.
+    fn truncate_with_error(&'a self, threshold: f64) -> (Self, f64)
+    where
+        Self::Value: Into<CalculatorComplex>,
+    {
+        let mut new_self = self.empty_clone(Some(self.len()));
+        let mut discarded_weight = 0.0;
+        for (key, value) in self.iter() {
+            let original: CalculatorComplex = value.clone().into();
+            let discarded = match value.truncate(threshold) {
+                Some(value_truncated) => {
+                    let kept: CalculatorComplex = value_truncated.clone().into();
+                    new_self.extend(std::iter::once((key.clone(), value_truncated)));
+                    original - kept
+                }
+                None => original,
+            };
+            if let Some(abs) = discarded.numeric_abs() {
+                discarded_weight += abs * abs;
+            }
+        }
+        (new_self, discarded_weight.sqrt())
+    }
+
+    /// Returns a copy of Self with all numeric coefficients replaced by rational approximations.
+    ///
+    /// Each numeric coefficient (real and imaginary part separately) is replaced by the nearest fraction
+    /// with a denominator of at most `denominator_limit`, determined by a continued fraction approximation.
+    /// Symbolic coefficients are left untouched. Terms whose coefficients are rounded to zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `denominator_limit` - The maximal denominator of the fractions.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The copy of Self with rounded coefficients.
+    fn round_coefficients(&'a self, denominator_limit: u64) -> Self
+    where
+        Self::Value: RationalApproximationTrait,
+    {
+        let mut new_self = self.empty_clone(Some(self.len()));
+        new_self.extend(
+            self.iter()
+                .map(|(k, v)| (k.clone(), v.round_to_rational(denominator_limit))),
+        );
+        new_self
+    }
+
+    /// Returns a copy of Self with all symbolic coefficients replaced by numeric values.
+    ///
+    /// The symbolic expressions are evaluated with the parameter values given in `map`.
+    /// Terms whose coefficients evaluate to zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The fully numeric copy of Self.
+    /// * `Err(StruqtureError::CalculatorError)` - A symbolic expression could not be evaluated, e.g. a parameter is missing in `map`.
+    fn substitute_parameters(
+        &'a self,
+        map: &std::collections::HashMap<String, f64>,
+    ) -> Result<Self, StruqtureError>
+    where
+        Self::Value: SubstituteParametersTrait,
+    {
+        let mut calculator = Calculator::new();
+        for (name, value) in map.iter() {
+            calculator.set_variable(name, *value);
+        }
+        let mut new_self = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            new_self.extend(std::iter::once((
+                key.clone(),
+                value.substitute_parameters(&calculator)?,
+            )));
+        }
+        Ok(new_self)
+    }
+
+    /// Returns summary statistics of the absolute values of the coefficients of Self.
+    ///
+    /// Symbolic coefficients are counted in `num_symbolic` and excluded from the magnitude statistics.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CoefficientStats)` - The statistics of the coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A numeric coefficient is not finite.
+    fn coefficient_statistics(&'a self) -> Result<CoefficientStats, StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        let mut statistics = CoefficientStats {
+            num_terms: self.len(),
+            max_abs: 0.0,
+            min_abs: f64::INFINITY,
+            mean_abs: 0.0,
+            num_symbolic: 0,
+        };
+        let mut sum_abs = 0.0;
+        for value in self.values() {
+            match value.numeric_abs() {
+                Some(abs) => {
+                    if !abs.is_finite() {
+                        return Err(StruqtureError::GenericError {
+                            msg: "Coefficient statistics require finite coefficients.".to_string(),
+                        });
+                    }
+                    statistics.max_abs = statistics.max_abs.max(abs);
+                    statistics.min_abs = statistics.min_abs.min(abs);
+                    sum_abs += abs;
+                }
+                None => statistics.num_symbolic += 1,
+            }
+        }
+        let num_numeric = statistics.num_terms - statistics.num_symbolic;
+        if num_numeric == 0 {
+            statistics.min_abs = 0.0;
+        } else {
+            statistics.mean_abs = sum_abs / num_numeric as f64;
+        }
+        Ok(statistics)
+    }
+
+    /// Returns the minimum version of struqture required to deserialize Self with its current content.
+    ///
+    /// The result is the maximum of the minimum supported version of the type of Self
+    /// and the minimum supported versions of all keys contained in Self, as given by `min_supported_version_of`.
+    ///
+    /// # Returns
+    ///
+    /// (major_version, minor_version, patch_version)
+    fn min_supported_version_for_content(&'a self) -> (usize, usize, usize)
+    where
+        Self: MinSupportedVersion,
+        Self::Index: MinSupportedVersion,
+    {
+        self.keys()
+            .map(|key| key.min_supported_version_of())
+            .fold(Self::min_supported_version(), std::cmp::max)
+    }
 }
 
 /// Trait for representing complete open systems
@@ -1020,6 +1951,153 @@ where
         }));
         new_self
     }
+
+    /// Returns the hermitian part `(O + O^dagger) / 2` of Self.
+    ///
+    /// Together with [OperateOnState::anti_hermitian_part] this partitions Self,
+    /// `hermitian_part + anti_hermitian_part == Self`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian part of Self.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn hermitian_part(&'a self) -> Self {
+        let mut new_self = self.empty_clone(Some(2 * self.len()));
+        for (key, value) in self.iter() {
+            new_self
+                .add_operator_product(key.clone(), value.clone() * 0.5)
+                .expect("Internal error in add_operator_product");
+        }
+        for (key, value) in self.hermitian_conjugate() {
+            new_self
+                .add_operator_product(key, value * 0.5)
+                .expect("Internal error in add_operator_product");
+        }
+        new_self
+    }
+
+    /// Returns the anti-hermitian part `(O - O^dagger) / 2` of Self.
+    ///
+    /// Together with [OperateOnState::hermitian_part] this partitions Self,
+    /// `hermitian_part + anti_hermitian_part == Self`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anti-hermitian part of Self.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn anti_hermitian_part(&'a self) -> Self {
+        let mut new_self = self.empty_clone(Some(2 * self.len()));
+        for (key, value) in self.iter() {
+            new_self
+                .add_operator_product(key.clone(), value.clone() * 0.5)
+                .expect("Internal error in add_operator_product");
+        }
+        for (key, value) in self.hermitian_conjugate() {
+            new_self
+                .add_operator_product(key, value * -0.5)
+                .expect("Internal error in add_operator_product");
+        }
+        new_self
+    }
+
+    /// Returns an iterator over the terms of Self together with the hermitian conjugate of each key.
+    ///
+    /// The hermitian conjugate key is computed with [SymmetricIndex::hermitian_conjugate],
+    /// its prefactor is not included.
+    ///
+    /// # Returns
+    ///
+    /// * `Box<dyn Iterator<Item = (&Self::Index, &Self::Value, Self::Index)>>` - The keys, values and hermitian conjugate keys of Self.
+    fn iter_hermitian_pairs(&'a self) -> HermitianPairs<'a, Self::Index, Self::Value> {
+        Box::new(self.iter().map(|(key, value)| {
+            let (conjugate_key, _) = key.hermitian_conjugate();
+            (key, value, conjugate_key)
+        }))
+    }
+
+    /// Returns Self with every key and its hermitian conjugate combined into one canonical representative.
+    ///
+    /// For each key the smaller one of the key and its hermitian conjugate is used as the representative.
+    /// Terms whose key is not the representative are folded in with their conjugated coefficient
+    /// (including the prefactor of the hermitian conjugation of the key).
+    /// For a hermitian operator the representative of each pair of distinct conjugate keys therefore carries
+    /// twice its coefficient, halving the number of terms. Self-adjoint keys (e.g. all PauliProducts) are left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The canonical form of Self.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn to_canonical_hermitian_form(&'a self) -> Self
+    where
+        Self::Index: Ord,
+    {
+        let mut new_self = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            let (conjugate_key, conjugation_prefactor) = key.hermitian_conjugate();
+            if conjugate_key < *key {
+                new_self
+                    .add_operator_product(conjugate_key, value.conjugate() * conjugation_prefactor)
+                    .expect("Internal error in add_operator_product");
+            } else {
+                new_self
+                    .add_operator_product(key.clone(), value.clone())
+                    .expect("Internal error in add_operator_product");
+            }
+        }
+        new_self
+    }
+
+    /// Returns the terms of Self grouped into orbits under hermitian conjugation.
+    ///
+    /// Each orbit contains a term and, if its hermitian conjugate key is distinct and present in Self,
+    /// the term with the conjugate key. Terms with self-adjoint keys (and terms whose conjugate partner
+    /// is not present in Self) form singleton orbits.
+    /// The terms within each orbit and the orbits themselves are sorted by key.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Vec<(&Self::Index, &Self::Value)>>` - The hermitian conjugation orbits of the terms of Self.
+    fn hermitian_orbits(&'a self) -> Vec<Vec<(&'a Self::Index, &'a Self::Value)>>
+    where
+        Self::Index: Ord,
+    {
+        let mut terms: Vec<(&'a Self::Index, &'a Self::Value)> = self.iter().collect();
+        terms.sort_by_key(|(a, _)| *a);
+        let positions: std::collections::BTreeMap<&'a Self::Index, usize> = terms
+            .iter()
+            .enumerate()
+            .map(|(position, (key, _))| (*key, position))
+            .collect();
+        let mut assigned: Vec<bool> = vec![false; terms.len()];
+        let mut orbits: Vec<Vec<(&'a Self::Index, &'a Self::Value)>> = Vec::new();
+        for (position, (key, value)) in terms.iter().enumerate() {
+            if assigned[position] {
+                continue;
+            }
+            assigned[position] = true;
+            let mut orbit = vec![(*key, *value)];
+            let (conjugate_key, _) = key.hermitian_conjugate();
+            if conjugate_key != **key {
+                if let Some(partner) = positions.get(&conjugate_key) {
+                    if !assigned[*partner] {
+                        assigned[*partner] = true;
+                        orbit.push(terms[*partner]);
+                    }
+                }
+            }
+            orbits.push(orbit);
+        }
+        orbits
+    }
 }
 
 /// Trait for bosonic or fermionic modes.
@@ -1059,8 +2137,14 @@ pub trait OperateOnModes<'a>: PartialEq + Clone + Mul<CalculatorFloat> + Add + S
 /// Shorthand type notation for a tuple of lists of indices of creators and annihilators
 type CreatorsAnnihilators = (TinyVec<[usize; 2]>, TinyVec<[usize; 2]>);
 
+/// Shorthand type notation for an iterator over keys, values and hermitian conjugate keys
+type HermitianPairs<'a, I, V> = Box<dyn Iterator<Item = (&'a I, &'a V, I)> + 'a>;
+
 pub mod bosons;
 pub mod fermions;
+#[cfg(feature = "hdf5")]
+mod hdf5_io;
+pub mod higher_spins;
 pub mod mappings;
 pub mod mixed_systems;
 pub mod prelude;
@@ -1079,4 +2163,32 @@ pub trait MinSupportedVersion {
     fn min_supported_version() -> (usize, usize, usize) {
         (1, 0, 0)
     }
+
+    /// Returns the minimum version of struqture required to deserialize this instance.
+    ///
+    /// Defaults to the minimum supported version of the type, types whose requirements depend
+    /// on their content can override it.
+    ///
+    /// # Returns
+    /// (major_version, minor_version, patch_version)
+    fn min_supported_version_of(&self) -> (usize, usize, usize) {
+        Self::min_supported_version()
+    }
+}
+
+impl<A, B> MinSupportedVersion for (A, B)
+where
+    A: MinSupportedVersion,
+    B: MinSupportedVersion,
+{
+    fn min_supported_version() -> (usize, usize, usize) {
+        std::cmp::max(A::min_supported_version(), B::min_supported_version())
+    }
+
+    fn min_supported_version_of(&self) -> (usize, usize, usize) {
+        std::cmp::max(
+            self.0.min_supported_version_of(),
+            self.1.min_supported_version_of(),
+        )
+    }
 }
@@ -0,0 +1,121 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bravyi-Kitaev mapping from fermionic operators to spin operators.
+//!
+//! The qubits store partial sums of the occupation numbers arranged in a Fenwick tree over the
+//! `number_modes` modes, so that both the parity and the occupation of a mode are encoded in
+//! O(log(number_modes)) qubits. As for the Jordan-Wigner mapping, the qubit state $|0 \rangle$
+//! corresponds to an even and $|1\rangle$ to an odd partial sum. The mapping is given by
+//!
+//! BK(a_p^{dagger}) = X_{U(p)} (X_p Z_{P(p)} - i Y_p Z_{R(p)})*1/2
+//! BK(a_p) = X_{U(p)} (X_p Z_{P(p)} + i Y_p Z_{R(p)})*1/2
+//!
+//! where U(p) is the update set (the ancestors of p in the Fenwick tree), P(p) the parity set
+//! encoding the parity of all modes smaller than p and R(p) the parity set without the children of p.
+//! The resulting spin operators depend on the total number of modes.
+
+use crate::spins::PauliProduct;
+use crate::{SpinIndex, StruqtureError};
+
+/// Trait for transforming fermionic objects into spin objects using the Bravyi-Kitaev mapping.
+pub trait BravyiKitaevFermionToSpin {
+    /// The Output type for the Bravyi-Kitaev transformation
+    ///
+    /// For a FermionProduct or FermionOperator it will be a SpinOperator
+    /// For a HermitianFermionProduct or FermionHamiltonian it will be a SpinHamiltonian
+    /// For a FermionLindbladNoiseOperator it will be a SpinLindbladNoiseOperator
+    /// For a FermionLindbladOpenSystem it will be a SpinLindbladOpenSystem etc.
+    type Output;
+
+    /// Transform the given fermionic object into a spin object using
+    /// the Bravyi-Kitaev mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes defining the Fenwick tree of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The spin object resulting from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The fermionic object acts on more than `number_modes` modes.
+    fn bravyi_kitaev(&self, number_modes: usize) -> Result<Self::Output, StruqtureError>;
+}
+
+/// Returns the images of the Majorana operators under the Bravyi-Kitaev mapping.
+///
+/// The Majorana operators of mode p are ordered as (a_p^{dagger} + a_p, i (a_p^{dagger} - a_p)),
+/// which are mapped to X_{U(p)} X_p Z_{P(p)} and X_{U(p)} Y_p Z_{R(p)} respectively.
+///
+/// # Arguments
+///
+/// * `number_modes` - The total number of modes.
+///
+/// # Returns
+///
+/// * `Vec<PauliProduct>` - The 2 * `number_modes` Majorana operator images.
+///
+/// # Panics
+///
+/// * Internal bug in the construction of the update and parity sets.
+pub(crate) fn bravyi_kitaev_majorana_operators(number_modes: usize) -> Vec<PauliProduct> {
+    let mut parents: Vec<Option<usize>> = vec![None; number_modes];
+    if number_modes > 0 {
+        fenwick_parents(0, number_modes - 1, &mut parents);
+    }
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); number_modes];
+    for (mode, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            children[*parent].push(mode);
+        }
+    }
+
+    let mut majorana_operators: Vec<PauliProduct> = Vec::with_capacity(2 * number_modes);
+    for mode in 0..number_modes {
+        let mut update_string = PauliProduct::new();
+        let mut remainder_string = PauliProduct::new();
+        let mut ancestor = parents[mode];
+        while let Some(index) = ancestor {
+            update_string = update_string.x(index);
+            for child in children[index].iter().filter(|child| **child < mode) {
+                remainder_string = remainder_string.z(*child);
+            }
+            ancestor = parents[index];
+        }
+        let mut parity_string = remainder_string.clone();
+        for child in children[mode].iter() {
+            parity_string = parity_string.z(*child);
+        }
+        majorana_operators.push(
+            update_string
+                .concatenate(parity_string.x(mode))
+                .expect("Internal bug in bravyi_kitaev_majorana_operators, overlapping sets."),
+        );
+        majorana_operators.push(
+            update_string
+                .concatenate(remainder_string.y(mode))
+                .expect("Internal bug in bravyi_kitaev_majorana_operators, overlapping sets."),
+        );
+    }
+    majorana_operators
+}
+
+/// Sets the parents of the modes in the interval [left, right] of the Fenwick tree.
+fn fenwick_parents(left: usize, right: usize, parents: &mut [Option<usize>]) {
+    if left == right {
+        return;
+    }
+    let middle = (left + right) / 2;
+    parents[middle] = Some(right);
+    fenwick_parents(left, middle, parents);
+    fenwick_parents(middle + 1, right, parents);
+}
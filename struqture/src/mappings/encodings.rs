@@ -0,0 +1,469 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encodings of fermionic operators in spin operators that can be selected at runtime.
+//!
+//! Every encoding is defined by the images of the 2N Majorana operators of N fermionic modes,
+//! which are mutually anticommuting PauliProducts. The Majorana operators of mode p are ordered as
+//! (a_p^{dagger} + a_p, i (a_p^{dagger} - a_p)), so that
+//!
+//! a_p^{dagger} = (gamma_{2p} - i gamma_{2p + 1})*1/2
+//! a_p = (gamma_{2p} + i gamma_{2p + 1})*1/2
+//!
+//! The following encodings are available:
+//!
+//! * [JordanWignerEncoding] - gamma_{2p} = Z_0...Z_{p-1} X_p, gamma_{2p+1} = Z_0...Z_{p-1} Y_p.
+//! * [ParityEncoding] - spin p stores the parity of the modes 0 to p,
+//!   gamma_{2p} = Z_{p-1} X_p X_{p+1}...X_{N-1}, gamma_{2p+1} = Y_p X_{p+1}...X_{N-1}.
+//! * [BravyiKitaevEncoding] - the spins store partial sums of the occupations in a Fenwick tree,
+//!   see [crate::mappings::bravyi_kitaev].
+//! * [TernaryTreeEncoding] - the spins are arranged in a complete ternary tree and the Majorana
+//!   operators are the Pauli strings along the paths from the root to the free legs of the tree,
+//!   leading to Pauli strings of weight O(log_3(N)).
+
+use super::bravyi_kitaev::bravyi_kitaev_majorana_operators;
+use super::JordanWignerFermionToSpin;
+use crate::fermions::{FermionHamiltonian, FermionOperator};
+use crate::spins::{PauliProduct, SpinHamiltonian, SpinOperator};
+use crate::{
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, SpinIndex, StruqtureError, SymmetricIndex,
+};
+use qoqo_calculator::CalculatorComplex;
+
+/// Trait for encodings of fermionic operators in spin operators.
+///
+/// The trait is object safe, so that the encoding can be selected at runtime, e.g. via a `Box<dyn FermionToSpinEncoding>`.
+pub trait FermionToSpinEncoding {
+    /// Returns the number of fermionic modes the encoding is defined for.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of fermionic modes.
+    fn number_modes(&self) -> usize;
+
+    /// Returns the number of spins (qubits) required by the encoding.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins.
+    fn number_spins(&self) -> usize;
+
+    /// Returns the number of spins required in addition to the number of fermionic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The overhead in the number of spins.
+    fn spin_overhead(&self) -> usize {
+        self.number_spins().saturating_sub(self.number_modes())
+    }
+
+    /// Returns the images of the Majorana operators of all modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<PauliProduct>` - The 2 * `number_modes` Majorana operator images, ordered as (gamma_0, gamma_1, ...).
+    fn majorana_operators(&self) -> Vec<PauliProduct>;
+
+    /// Encodes a FermionOperator as a SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The FermionOperator to encode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The encoded spin operator.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The operator acts on more modes than the encoding is defined for.
+    fn encode_operator(&self, operator: &FermionOperator) -> Result<SpinOperator, StruqtureError> {
+        let ladder_operators = LadderOperatorImages::new(&self.majorana_operators());
+        let mut out = SpinOperator::new();
+        for (fp, coeff) in operator.iter() {
+            out += ladder_operators.transform_product(fp.creators(), fp.annihilators())?
+                * coeff.clone();
+        }
+        Ok(out)
+    }
+
+    /// Encodes a FermionHamiltonian as a SpinHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The FermionHamiltonian to encode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The encoded spin Hamiltonian.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The Hamiltonian acts on more modes than the encoding is defined for.
+    fn encode(&self, hamiltonian: &FermionHamiltonian) -> Result<SpinHamiltonian, StruqtureError> {
+        let ladder_operators = LadderOperatorImages::new(&self.majorana_operators());
+        let mut out = SpinHamiltonian::new();
+        for (hfp, coeff) in hamiltonian.iter() {
+            out = out
+                + ladder_operators.transform_hermitian_product(
+                    hfp.creators(),
+                    hfp.annihilators(),
+                    hfp.is_natural_hermitian(),
+                    coeff.clone(),
+                )?;
+        }
+        Ok(out)
+    }
+}
+
+/// The Jordan-Wigner encoding of fermionic operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JordanWignerEncoding {
+    number_modes: usize,
+}
+
+impl JordanWignerEncoding {
+    /// Creates a new JordanWignerEncoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new JordanWignerEncoding.
+    pub fn new(number_modes: usize) -> Self {
+        Self { number_modes }
+    }
+}
+
+impl FermionToSpinEncoding for JordanWignerEncoding {
+    fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    fn number_spins(&self) -> usize {
+        self.number_modes
+    }
+
+    fn majorana_operators(&self) -> Vec<PauliProduct> {
+        let mut majorana_operators: Vec<PauliProduct> = Vec::with_capacity(2 * self.number_modes);
+        let mut string = PauliProduct::new();
+        for mode in 0..self.number_modes {
+            majorana_operators.push(string.clone().x(mode));
+            majorana_operators.push(string.clone().y(mode));
+            string = string.z(mode);
+        }
+        majorana_operators
+    }
+
+    fn encode_operator(&self, operator: &FermionOperator) -> Result<SpinOperator, StruqtureError> {
+        if operator.current_number_modes() > self.number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        Ok(operator.jordan_wigner())
+    }
+
+    fn encode(&self, hamiltonian: &FermionHamiltonian) -> Result<SpinHamiltonian, StruqtureError> {
+        if hamiltonian.current_number_modes() > self.number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        Ok(hamiltonian.jordan_wigner())
+    }
+}
+
+/// The parity encoding of fermionic operators, where spin p stores the parity of the modes 0 to p.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParityEncoding {
+    number_modes: usize,
+}
+
+impl ParityEncoding {
+    /// Creates a new ParityEncoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new ParityEncoding.
+    pub fn new(number_modes: usize) -> Self {
+        Self { number_modes }
+    }
+}
+
+impl FermionToSpinEncoding for ParityEncoding {
+    fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    fn number_spins(&self) -> usize {
+        self.number_modes
+    }
+
+    fn majorana_operators(&self) -> Vec<PauliProduct> {
+        let mut majorana_operators: Vec<PauliProduct> = Vec::with_capacity(2 * self.number_modes);
+        for mode in 0..self.number_modes {
+            let mut update_string = PauliProduct::new();
+            for index in (mode + 1)..self.number_modes {
+                update_string = update_string.x(index);
+            }
+            let x_term = if mode == 0 {
+                update_string.clone().x(mode)
+            } else {
+                update_string.clone().z(mode - 1).x(mode)
+            };
+            majorana_operators.push(x_term);
+            majorana_operators.push(update_string.y(mode));
+        }
+        majorana_operators
+    }
+}
+
+/// The Bravyi-Kitaev encoding of fermionic operators, see [crate::mappings::bravyi_kitaev].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BravyiKitaevEncoding {
+    number_modes: usize,
+}
+
+impl BravyiKitaevEncoding {
+    /// Creates a new BravyiKitaevEncoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new BravyiKitaevEncoding.
+    pub fn new(number_modes: usize) -> Self {
+        Self { number_modes }
+    }
+}
+
+impl FermionToSpinEncoding for BravyiKitaevEncoding {
+    fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    fn number_spins(&self) -> usize {
+        self.number_modes
+    }
+
+    fn majorana_operators(&self) -> Vec<PauliProduct> {
+        bravyi_kitaev_majorana_operators(self.number_modes)
+    }
+}
+
+/// The ternary tree encoding of fermionic operators.
+///
+/// The spins are the nodes of a complete ternary tree, where spin k has the children 3k + 1, 3k + 2 and 3k + 3
+/// connected by the X, Y and Z legs. The 2N + 1 paths from the root to the free legs define anticommuting
+/// Pauli strings. The path following only Z legs is discarded and the remaining strings, in depth-first order,
+/// are paired up into the Majorana operators of the modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TernaryTreeEncoding {
+    number_modes: usize,
+}
+
+impl TernaryTreeEncoding {
+    /// Creates a new TernaryTreeEncoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new TernaryTreeEncoding.
+    pub fn new(number_modes: usize) -> Self {
+        Self { number_modes }
+    }
+}
+
+impl FermionToSpinEncoding for TernaryTreeEncoding {
+    fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    fn number_spins(&self) -> usize {
+        self.number_modes
+    }
+
+    fn majorana_operators(&self) -> Vec<PauliProduct> {
+        let mut majorana_operators: Vec<PauliProduct> =
+            Vec::with_capacity(2 * self.number_modes + 1);
+        if self.number_modes > 0 {
+            ternary_tree_paths(
+                0,
+                PauliProduct::new(),
+                self.number_modes,
+                &mut majorana_operators,
+            );
+            // The path following only Z legs is visited last.
+            majorana_operators.pop();
+        }
+        majorana_operators
+    }
+}
+
+/// Collects the Pauli strings of all paths from a node of the ternary tree to the free legs below it.
+fn ternary_tree_paths(
+    node: usize,
+    prefix: PauliProduct,
+    number_spins: usize,
+    paths: &mut Vec<PauliProduct>,
+) {
+    let legs = [
+        (prefix.clone().x(node), 3 * node + 1),
+        (prefix.clone().y(node), 3 * node + 2),
+        (prefix.z(node), 3 * node + 3),
+    ];
+    for (string, child) in legs {
+        if child < number_spins {
+            ternary_tree_paths(child, string, number_spins, paths);
+        } else {
+            paths.push(string);
+        }
+    }
+}
+
+/// The images of all creation and annihilation operators under a fermion to spin encoding.
+#[derive(Debug, Clone)]
+pub(crate) struct LadderOperatorImages {
+    creators: Vec<SpinOperator>,
+    annihilators: Vec<SpinOperator>,
+}
+
+impl LadderOperatorImages {
+    /// Constructs the images of the ladder operators from the images of the Majorana operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `majorana_operators` - The images of the Majorana operators, ordered as (gamma_0, gamma_1, ...).
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The images of the creation and annihilation operators.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in `add_operator_product`.
+    pub(crate) fn new(majorana_operators: &[PauliProduct]) -> Self {
+        let number_modes = majorana_operators.len() / 2;
+        let mut creators: Vec<SpinOperator> = Vec::with_capacity(number_modes);
+        let mut annihilators: Vec<SpinOperator> = Vec::with_capacity(number_modes);
+        for pair in majorana_operators.chunks_exact(2) {
+            let mut creator = SpinOperator::with_capacity(2);
+            creator
+                .add_operator_product(pair[0].clone(), CalculatorComplex::new(0.5, 0.0))
+                .expect("Internal bug in add_operator_product.");
+            creator
+                .add_operator_product(pair[1].clone(), CalculatorComplex::new(0.0, -0.5))
+                .expect("Internal bug in add_operator_product.");
+            creators.push(creator);
+
+            let mut annihilator = SpinOperator::with_capacity(2);
+            annihilator
+                .add_operator_product(pair[0].clone(), CalculatorComplex::new(0.5, 0.0))
+                .expect("Internal bug in add_operator_product.");
+            annihilator
+                .add_operator_product(pair[1].clone(), CalculatorComplex::new(0.0, 0.5))
+                .expect("Internal bug in add_operator_product.");
+            annihilators.push(annihilator);
+        }
+        Self {
+            creators,
+            annihilators,
+        }
+    }
+
+    /// Constructs the images of the ladder operators under the Bravyi-Kitaev mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The total number of modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The images of the creation and annihilation operators.
+    pub(crate) fn bravyi_kitaev(number_modes: usize) -> Self {
+        Self::new(&bravyi_kitaev_majorana_operators(number_modes))
+    }
+
+    /// Transforms a product of creation and annihilation operators into a SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The creator indices of the product.
+    /// * `annihilators` - The annihilator indices of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator resulting from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - An index exceeds the number of modes.
+    pub(crate) fn transform_product<'b>(
+        &self,
+        creators: impl Iterator<Item = &'b usize>,
+        annihilators: impl Iterator<Item = &'b usize>,
+    ) -> Result<SpinOperator, StruqtureError> {
+        let mut spin_operator = SpinOperator::new();
+        spin_operator
+            .add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))?;
+        for creator in creators {
+            spin_operator = spin_operator
+                * self
+                    .creators
+                    .get(*creator)
+                    .ok_or(StruqtureError::NumberModesExceeded)?
+                    .clone();
+        }
+        for annihilator in annihilators {
+            spin_operator = spin_operator
+                * self
+                    .annihilators
+                    .get(*annihilator)
+                    .ok_or(StruqtureError::NumberModesExceeded)?
+                    .clone();
+        }
+        Ok(spin_operator)
+    }
+
+    /// Transforms a hermitian product of creation and annihilation operators with a coefficient into a SpinHamiltonian.
+    ///
+    /// For a product that is not natural hermitian the hermitian conjugate term is included,
+    /// i.e. the transformed operator is c P + c^* P^{dagger}.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The creator indices of the product.
+    /// * `annihilators` - The annihilator indices of the product.
+    /// * `is_natural_hermitian` - Whether the product is its own hermitian conjugate.
+    /// * `coefficient` - The coefficient of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The spin Hamiltonian resulting from the transformation.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - An index exceeds the number of modes.
+    pub(crate) fn transform_hermitian_product<'b>(
+        &self,
+        creators: impl Iterator<Item = &'b usize>,
+        annihilators: impl Iterator<Item = &'b usize>,
+        is_natural_hermitian: bool,
+        coefficient: CalculatorComplex,
+    ) -> Result<SpinHamiltonian, StruqtureError> {
+        let spin_operator = self.transform_product(creators, annihilators)? * coefficient;
+        // All PauliProducts are hermitian, so adding the hermitian conjugate
+        // doubles the real parts and cancels the imaginary parts of the coefficients.
+        let prefactor = if is_natural_hermitian { 1.0 } else { 2.0 };
+        let mut out = SpinHamiltonian::new();
+        for (product, coeff) in spin_operator.iter() {
+            out.add_operator_product(product.clone(), coeff.re.clone() * prefactor)?;
+        }
+        Ok(out)
+    }
+}
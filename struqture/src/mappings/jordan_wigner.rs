@@ -18,6 +18,12 @@
 //! JW(a_p^{dagger}) = ( \prod_{i = 1}^{p - 1} Z_i )(X_p - i Y_p)*1/2
 //! JW(a_p) = ( \prod_{i = 1}^{p - 1} Z_i )(X_p + i Y_p)*1/2
 
+use crate::fermions::FermionProduct;
+use crate::spins::{PauliProduct, SpinOperator};
+use crate::{ModeIndex, OperateOnDensityMatrix, SpinIndex};
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+
 pub trait JordanWignerFermionToSpin {
     /// The Output type for the JordanWigner transformation
     ///
@@ -45,3 +51,101 @@ pub trait JordanWignerSpinToFermion {
     /// the Jordan Wigner mapping.
     fn jordan_wigner(&self) -> Self::Output;
 }
+
+/// Transforms a batch of FermionProducts into SpinOperators using the Jordan-Wigner mapping.
+///
+/// The result is identical to calling `jordan_wigner` on each FermionProduct individually.
+/// The Jordan-Wigner strings of Z operators and the single-site raising and lowering operators
+/// are cached and reused across all products of the batch, which avoids reconstructing them
+/// for every product when transforming large operators.
+///
+/// # Arguments
+///
+/// * `products` - The FermionProducts to transform.
+///
+/// # Returns
+///
+/// * `Vec<SpinOperator>` - The spin operators resulting from the transformation, in the order of the input products.
+///
+/// # Panics
+///
+/// * Internal bug in `add_operator_product`.
+pub fn jordan_wigner_batch(products: &[FermionProduct]) -> Vec<SpinOperator> {
+    let mut z_strings: HashMap<(usize, usize), PauliProduct> = HashMap::new();
+    let mut lowering_operators: HashMap<usize, SpinOperator> = HashMap::new();
+    let mut raising_operators: HashMap<usize, SpinOperator> = HashMap::new();
+
+    let mut z_string = |start: usize, end: usize| -> PauliProduct {
+        z_strings
+            .entry((start, end))
+            .or_insert_with(|| {
+                let mut string = PauliProduct::with_capacity(end - start);
+                for i in start..end {
+                    string = string.z(i);
+                }
+                string
+            })
+            .clone()
+    };
+
+    let mut results: Vec<SpinOperator> = Vec::with_capacity(products.len());
+    for product in products {
+        let number_creators = product.number_creators();
+        let number_annihilators = product.number_annihilators();
+        let mut spin_operator = SpinOperator::new();
+        spin_operator
+            .add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+            .expect("Internal bug in add_operator_product.");
+
+        // Jordan-Wigner strings are inserted every second lowering (raising) operator, in even or
+        // odd positions depending on the parity of the total number of creation (annihilation)
+        // operators.
+        let mut previous = 0;
+        for (index, site) in product.creators().enumerate() {
+            if index % 2 != number_creators % 2 && previous < *site {
+                spin_operator = spin_operator * z_string(previous, *site);
+            }
+            let lowering = lowering_operators.entry(*site).or_insert_with(|| {
+                let mut out = SpinOperator::with_capacity(2);
+                out.add_operator_product(
+                    PauliProduct::new().x(*site),
+                    CalculatorComplex::new(0.5, 0.0),
+                )
+                .expect("Internal bug in add_operator_product.");
+                out.add_operator_product(
+                    PauliProduct::new().y(*site),
+                    CalculatorComplex::new(0.0, -0.5),
+                )
+                .expect("Internal bug in add_operator_product.");
+                out
+            });
+            spin_operator = spin_operator * lowering.clone();
+            previous = *site;
+        }
+
+        previous = 0;
+        for (index, site) in product.annihilators().enumerate() {
+            if index % 2 != number_annihilators % 2 && previous < *site {
+                spin_operator = spin_operator * z_string(previous, *site);
+            }
+            let raising = raising_operators.entry(*site).or_insert_with(|| {
+                let mut out = SpinOperator::with_capacity(2);
+                out.add_operator_product(
+                    PauliProduct::new().x(*site),
+                    CalculatorComplex::new(0.5, 0.0),
+                )
+                .expect("Internal bug in add_operator_product.");
+                out.add_operator_product(
+                    PauliProduct::new().y(*site),
+                    CalculatorComplex::new(0.0, 0.5),
+                )
+                .expect("Internal bug in add_operator_product.");
+                out
+            });
+            spin_operator = spin_operator * raising.clone();
+            previous = *site;
+        }
+        results.push(spin_operator);
+    }
+    results
+}
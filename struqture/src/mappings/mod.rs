@@ -12,7 +12,15 @@
 
 //! Module for representing mappings between systems of bosons, fermions and spins.
 
+pub mod bravyi_kitaev;
+pub mod encodings;
 pub mod jordan_wigner;
 
+pub use bravyi_kitaev::BravyiKitaevFermionToSpin;
+pub use encodings::{
+    BravyiKitaevEncoding, FermionToSpinEncoding, JordanWignerEncoding, ParityEncoding,
+    TernaryTreeEncoding,
+};
+pub use jordan_wigner::jordan_wigner_batch;
 pub use jordan_wigner::JordanWignerFermionToSpin;
 pub use jordan_wigner::JordanWignerSpinToFermion;
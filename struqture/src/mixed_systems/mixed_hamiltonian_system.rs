@@ -424,6 +424,13 @@ impl MixedHamiltonianSystem {
         }
     }
 
+    /// Removes all terms from the MixedHamiltonianSystem while keeping the number of spins and modes of the subsystems and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.hamiltonian.clear();
+    }
+
     /// Returns the MixedHamiltonian of the MixedHamiltonianSystem.
     ///
     /// # Returns
@@ -521,6 +528,51 @@ impl MixedHamiltonianSystem {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Writes the MixedHamiltonianSystem into an HDF5 group.
+    ///
+    /// The hamiltonian of the MixedHamiltonianSystem is written with [MixedHamiltonian::to_hdf5], the numbers of spins and modes
+    /// of the subsystems are stored in the `number_spins`, `number_bosons` and `number_fermions` datasets,
+    /// where unset numbers are stored as -1.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedHamiltonianSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedHamiltonianSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.hamiltonian.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_spins", &self.number_spins)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_bosons", &self.number_bosons)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_fermions", &self.number_fermions)
+    }
+
+    /// Reads a MixedHamiltonianSystem from an HDF5 group written with [MixedHamiltonianSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedHamiltonianSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedHamiltonianSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The hamiltonian could not be read or does not fit into the numbers of spins and modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_hamiltonian(
+            MixedHamiltonian::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_numbers(group, "number_spins")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_bosons")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_fermions")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of MixedHamiltonianSystem.
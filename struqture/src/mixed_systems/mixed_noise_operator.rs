@@ -403,6 +403,22 @@ impl MixedLindbladNoiseOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the MixedLindbladNoiseOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the MixedLindbladNoiseOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
     // /// Separate self into an operator with the terms of given number of qubits and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -439,6 +455,61 @@ impl MixedLindbladNoiseOperator {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Writes the MixedLindbladNoiseOperator into an HDF5 group.
+    ///
+    /// The left and right MixedDecoherenceProducts of the sorted terms are stored as strings in the `left_keys` and `right_keys`
+    /// datasets, the real and imaginary parts of the coefficients in the `real` and `imag` datasets.
+    /// The minimum struqture version required to read the data is stored in the `struqture_major_version`
+    /// and `struqture_minor_version` attributes.
+    /// The numbers of spin, bosonic and fermionic subsystems are stored in the `number_spin_subsystems`,
+    /// `number_boson_subsystems` and `number_fermion_subsystems` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedLindbladNoiseOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedLindbladNoiseOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_noise_terms(group, self.iter())?;
+        crate::hdf5_io::write_number(group, "number_spin_subsystems", self.n_spins)?;
+        crate::hdf5_io::write_number(group, "number_boson_subsystems", self.n_bosons)?;
+        crate::hdf5_io::write_number(group, "number_fermion_subsystems", self.n_fermions)
+    }
+
+    /// Reads a MixedLindbladNoiseOperator from an HDF5 group written with [MixedLindbladNoiseOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedLindbladNoiseOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladNoiseOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a MixedDecoherenceProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(
+            (MixedDecoherenceProduct, MixedDecoherenceProduct),
+            CalculatorComplex,
+        )> = crate::hdf5_io::read_noise_terms(group)?;
+        let mut operator = Self::new(
+            crate::hdf5_io::read_number(group, "number_spin_subsystems")?,
+            crate::hdf5_io::read_number(group, "number_boson_subsystems")?,
+            crate::hdf5_io::read_number(group, "number_fermion_subsystems")?,
+        );
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladNoiseOperator.
@@ -514,6 +514,13 @@ impl MixedLindbladNoiseSystem {
         }
     }
 
+    /// Removes all terms from the MixedLindbladNoiseSystem while keeping the number of spins and modes of the subsystems and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the MixedLindbladNoiseOperator of the MixedLindbladNoiseSystem.
     ///
     /// # Returns
@@ -618,6 +625,51 @@ impl MixedLindbladNoiseSystem {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Writes the MixedLindbladNoiseSystem into an HDF5 group.
+    ///
+    /// The operator of the MixedLindbladNoiseSystem is written with [MixedLindbladNoiseOperator::to_hdf5], the numbers of spins and modes
+    /// of the subsystems are stored in the `number_spins`, `number_bosons` and `number_fermions` datasets,
+    /// where unset numbers are stored as -1.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedLindbladNoiseSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedLindbladNoiseSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_spins", &self.number_spins)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_bosons", &self.number_bosons)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_fermions", &self.number_fermions)
+    }
+
+    /// Reads a MixedLindbladNoiseSystem from an HDF5 group written with [MixedLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedLindbladNoiseSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladNoiseSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the numbers of spins and modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            MixedLindbladNoiseOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_numbers(group, "number_spins")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_bosons")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_fermions")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladNoiseSystem.
@@ -10,9 +10,15 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedHamiltonianSystem, MixedLindbladNoiseSystem, OperateOnMixedSystems};
-use crate::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
-use qoqo_calculator::CalculatorFloat;
+use super::{
+    HermitianMixedProduct, MixedDecoherenceProduct, MixedHamiltonianSystem, MixedIndex,
+    MixedLindbladNoiseSystem, OperateOnMixedSystems,
+};
+use crate::bosons::{BosonLindbladOpenSystem, BosonProduct};
+use crate::fermions::{FermionLindbladOpenSystem, FermionProduct};
+use crate::spins::{DecoherenceProduct, PauliProduct, SpinLindbladOpenSystem};
+use crate::{ModeIndex, OpenSystem, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops;
@@ -330,6 +336,172 @@ impl MixedLindbladOpenSystem {
             noise: MixedLindbladNoiseSystem::new(number_spins, number_bosons, number_fermions),
         }
     }
+
+    /// Creates a new MixedLindbladOpenSystem from separate spin, bosonic and fermionic open systems.
+    ///
+    /// Each given open system becomes one subsystem of the MixedLindbladOpenSystem.
+    /// The terms of each open system are wrapped into mixed products acting as the identity on all other subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin` - The optional SpinLindbladOpenSystem forming the spin subsystem.
+    /// * `boson` - The optional BosonLindbladOpenSystem forming the bosonic subsystem.
+    /// * `fermion` - The optional FermionLindbladOpenSystem forming the fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladOpenSystem combining the input open systems.
+    /// * `Err(StruqtureError)` - Error adding the terms of one of the open systems.
+    pub fn from_subsystems(
+        spin: Option<SpinLindbladOpenSystem>,
+        boson: Option<BosonLindbladOpenSystem>,
+        fermion: Option<FermionLindbladOpenSystem>,
+    ) -> Result<Self, StruqtureError> {
+        let mut open_system = Self::new(
+            spin.iter().map(|open| open.system().number_spins),
+            boson.iter().map(|open| open.system().number_modes),
+            fermion.iter().map(|open| open.system().number_modes),
+        );
+        let spin_identity: Vec<PauliProduct> = spin.iter().map(|_| PauliProduct::new()).collect();
+        let decoherence_identity: Vec<DecoherenceProduct> =
+            spin.iter().map(|_| DecoherenceProduct::new()).collect();
+        let boson_identity: Vec<BosonProduct> = boson
+            .iter()
+            .map(|_| BosonProduct::new([], []))
+            .collect::<Result<_, _>>()?;
+        let fermion_identity: Vec<FermionProduct> = fermion
+            .iter()
+            .map(|_| FermionProduct::new([], []))
+            .collect::<Result<_, _>>()?;
+
+        if let Some(spin_open_system) = spin {
+            let (system, noise) = spin_open_system.ungroup();
+            for (key, value) in system {
+                let mixed_key = HermitianMixedProduct::new(
+                    [key],
+                    boson_identity.clone(),
+                    fermion_identity.clone(),
+                )?;
+                open_system
+                    .system
+                    .add_operator_product(mixed_key, CalculatorComplex::from(value))?;
+            }
+            for ((left, right), value) in noise {
+                let mixed_left = MixedDecoherenceProduct::new(
+                    [left],
+                    boson_identity.clone(),
+                    fermion_identity.clone(),
+                )?;
+                let mixed_right = MixedDecoherenceProduct::new(
+                    [right],
+                    boson_identity.clone(),
+                    fermion_identity.clone(),
+                )?;
+                open_system
+                    .noise
+                    .add_operator_product((mixed_left, mixed_right), value)?;
+            }
+        }
+        if let Some(boson_open_system) = boson {
+            let (system, noise) = boson_open_system.ungroup();
+            for (key, value) in system {
+                let boson_key =
+                    BosonProduct::new(key.creators().copied(), key.annihilators().copied())?;
+                let mixed_key = HermitianMixedProduct::new(
+                    spin_identity.clone(),
+                    [boson_key],
+                    fermion_identity.clone(),
+                )?;
+                open_system.system.add_operator_product(mixed_key, value)?;
+            }
+            for ((left, right), value) in noise {
+                let mixed_left = MixedDecoherenceProduct::new(
+                    decoherence_identity.clone(),
+                    [left],
+                    fermion_identity.clone(),
+                )?;
+                let mixed_right = MixedDecoherenceProduct::new(
+                    decoherence_identity.clone(),
+                    [right],
+                    fermion_identity.clone(),
+                )?;
+                open_system
+                    .noise
+                    .add_operator_product((mixed_left, mixed_right), value)?;
+            }
+        }
+        if let Some(fermion_open_system) = fermion {
+            let (system, noise) = fermion_open_system.ungroup();
+            for (key, value) in system {
+                let fermion_key =
+                    FermionProduct::new(key.creators().copied(), key.annihilators().copied())?;
+                let mixed_key = HermitianMixedProduct::new(
+                    spin_identity.clone(),
+                    boson_identity.clone(),
+                    [fermion_key],
+                )?;
+                open_system.system.add_operator_product(mixed_key, value)?;
+            }
+            for ((left, right), value) in noise {
+                let mixed_left = MixedDecoherenceProduct::new(
+                    decoherence_identity.clone(),
+                    boson_identity.clone(),
+                    [left],
+                )?;
+                let mixed_right = MixedDecoherenceProduct::new(
+                    decoherence_identity.clone(),
+                    boson_identity.clone(),
+                    [right],
+                )?;
+                open_system
+                    .noise
+                    .add_operator_product((mixed_left, mixed_right), value)?;
+            }
+        }
+        Ok(open_system)
+    }
+
+    /// Writes the MixedLindbladOpenSystem into an HDF5 group.
+    ///
+    /// The system and noise parts are written into the subgroups `system` and `noise` with
+    /// [MixedHamiltonianSystem::to_hdf5] and [MixedLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedLindbladOpenSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedLindbladOpenSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::create_open_system_groups(group)?;
+        self.system.to_hdf5(&system_group)?;
+        self.noise.to_hdf5(&noise_group)
+    }
+
+    /// Reads a MixedLindbladOpenSystem from an HDF5 group written with [MixedLindbladOpenSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedLindbladOpenSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladOpenSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The system or noise could not be read or do not have matching sizes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::open_system_groups(group)?;
+        Self::group(
+            MixedHamiltonianSystem::from_hdf5(&system_group)?,
+            MixedLindbladNoiseSystem::from_hdf5(&noise_group)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladOpenSystem.
@@ -11,10 +11,13 @@
 // limitations under the License.
 
 use super::{MixedIndex, MixedProduct, OperateOnMixedSystems};
+use crate::bosons::boson_fock_space_dimension;
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    sorted_coo_from_entries, CooSparseMatrix, ModeIndex, OperateOnDensityMatrix, OperateOnState,
+    SpinIndex, StruqtureError, StruqtureVersionSerializable, TruncateTrait,
+    MINIMUM_STRUQTURE_VERSION,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -30,6 +33,9 @@ use std::collections::hash_map::{Entry, Iter, Keys, Values};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::HashMap;
 
+/// Shorthand type notation for the spin, boson and fermion subsystems a term acts on non-trivially
+type SupportPattern = (Vec<bool>, Vec<bool>, Vec<bool>);
+
 /// MixedOperators are combinations of MixedProducts with specific CalculatorComplex coefficients.
 ///
 /// # Example
@@ -169,6 +175,23 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedOperator {
         self.internal_map.remove(key)
     }
 
+    // From trait
+    fn drain_filter<F>(&mut self, predicate: F) -> Vec<(Self::Index, Self::Value)>
+    where
+        F: Fn(&Self::Index, &Self::Value) -> bool,
+    {
+        let mut drained: Vec<(Self::Index, Self::Value)> = Vec::new();
+        self.internal_map.retain(|key, value| {
+            if predicate(key, value) {
+                drained.push((key.clone(), value.clone()));
+                false
+            } else {
+                true
+            }
+        });
+        drained
+    }
+
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
         match capacity {
@@ -194,10 +217,7 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if key.spins().len() != self.n_spins
-            || key.bosons().len() != self.n_bosons
-            || key.fermions().len() != self.n_fermions
-        {
+        if !self.matches_subsystems(&key) {
             return Err(StruqtureError::MissmatchedNumberSubsystems {
                 target_number_spin_subsystems: self.n_spins,
                 target_number_boson_subsystems: self.n_bosons,
@@ -351,6 +371,339 @@ impl MixedOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the MixedOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the MixedOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Counts the terms of the MixedOperator for each pattern of subsystems they act on.
+    ///
+    /// The key of the histogram records for every spin, boson and fermion subsystem
+    /// whether the term acts non-trivially (with a non-identity operator) on it.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeMap<(Vec<bool>, Vec<bool>, Vec<bool>), usize>` - The number of terms for each support pattern.
+    pub fn support_histogram(&self) -> std::collections::BTreeMap<SupportPattern, usize> {
+        let mut histogram: std::collections::BTreeMap<SupportPattern, usize> =
+            std::collections::BTreeMap::new();
+        for key in self.internal_map.keys() {
+            let pattern = (
+                key.spins().map(|spin| !spin.is_empty()).collect(),
+                key.bosons()
+                    .map(|boson| boson.number_creators() + boson.number_annihilators() > 0)
+                    .collect(),
+                key.fermions()
+                    .map(|fermion| fermion.number_creators() + fermion.number_annihilators() > 0)
+                    .collect(),
+            );
+            *histogram.entry(pattern).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns whether the MixedProduct acts on the same number of subsystems as the MixedOperator.
+    fn matches_subsystems(&self, key: &MixedProduct) -> bool {
+        key.spins().len() == self.n_spins
+            && key.bosons().len() == self.n_bosons
+            && key.fermions().len() == self.n_fermions
+    }
+
+    /// Checks that another MixedOperator is defined on the same number of subsystems as Self.
+    fn check_same_subsystems(&self, other: &Self) -> Result<(), StruqtureError> {
+        if self.n_spins != other.n_spins
+            || self.n_bosons != other.n_bosons
+            || self.n_fermions != other.n_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: other.n_spins,
+                actual_number_boson_subsystems: other.n_bosons,
+                actual_number_fermion_subsystems: other.n_fermions,
+            });
+        }
+        Ok(())
+    }
+
+    /// Subtracts another MixedOperator from Self, checking that the subsystems match.
+    ///
+    /// The number of subsystems of `other` is checked before any term is subtracted,
+    /// so an error is also returned if `other` is empty but defined on different subsystems.
+    /// The `-` operator of MixedOperator uses this function.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two MixedOperators subtracted.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn checked_sub(self, other: Self) -> Result<Self, StruqtureError> {
+        self.check_same_subsystems(&other)?;
+        let mut new_self = self;
+        for (key, value) in other.into_iter() {
+            new_self.add_operator_product(key, value * -1.0)?;
+        }
+        Ok(new_self)
+    }
+
+    /// Constructs the sparse matrix representation of the MixedOperator in COO format.
+    ///
+    /// The matrix of each MixedProduct is the Kronecker product of the matrices of its subsystems
+    /// (see [crate::mixed_systems::MixedProduct::subsystem_matrices]) multiplied by the coefficient.
+    /// The tensor ordering is: all spin subsystems, then all boson subsystems, then all fermion subsystems,
+    /// where the first subsystem is the leftmost factor of the Kronecker product (the most significant part of the basis state index).
+    /// Each boson subsystem contains as many modes as the MixedOperator acts on in that subsystem,
+    /// each mode truncated to `boson_cutoffs[i]` occupation numbers.
+    /// The entries are sorted by row and column, entries that sum to zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin_counts` - The number of spins of each spin subsystem.
+    /// * `boson_cutoffs` - The number of occupation numbers per mode of each boson subsystem.
+    /// * `fermion_modes` - The number of modes of each fermion subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix representation of the MixedOperator.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - The number of entries in the inputs does not match the number of subsystems.
+    /// * `Err(StruqtureError::GenericError)` - The dimension of the matrix does not fit into usize.
+    /// * `Err(StruqtureError)` - Error constructing the matrices of the subsystems.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(
+        &self,
+        spin_counts: &[usize],
+        boson_cutoffs: &[usize],
+        fermion_modes: &[usize],
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if spin_counts.len() != self.n_spins
+            || boson_cutoffs.len() != self.n_bosons
+            || fermion_modes.len() != self.n_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: spin_counts.len(),
+                actual_number_boson_subsystems: boson_cutoffs.len(),
+                actual_number_fermion_subsystems: fermion_modes.len(),
+            });
+        }
+        let boson_modes = self.current_number_bosonic_modes();
+        // The dimensions of all subsystems and of the whole matrix are checked first,
+        // so that no index of the Kronecker products below can overflow
+        let overflow = || {
+            StruqtureError::GenericError {
+            msg: format!(
+                "The matrix of the MixedOperator with {:?} spins, boson cutoffs {:?} and {:?} fermionic modes cannot be represented, its dimension exceeds {}.",
+                spin_counts,
+                boson_cutoffs,
+                fermion_modes,
+                usize::MAX
+            ),
+        }
+        };
+        let two_level_dimension = |number: usize| {
+            u32::try_from(number)
+                .ok()
+                .and_then(|number| 2usize.checked_pow(number))
+                .ok_or_else(overflow)
+        };
+        let spin_dimensions: Vec<usize> = spin_counts
+            .iter()
+            .map(|number_spins| two_level_dimension(*number_spins))
+            .collect::<Result<_, _>>()?;
+        let boson_dimensions: Vec<usize> = boson_cutoffs
+            .iter()
+            .zip(boson_modes.iter())
+            .map(|(cutoff, number_modes)| boson_fock_space_dimension(&vec![*cutoff; *number_modes]))
+            .collect::<Result<_, _>>()?;
+        let fermion_dimensions: Vec<usize> = fermion_modes
+            .iter()
+            .map(|number_modes| two_level_dimension(*number_modes))
+            .collect::<Result<_, _>>()?;
+        spin_dimensions
+            .iter()
+            .chain(boson_dimensions.iter())
+            .chain(fermion_dimensions.iter())
+            .try_fold(1usize, |dimension, subsystem| {
+                dimension.checked_mul(*subsystem)
+            })
+            .ok_or_else(overflow)?;
+        let mut entries: std::collections::HashMap<(usize, usize), Complex64> =
+            std::collections::HashMap::new();
+        for (product, value) in self.iter() {
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            let mut term: std::collections::HashMap<(usize, usize), Complex64> =
+                std::collections::HashMap::new();
+            term.insert((0, 0), Complex64::new(real, imaginary));
+
+            let mut matrices = product
+                .subsystem_matrices(spin_counts, boson_cutoffs, fermion_modes)?
+                .into_iter();
+            // The subsystem counts are zipped first, so that no matrix of the next subsystem type is consumed
+            for (dimension, matrix) in spin_dimensions.iter().zip(matrices.by_ref()) {
+                term = kronecker_product(&term, &matrix, *dimension);
+            }
+            for ((cutoff, number_modes), (boson, matrix)) in boson_cutoffs
+                .iter()
+                .zip(boson_modes.iter())
+                .zip(product.bosons().zip(matrices.by_ref()))
+            {
+                // The modes the product does not act on are the most significant part of the subsystem index
+                let product_modes = boson.current_number_modes();
+                let padding =
+                    boson_fock_space_dimension(&vec![*cutoff; number_modes - product_modes])?;
+                let identity: CooSparseMatrix = (
+                    vec![Complex64::new(1.0, 0.0); padding],
+                    ((0..padding).collect(), (0..padding).collect()),
+                );
+                term = kronecker_product(&term, &identity, padding);
+                term = kronecker_product(
+                    &term,
+                    &matrix,
+                    boson_fock_space_dimension(&vec![*cutoff; product_modes])?,
+                );
+            }
+            for (matrix, dimension) in matrices.zip(fermion_dimensions.iter()) {
+                term = kronecker_product(&term, &matrix, *dimension);
+            }
+            for (key, entry) in term.into_iter() {
+                *entries
+                    .entry(key)
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += entry;
+            }
+        }
+        Ok(sorted_coo_from_entries(entries))
+    }
+
+    /// Traces out all boson subsystems of the MixedOperator.
+    ///
+    /// Each boson subsystem contains as many modes as the MixedOperator acts on in that subsystem,
+    /// each mode truncated to `cutoffs[i]` occupation numbers (as in [MixedOperator::sparse_matrix_coo]).
+    /// A product only survives if its boson part is diagonal in the occupation number basis,
+    /// i.e. has the same number of creators and annihilators on every mode.
+    /// For a mode with `k` creators and annihilators the coefficient is multiplied by
+    /// `sum_{n=k}^{cutoff-1} n! / (n-k)!`, so a boson identity contributes a factor `cutoff` per mode.
+    /// The resulting coefficients therefore depend on the chosen cutoffs, they diverge
+    /// for all surviving terms when the cutoffs are increased.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoffs` - The number of occupation numbers per mode of each boson subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The MixedOperator without boson subsystems.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - The number of cutoffs does not match the number of boson subsystems.
+    pub fn partial_trace_bosons(&self, cutoffs: &[usize]) -> Result<MixedOperator, StruqtureError> {
+        if cutoffs.len() != self.n_bosons {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: self.n_spins,
+                actual_number_boson_subsystems: cutoffs.len(),
+                actual_number_fermion_subsystems: self.n_fermions,
+            });
+        }
+        let boson_modes = self.current_number_bosonic_modes();
+        let mut traced = MixedOperator::new(self.n_spins, 0, self.n_fermions);
+        for (product, value) in self.iter() {
+            let mut weight: f64 = 1.0;
+            for ((boson, cutoff), number_modes) in
+                product.bosons().zip(cutoffs.iter()).zip(boson_modes.iter())
+            {
+                for mode in 0..*number_modes {
+                    let creators = boson.creators().filter(|index| **index == mode).count();
+                    let annihilators = boson.annihilators().filter(|index| **index == mode).count();
+                    let trace: f64 = if creators == annihilators {
+                        // Diagonal element n! / (n-k)! of the normal ordered product on occupation n
+                        (creators..*cutoff)
+                            .map(|n| (0..creators).map(|j| (n - j) as f64).product::<f64>())
+                            .sum()
+                    } else {
+                        0.0
+                    };
+                    weight *= trace;
+                }
+            }
+            if weight != 0.0 {
+                let traced_product = MixedProduct::new(
+                    product.spins().cloned(),
+                    Vec::<crate::bosons::BosonProduct>::new(),
+                    product.fermions().cloned(),
+                )?;
+                traced.add_operator_product(traced_product, value.clone() * weight)?;
+            }
+        }
+        Ok(traced)
+    }
+
+    /// Truncates the MixedOperator with a separate threshold for each type of subsystem.
+    ///
+    /// A MixedProduct acts non-trivially on the spins if any of its PauliProducts is not the identity,
+    /// on the bosons if any of its BosonProducts contains a creator or annihilator and
+    /// on the fermions if any of its FermionProducts contains a creator or annihilator.
+    /// The coefficient of each MixedProduct is truncated (see [crate::TruncateTrait]) with the maximum of the
+    /// thresholds of the subsystem types it acts on non-trivially, e.g. a spin-boson coupling term is truncated
+    /// with `max(spin_threshold, boson_threshold)`. The identity term is only removed if its coefficient is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin_threshold` - The threshold for terms acting non-trivially on the spins.
+    /// * `boson_threshold` - The threshold for terms acting non-trivially on the bosons.
+    /// * `fermion_threshold` - The threshold for terms acting non-trivially on the fermions.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated MixedOperator.
+    pub fn truncate_by_subsystem(
+        &self,
+        spin_threshold: f64,
+        boson_threshold: f64,
+        fermion_threshold: f64,
+    ) -> Self {
+        let mut truncated = self.empty_clone(Some(self.len()));
+        for (product, value) in self.iter() {
+            let mut threshold: f64 = 0.0;
+            if product.spins().any(|spin| !spin.is_empty()) {
+                threshold = threshold.max(spin_threshold);
+            }
+            if product
+                .bosons()
+                .any(|boson| boson.creators().len() + boson.annihilators().len() > 0)
+            {
+                threshold = threshold.max(boson_threshold);
+            }
+            if product
+                .fermions()
+                .any(|fermion| fermion.creators().len() + fermion.annihilators().len() > 0)
+            {
+                threshold = threshold.max(fermion_threshold);
+            }
+            if let Some(new_value) = value.truncate(threshold) {
+                truncated.internal_map.insert(product.clone(), new_value);
+            }
+        }
+        truncated
+    }
+
     // /// Separate self into an operator with the terms of given number of spins, bosons and fermions and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -380,6 +733,79 @@ impl MixedOperator {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Writes the MixedOperator into an HDF5 group.
+    ///
+    /// The MixedProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    /// The numbers of spin, bosonic and fermionic subsystems are stored in the `number_spin_subsystems`,
+    /// `number_boson_subsystems` and `number_fermion_subsystems` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())?;
+        crate::hdf5_io::write_number(group, "number_spin_subsystems", self.n_spins)?;
+        crate::hdf5_io::write_number(group, "number_boson_subsystems", self.n_bosons)?;
+        crate::hdf5_io::write_number(group, "number_fermion_subsystems", self.n_fermions)
+    }
+
+    /// Reads a MixedOperator from an HDF5 group written with [MixedOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a MixedProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(MixedProduct, CalculatorComplex)> = crate::hdf5_io::read_terms(group)?;
+        let mut operator = Self::new(
+            crate::hdf5_io::read_number(group, "number_spin_subsystems")?,
+            crate::hdf5_io::read_number(group, "number_boson_subsystems")?,
+            crate::hdf5_io::read_number(group, "number_fermion_subsystems")?,
+        );
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
+}
+
+/// Kronecker product of a sparse matrix given as a HashMap with a sparse matrix in COO format.
+fn kronecker_product(
+    left: &std::collections::HashMap<(usize, usize), Complex64>,
+    right: &CooSparseMatrix,
+    right_dimension: usize,
+) -> std::collections::HashMap<(usize, usize), Complex64> {
+    let (values, (rows, columns)) = right;
+    let mut product = std::collections::HashMap::with_capacity(left.len() * values.len());
+    for ((left_row, left_column), left_value) in left.iter() {
+        for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+            product.insert(
+                (
+                    left_row * right_dimension + row,
+                    left_column * right_dimension + column,
+                ),
+                left_value * value,
+            );
+        }
+    }
+    product
 }
 
 /// Implements the negative sign function of MixedOperator.
@@ -452,11 +878,12 @@ where
     ///
     /// * `Ok(Self)` - The two MixedOperators subtracted.
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
-    fn sub(mut self, other: T) -> Self::Output {
+    fn sub(self, other: T) -> Self::Output {
+        let mut other_operator = MixedOperator::new(self.n_spins, self.n_bosons, self.n_fermions);
         for (key, value) in other.into_iter() {
-            self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
+            other_operator.add_operator_product(key, Into::<CalculatorComplex>::into(value))?;
         }
-        Ok(self)
+        self.checked_sub(other_operator)
     }
 }
 
@@ -494,6 +921,83 @@ where
     }
 }
 
+/// Implements the in-place plus function of MixedOperator by MixedOperator.
+///
+impl ops::AddAssign<MixedOperator> for MixedOperator {
+    /// Implements `+=` (add in place) for two MixedOperators.
+    ///
+    /// The number of subsystems is checked before any term is added, so self is never partially updated.
+    /// Use `+` to get an error instead of a panic for mismatched subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to be added.
+    ///
+    /// # Panics
+    ///
+    /// * Number of subsystems in self and other do not match.
+    fn add_assign(&mut self, other: MixedOperator) {
+        if let Err(error) = self.check_same_subsystems(&other) {
+            panic!(
+                "Cannot add MixedOperators defined on different subsystems: {}",
+                error
+            );
+        }
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, value)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place minus function of MixedOperator by MixedOperator.
+///
+impl ops::SubAssign<MixedOperator> for MixedOperator {
+    /// Implements `-=` (subtract in place) for two MixedOperators.
+    ///
+    /// The number of subsystems is checked before any term is subtracted, so self is never partially updated.
+    /// Use `-` to get an error instead of a panic for mismatched subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to be subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Number of subsystems in self and other do not match.
+    fn sub_assign(&mut self, other: MixedOperator) {
+        if let Err(error) = self.check_same_subsystems(&other) {
+            panic!(
+                "Cannot subtract MixedOperators defined on different subsystems: {}",
+                error
+            );
+        }
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, value * -1.0)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place multiplication function of MixedOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::MulAssign<T> for MixedOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    /// Implement `*=` (multiply in place) for MixedOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    fn mul_assign(&mut self, other: T) {
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        for value in self.internal_map.values_mut() {
+            *value = value.clone() * other_cc.clone();
+        }
+    }
+}
+
 /// Implements the multiplication function of MixedOperator by MixedOperator.
 ///
 impl ops::Mul<MixedOperator> for MixedOperator {
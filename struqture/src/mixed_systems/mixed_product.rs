@@ -11,10 +11,15 @@
 // limitations under the License.
 
 use super::{GetValueMixed, HermitianMixedProduct, MixedIndex};
-use crate::bosons::BosonProduct;
+use crate::bosons::{boson_product_matrix_entries, BosonProduct};
 use crate::fermions::FermionProduct;
+use crate::mappings::JordanWignerFermionToSpin;
 use crate::spins::PauliProduct;
-use crate::{CorrespondsTo, StruqtureError, SymmetricIndex};
+use crate::spins::{SpinOperator, ToSparseMatrixOperator};
+use crate::{
+    CooSparseMatrix, CorrespondsTo, ModeIndex, OperateOnDensityMatrix, SpinIndex, StruqtureError,
+    SymmetricIndex,
+};
 use num_complex::Complex64;
 use serde::{
     de::{Error, SeqAccess, Visitor},
@@ -273,6 +278,171 @@ impl MixedIndex for MixedProduct {
     }
 }
 
+/// Functions for the MixedProduct
+///
+impl MixedProduct {
+    /// Creates a new MixedProduct from products labeled with the subsystem they act on.
+    ///
+    /// The subsystems can be given in any order. The number of subsystems of each type is given
+    /// by the largest slot index plus one, subsystems without a product are filled with the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `spins` - Pairs of (subsystem slot, product of pauli operators acting on qubits).
+    /// * `bosons` - Pairs of (subsystem slot, product of bosonic creation and annihilation operators).
+    /// * `fermions` - Pairs of (subsystem slot, product of fermionic creation and annihilation operators).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new MixedProduct with the products in the given slots.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem slot is assigned more than once.
+    /// * `Err(StruqtureError::GenericError)` - A subsystem slot is not smaller than the maximal number of subsystems (64).
+    pub fn from_labeled(
+        spins: &[(usize, PauliProduct)],
+        bosons: &[(usize, BosonProduct)],
+        fermions: &[(usize, FermionProduct)],
+    ) -> Result<Self, StruqtureError> {
+        Ok(Self {
+            spins: fill_labeled_slots(spins, "spin")?,
+            bosons: fill_labeled_slots(bosons, "boson")?,
+            fermions: fill_labeled_slots(fermions, "fermion")?,
+        })
+    }
+
+    /// Returns the sparse matrices of the factors of the MixedProduct acting on the individual subsystems.
+    ///
+    /// The matrices are returned in the order spin subsystems, boson subsystems, fermion subsystems,
+    /// so that their Kronecker product (with the first matrix as the leftmost factor) gives the matrix of
+    /// the full MixedProduct.
+    ///
+    /// * The matrix of a spin subsystem is the matrix of the PauliProduct for `spin_dims[i]` spins.
+    /// * The matrix of a boson subsystem is the matrix of the BosonProduct on the modes the product acts on,
+    ///   where each mode is truncated to the `boson_cutoffs[i]` lowest occupation numbers. The basis state
+    ///   index is `sum_k n_k * cutoff^k` for occupation numbers `n_k` of mode `k`.
+    /// * The matrix of a fermion subsystem is the matrix of the Jordan-Wigner transformed FermionProduct for `fermion_modes[i]` modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin_dims` - The number of spins of each spin subsystem.
+    /// * `boson_cutoffs` - The number of occupation numbers per mode of each boson subsystem.
+    /// * `fermion_modes` - The number of modes of each fermion subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<CooSparseMatrix>)` - The matrices of all subsystems in order.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - The number of entries in the inputs does not match the number of subsystems.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - A PauliProduct acts on more spins than given in `spin_dims`.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - A FermionProduct acts on more modes than given in `fermion_modes`.
+    /// * `Err(StruqtureError::GenericError)` - A boson cutoff is zero.
+    pub fn subsystem_matrices(
+        &self,
+        spin_dims: &[usize],
+        boson_cutoffs: &[usize],
+        fermion_modes: &[usize],
+    ) -> Result<Vec<CooSparseMatrix>, StruqtureError> {
+        if spin_dims.len() != self.spins.len()
+            || boson_cutoffs.len() != self.bosons.len()
+            || fermion_modes.len() != self.fermions.len()
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.spins.len(),
+                target_number_boson_subsystems: self.bosons.len(),
+                target_number_fermion_subsystems: self.fermions.len(),
+                actual_number_spin_subsystems: spin_dims.len(),
+                actual_number_boson_subsystems: boson_cutoffs.len(),
+                actual_number_fermion_subsystems: fermion_modes.len(),
+            });
+        }
+        let mut matrices: Vec<CooSparseMatrix> =
+            Vec::with_capacity(self.spins.len() + self.bosons.len() + self.fermions.len());
+        for (pauli_product, number_spins) in self.spins.iter().zip(spin_dims.iter()) {
+            if pauli_product.current_number_spins() > *number_spins {
+                return Err(StruqtureError::NumberSpinsExceeded);
+            }
+            let mut operator = SpinOperator::new();
+            operator.add_operator_product(
+                pauli_product.clone(),
+                qoqo_calculator::CalculatorComplex::from(1.0),
+            )?;
+            matrices.push(operator.sparse_matrix_coo(Some(*number_spins))?);
+        }
+        for (boson_product, cutoff) in self.bosons.iter().zip(boson_cutoffs.iter()) {
+            matrices.push(boson_product_matrix(boson_product, *cutoff)?);
+        }
+        for (fermion_product, number_modes) in self.fermions.iter().zip(fermion_modes.iter()) {
+            if fermion_product.current_number_modes() > *number_modes {
+                return Err(StruqtureError::NumberModesExceeded);
+            }
+            matrices.push(
+                fermion_product
+                    .jordan_wigner()
+                    .sparse_matrix_coo(Some(*number_modes))?,
+            );
+        }
+        Ok(matrices)
+    }
+}
+
+/// Maximal number of subsystems of one type that can be created with [MixedProduct::from_labeled].
+const MAX_LABELED_SUBSYSTEMS: usize = 64;
+
+/// Places labeled products in their subsystem slots, filling the remaining slots with identities.
+fn fill_labeled_slots<T: Clone + Default>(
+    labeled: &[(usize, T)],
+    subsystem_type: &str,
+) -> Result<TinyVec<[T; 2]>, StruqtureError> {
+    if let Some((slot, _)) = labeled
+        .iter()
+        .find(|(slot, _)| *slot >= MAX_LABELED_SUBSYSTEMS)
+    {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The {} subsystem slot {} exceeds the maximal number of {} subsystems.",
+                subsystem_type, slot, MAX_LABELED_SUBSYSTEMS
+            ),
+        });
+    }
+    let number_subsystems = labeled.iter().map(|(slot, _)| slot + 1).max().unwrap_or(0);
+    let mut slots: Vec<Option<T>> = vec![None; number_subsystems];
+    for (slot, product) in labeled.iter() {
+        if slots[*slot].replace(product.clone()).is_some() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The {} subsystem slot {} is assigned more than once.",
+                    subsystem_type, slot
+                ),
+            });
+        }
+    }
+    Ok(slots
+        .into_iter()
+        .map(|product| product.unwrap_or_default())
+        .collect())
+}
+
+/// Constructs the matrix of a BosonProduct with every mode truncated to `cutoff` occupation numbers.
+fn boson_product_matrix(
+    boson_product: &BosonProduct,
+    cutoff: usize,
+) -> Result<CooSparseMatrix, StruqtureError> {
+    if cutoff == 0 {
+        return Err(StruqtureError::GenericError {
+            msg: "The boson cutoff needs to be at least one.".to_string(),
+        });
+    }
+    let cutoffs = vec![cutoff; boson_product.current_number_modes()];
+    let entries = boson_product_matrix_entries(boson_product, &cutoffs)?;
+    let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+    let mut rows: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+    for (row, column, value) in entries.into_iter() {
+        values.push(Complex64::new(value, 0.0));
+        rows.push(row);
+        columns.push(column);
+    }
+    Ok((values, (rows, columns)))
+}
+
 impl FromStr for MixedProduct {
     type Err = StruqtureError;
     /// Constructs a MixedProduct from a string.
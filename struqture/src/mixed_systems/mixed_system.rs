@@ -428,6 +428,13 @@ impl MixedSystem {
         }
     }
 
+    /// Removes all terms from the MixedSystem while keeping the number of spins and modes of the subsystems and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the MixedOperator of the MixedSystem.
     ///
     /// # Returns
@@ -525,6 +532,51 @@ impl MixedSystem {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Writes the MixedSystem into an HDF5 group.
+    ///
+    /// The operator of the MixedSystem is written with [MixedOperator::to_hdf5], the numbers of spins and modes
+    /// of the subsystems are stored in the `number_spins`, `number_bosons` and `number_fermions` datasets,
+    /// where unset numbers are stored as -1.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the MixedSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The MixedSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_spins", &self.number_spins)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_bosons", &self.number_bosons)?;
+        crate::hdf5_io::write_optional_numbers(group, "number_fermions", &self.number_fermions)
+    }
+
+    /// Reads a MixedSystem from an HDF5 group written with [MixedSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the MixedSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the numbers of spins and modes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            MixedOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_numbers(group, "number_spins")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_bosons")?,
+            crate::hdf5_io::read_optional_numbers(group, "number_fermions")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of MixedSystem.
@@ -612,7 +664,7 @@ where
     /// * `Self` - The MixedSystem multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(mut self, other: T) -> Self {
         let other_cc = Into::<CalculatorComplex>::into(other);
-        self.operator = self.operator * other_cc;
+        self.operator *= other_cc;
         self
     }
 }
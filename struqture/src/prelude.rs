@@ -10,11 +10,13 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use crate::bosons::ToSparseMatrixBosonOperator;
 pub use crate::mixed_systems::MixedIndex;
 pub use crate::mixed_systems::OperateOnMixedSystems;
 pub use crate::spins::OperateOnSpins;
 pub use crate::spins::ToSparseMatrixOperator;
 pub use crate::spins::ToSparseMatrixSuperOperator;
+pub use crate::AbsoluteValueTrait;
 pub use crate::ConjugationTrait;
 pub use crate::GetValue;
 pub use crate::ModeIndex;
@@ -22,6 +24,8 @@ pub use crate::OpenSystem;
 pub use crate::OperateOnDensityMatrix;
 pub use crate::OperateOnModes;
 pub use crate::OperateOnState;
+pub use crate::RationalApproximationTrait;
 pub use crate::SpinIndex;
+pub use crate::SubstituteParametersTrait;
 pub use crate::SymmetricIndex;
 pub use crate::TruncateTrait;
@@ -10,7 +10,10 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, SpinOperator};
+use super::{
+    apply_pauli_operators, check_statevector_lengths, prepared_terms, OperateOnSpins, PauliProduct,
+    SpinOperator,
+};
 use crate::fermions::FermionOperator;
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::spins::DecoherenceProduct;
@@ -18,6 +21,7 @@ use crate::{
     OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError,
     StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
 };
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Write};
@@ -288,6 +292,22 @@ impl DecoherenceOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the DecoherenceOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the DecoherenceOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -312,6 +332,99 @@ impl DecoherenceOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Applies the DecoherenceOperator to a dense state vector without constructing its matrix representation.
+    ///
+    /// The number of spins is determined from the length of `input`, `output` is overwritten with the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The amplitudes of the state in the computational basis (qubit `i` corresponds to the bit `2^i`).
+    /// * `output` - The vector the amplitudes of the resulting state are written to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The DecoherenceOperator was applied to the state vector.
+    /// * `Err(StruqtureError::GenericError)` - The length of `input` is not a power of two or does not match the length of `output`.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The DecoherenceOperator acts on more spins than the state vector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn apply_to_statevector(
+        &self,
+        input: &[Complex64],
+        output: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        let number_spins = check_statevector_lengths(input, output)?;
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        // iY = i * Y, the factors of i are moved into the coefficients of the PauliProducts
+        let spin_terms: Vec<(PauliProduct, CalculatorComplex)> = self
+            .iter()
+            .map(|(product, value)| {
+                let (pauli_product, prefactor) =
+                    DecoherenceProduct::decoherence_to_spin(product.clone());
+                (
+                    pauli_product,
+                    value.clone() * CalculatorComplex::from(prefactor),
+                )
+            })
+            .collect();
+        let terms = prepared_terms(spin_terms.iter().map(|(product, value)| (product, value)))?;
+        output.fill(Complex64::new(0.0, 0.0));
+        for (column, amplitude) in input.iter().enumerate() {
+            if *amplitude == Complex64::new(0.0, 0.0) {
+                continue;
+            }
+            for (operators, value) in terms.iter() {
+                let (row, prefac) = apply_pauli_operators(operators, column);
+                output[row] += value * prefac * amplitude;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the DecoherenceOperator into an HDF5 group.
+    ///
+    /// The DecoherenceProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the DecoherenceOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The DecoherenceOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a DecoherenceOperator from an HDF5 group written with [DecoherenceOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the DecoherenceOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The DecoherenceOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a DecoherenceProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(DecoherenceProduct, CalculatorComplex)> =
+            crate::hdf5_io::read_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 /// Implements the negative sign function of DecoherenceOperator.
@@ -583,7 +696,7 @@ impl JordanWignerSpinToFermion for DecoherenceOperator {
     fn jordan_wigner(&self) -> Self::Output {
         let mut out = FermionOperator::new();
         for (dp, value) in self.iter() {
-            out = out + dp.jordan_wigner() * value;
+            out += dp.jordan_wigner() * value;
         }
         out
     }
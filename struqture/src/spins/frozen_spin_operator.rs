@@ -0,0 +1,367 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    apply_pauli_operators, collect_sparse_rows, prepared_terms, sparse_number_spins, PreparedTerm,
+    SingleSpinOperator,
+};
+use crate::spins::{PauliProduct, SpinIndex, SpinOperator};
+use crate::{CooSparseMatrix, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+
+/// Type of a term of the FrozenSpinOperator as it is stored in the sorted Vec.
+type FrozenTerm = (PauliProduct, CalculatorComplex);
+
+/// Iterator over the (key, value) pairs of a FrozenSpinOperator.
+pub type FrozenSpinOperatorIter<'a> = std::iter::Map<
+    std::slice::Iter<'a, FrozenTerm>,
+    fn(&'a FrozenTerm) -> (&'a PauliProduct, &'a CalculatorComplex),
+>;
+/// Iterator over the keys of a FrozenSpinOperator.
+pub type FrozenSpinOperatorKeys<'a> =
+    std::iter::Map<std::slice::Iter<'a, FrozenTerm>, fn(&'a FrozenTerm) -> &'a PauliProduct>;
+/// Iterator over the values of a FrozenSpinOperator.
+pub type FrozenSpinOperatorValues<'a> =
+    std::iter::Map<std::slice::Iter<'a, FrozenTerm>, fn(&'a FrozenTerm) -> &'a CalculatorComplex>;
+
+/// Immutable, lookup-optimized version of a SpinOperator.
+///
+/// The terms are stored in a Vec sorted by PauliProduct and looked up with a binary search.
+/// The FrozenSpinOperator is read-only, it provides `get`, the iterators, `len` and the sparse matrix
+/// functions of a SpinOperator. Use [FrozenSpinOperator::thaw] to obtain a SpinOperator when the terms
+/// need to be modified or combined with other operators.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let mut so = SpinOperator::new();
+/// let pp_0z = PauliProduct::new().z(0);
+/// so.add_operator_product(pp_0z.clone(), CalculatorComplex::from(0.2)).unwrap();
+///
+/// let frozen = so.freeze();
+/// assert_eq!(frozen.get(&pp_0z), &CalculatorComplex::from(0.2));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(from = "SpinOperator")]
+#[serde(into = "SpinOperator")]
+pub struct FrozenSpinOperator {
+    // The internal sorted Vec of PauliProducts and coefficients (CalculatorComplex)
+    internal_vec: Vec<FrozenTerm>,
+}
+
+impl crate::MinSupportedVersion for FrozenSpinOperator {}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for FrozenSpinOperator {
+    fn schema_name() -> String {
+        "FrozenSpinOperator".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <SpinOperator>::json_schema(gen)
+    }
+}
+
+/// Functions for the FrozenSpinOperator
+///
+impl FrozenSpinOperator {
+    /// Gets the CalculatorComplex coefficient corresponding to the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PauliProduct for which to get the corresponding coefficient.
+    ///
+    /// # Returns
+    ///
+    /// * `&CalculatorComplex` - The coefficient of the key, zero if the key is not contained.
+    pub fn get(&self, key: &PauliProduct) -> &CalculatorComplex {
+        match self.position(key) {
+            Ok(position) => &self.internal_vec[position].1,
+            Err(_) => &CalculatorComplex::ZERO,
+        }
+    }
+
+    /// Returns the iterator over the (key, value) pairs of the FrozenSpinOperator, sorted by PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `FrozenSpinOperatorIter` - The iterator over the terms.
+    pub fn iter<'a>(&'a self) -> FrozenSpinOperatorIter<'a> {
+        self.internal_vec.iter().map(
+            (|(key, value)| (key, value))
+                as fn(&'a FrozenTerm) -> (&'a PauliProduct, &'a CalculatorComplex),
+        )
+    }
+
+    /// Returns the iterator over the keys of the FrozenSpinOperator, sorted by PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `FrozenSpinOperatorKeys` - The iterator over the keys.
+    pub fn keys<'a>(&'a self) -> FrozenSpinOperatorKeys<'a> {
+        self.internal_vec
+            .iter()
+            .map((|(key, _)| key) as fn(&'a FrozenTerm) -> &'a PauliProduct)
+    }
+
+    /// Returns the iterator over the values of the FrozenSpinOperator, sorted by PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `FrozenSpinOperatorValues` - The iterator over the values.
+    pub fn values<'a>(&'a self) -> FrozenSpinOperatorValues<'a> {
+        self.internal_vec
+            .iter()
+            .map((|(_, value)| value) as fn(&'a FrozenTerm) -> &'a CalculatorComplex)
+    }
+
+    /// Returns the number of terms in the FrozenSpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms.
+    pub fn len(&self) -> usize {
+        self.internal_vec.len()
+    }
+
+    /// Returns true if the FrozenSpinOperator contains no terms.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the FrozenSpinOperator is empty.
+    pub fn is_empty(&self) -> bool {
+        self.internal_vec.is_empty()
+    }
+
+    /// Returns the maximum index of the spins the FrozenSpinOperator acts on plus one.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins in the FrozenSpinOperator.
+    pub fn current_number_spins(&self) -> usize {
+        self.internal_vec
+            .iter()
+            .map(|(key, _)| key.current_number_spins())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Gets the maximum index of the FrozenSpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins in the FrozenSpinOperator.
+    pub fn number_spins(&self) -> usize {
+        self.current_number_spins()
+    }
+
+    /// Constructs the sparse matrix representation of the FrozenSpinOperator as a HashMap with a given number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<(usize, usize), Complex64>)` - The matrix representation of the FrozenSpinOperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the FrozenSpinOperator is empty.
+    pub fn sparse_matrix(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
+        let (values, (rows, columns)) = self.sparse_matrix_coo(number_spins)?;
+        Ok(rows.into_iter().zip(columns).zip(values).collect())
+    }
+
+    /// Constructs the sparse matrix representation of the FrozenSpinOperator as a scipy COO matrix with a given number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the FrozenSpinOperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the FrozenSpinOperator is empty.
+    pub fn sparse_matrix_coo(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        // Applying a PauliProduct to the row gives the entries of the transposed product,
+        // which differs from the product by a factor of -1 for every Y
+        let terms: Vec<PreparedTerm> = prepared_terms(self.iter())?
+            .into_iter()
+            .map(|(operators, value)| {
+                let number_y = operators
+                    .iter()
+                    .filter(|(_, operator)| *operator == SingleSpinOperator::Y)
+                    .count();
+                let sign = if number_y % 2 == 0 { 1.0 } else { -1.0 };
+                (operators, value * sign)
+            })
+            .collect();
+        collect_sparse_rows(dimension, |row| {
+            let mut entries: HashMap<usize, Complex64> = HashMap::with_capacity(terms.len());
+            for (operators, value) in terms.iter() {
+                let (column, prefac) = apply_pauli_operators(operators, row);
+                *entries
+                    .entry(column)
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += value * prefac;
+            }
+            Ok(entries)
+        })
+    }
+
+    /// Searches for the position of a key in the sorted Vec of terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PauliProduct to search for.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The position of the key.
+    /// * `Err(usize)` - The key is not contained, this is the position at which it would be inserted.
+    fn position(&self, key: &PauliProduct) -> Result<usize, usize> {
+        self.internal_vec.binary_search_by(|(k, _)| k.cmp(key))
+    }
+
+    /// Converts the FrozenSpinOperator back into a mutable SpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The SpinOperator with the same terms.
+    pub fn thaw(self) -> SpinOperator {
+        SpinOperator::from_iter(self.internal_vec)
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of FrozenSpinOperator.
+///
+impl IntoIterator for FrozenSpinOperator {
+    type Item = (PauliProduct, CalculatorComplex);
+    type IntoIter = std::vec::IntoIter<(PauliProduct, CalculatorComplex)>;
+    /// Returns the FrozenSpinOperator in Iterator form, sorted by PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The FrozenSpinOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_vec.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference FrozenSpinOperator.
+///
+impl<'a> IntoIterator for &'a FrozenSpinOperator {
+    type Item = (&'a PauliProduct, &'a CalculatorComplex);
+    type IntoIter = FrozenSpinOperatorIter<'a>;
+
+    /// Returns the reference FrozenSpinOperator in Iterator form, sorted by PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference FrozenSpinOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of FrozenSpinOperator.
+///
+impl FromIterator<(PauliProduct, CalculatorComplex)> for FrozenSpinOperator {
+    /// Returns the object in FrozenSpinOperator form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the FrozenSpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in FrozenSpinOperator form.
+    fn from_iter<I: IntoIterator<Item = (PauliProduct, CalculatorComplex)>>(iter: I) -> Self {
+        FrozenSpinOperator::from(SpinOperator::from_iter(iter))
+    }
+}
+
+/// Implements the format function (Display trait) of FrozenSpinOperator.
+///
+impl fmt::Display for FrozenSpinOperator {
+    /// Formats the FrozenSpinOperator using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted FrozenSpinOperator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "FrozenSpinOperator{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
+
+impl From<SpinOperator> for FrozenSpinOperator {
+    /// Freezes a SpinOperator into a FrozenSpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The SpinOperator to freeze.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The FrozenSpinOperator with sorted terms.
+    fn from(operator: SpinOperator) -> Self {
+        let mut internal_vec: Vec<(PauliProduct, CalculatorComplex)> =
+            operator.into_iter().collect();
+        internal_vec.sort_by(|(a, _), (b, _)| a.cmp(b));
+        internal_vec.shrink_to_fit();
+        Self { internal_vec }
+    }
+}
+
+impl From<FrozenSpinOperator> for SpinOperator {
+    /// Converts a FrozenSpinOperator back into a SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `frozen` - The FrozenSpinOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinOperator with the same terms.
+    fn from(frozen: FrozenSpinOperator) -> Self {
+        frozen.thaw()
+    }
+}
@@ -13,8 +13,11 @@
 //! Module for representing spin physical systems
 
 use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use itertools::Itertools;
 use num_complex::{Complex, Complex64};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::iter::IntoIterator;
@@ -32,6 +35,12 @@ pub use decoherence_operator::*;
 mod spin_operator;
 pub use spin_operator::*;
 
+mod frozen_spin_operator;
+pub use frozen_spin_operator::*;
+
+mod trotter_schedule;
+pub use trotter_schedule::*;
+
 mod spin_hamiltonian;
 pub use spin_hamiltonian::*;
 
@@ -59,7 +68,730 @@ pub use plus_minus_operator::*;
 mod plus_minus_noise_operator;
 pub use plus_minus_noise_operator::*;
 
-use crate::CooSparseMatrix;
+use crate::{CooSparseMatrix, CsrSparseMatrix, SparseMatrixEntries};
+
+/// Ordering convention of the qubits in the matrix representation of spin objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatrixEndianness {
+    /// Qubit `i` corresponds to the bit with value `2^i` of the row and column indices (default of struqture).
+    LittleEndian,
+    /// Qubit `i` corresponds to the bit with value `2^(N-1-i)` of the row and column indices, `N` being the number of spins.
+    BigEndian,
+}
+
+/// Commutation relation required between all PauliProducts in a group of commuting terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommutationKind {
+    /// The PauliProducts act with the same Pauli operator (or the identity) on every qubit, see [PauliProduct::qubit_wise_commutes_with].
+    /// All terms of a group can be measured simultaneously with single-qubit basis rotations.
+    QubitWise,
+    /// The PauliProducts commute as operators, see [PauliProduct::commutes_with].
+    General,
+}
+
+/// Strategy for partitioning PauliProducts into groups of commuting terms.
+///
+/// Both strategies are greedy colorings of the graph connecting all pairs of PauliProducts that do not commute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupingStrategy {
+    /// The terms are visited in the order of their PauliProducts and each term is added to the first group
+    /// in which it commutes with all terms.
+    Greedy(CommutationKind),
+    /// The terms are visited in the order of decreasing number of non-commuting terms (Welsh-Powell coloring)
+    /// and each term is added to the first group in which it commutes with all terms.
+    LargestDegreeFirst(CommutationKind),
+}
+
+/// Returns whether a DecoherenceProduct can be used as a Lindblad operator in a noise operator.
+///
+/// Lindblad operators need to be traceless. The only DecoherenceProduct with a non-zero trace is the identity,
+/// all other DecoherenceProducts are valid Lindblad operators.
+///
+/// # Arguments
+///
+/// * `product` - The DecoherenceProduct to check.
+///
+/// # Returns
+///
+/// * `bool` - Whether the DecoherenceProduct is a valid Lindblad operator.
+pub fn is_valid_lindblad_operator(product: &DecoherenceProduct) -> bool {
+    !product.is_empty()
+}
+
+/// Returns all PauliProducts on `number_qubits` qubits acting non-trivially on at most `max_weight` qubits.
+///
+/// The products are ordered by their weight (starting with the identity), then by the qubits they act on
+/// and finally by the Pauli operators (X, Y, Z) acting on these qubits.
+/// The number of returned products is `sum_{w=0}^{max_weight} binomial(number_qubits, w) * 3^w`,
+/// which grows combinatorially, e.g. 4^number_qubits for `max_weight >= number_qubits`.
+///
+/// # Arguments
+///
+/// * `number_qubits` - The number of qubits the PauliProducts can act on.
+/// * `max_weight` - The maximal number of non-identity Pauli operators in each product.
+///
+/// # Returns
+///
+/// * `Vec<PauliProduct>` - All PauliProducts with a weight of at most `max_weight`.
+pub fn pauli_basis(number_qubits: usize, max_weight: usize) -> Vec<PauliProduct> {
+    let paulis = [
+        SingleSpinOperator::X,
+        SingleSpinOperator::Y,
+        SingleSpinOperator::Z,
+    ];
+    let mut basis: Vec<PauliProduct> = Vec::new();
+    for weight in 0..=max_weight.min(number_qubits) {
+        for qubits in (0..number_qubits).combinations(weight) {
+            for assignment in 0..3usize.pow(weight as u32) {
+                let mut product = PauliProduct::with_capacity(weight);
+                for (position, qubit) in qubits.iter().enumerate() {
+                    let pauli =
+                        paulis[(assignment / 3usize.pow((weight - 1 - position) as u32)) % 3];
+                    product = product.set_pauli(*qubit, pauli);
+                }
+                basis.push(product);
+            }
+        }
+    }
+    basis
+}
+
+/// Constructs the superoperator of the unitary channel `rho -> U rho U^dagger` for a Pauli rotation in COO format.
+///
+/// The rotation is `U = exp(-i * angle * P) = cos(angle) * I - i * sin(angle) * P` for the PauliProduct P.
+/// As for [ToSparseMatrixSuperOperator], the density matrix is flattened in row-major form,
+/// so that the superoperator is given by `U ⊗ U*`.
+///
+/// # Arguments
+///
+/// * `product` - The PauliProduct P generating the rotation.
+/// * `angle` - The rotation angle.
+/// * `number_spins` - The number of spins for which to construct the superoperator.
+///
+/// # Returns
+///
+/// * `Ok(CooSparseMatrix)` - The superoperator of the rotation channel.
+/// * `Err(StruqtureError::NumberSpinsExceeded)` - The PauliProduct acts on more than `number_spins` spins.
+/// * `Err(StruqtureError)` - Error constructing the matrix of the rotation.
+pub fn rotation_channel_superoperator_coo(
+    product: &PauliProduct,
+    angle: f64,
+    number_spins: usize,
+) -> Result<CooSparseMatrix, StruqtureError> {
+    if product.current_number_spins() > number_spins {
+        return Err(StruqtureError::NumberSpinsExceeded);
+    }
+    let mut rotation = SpinOperator::new();
+    rotation.add_operator_product(PauliProduct::new(), CalculatorComplex::from(angle.cos()))?;
+    rotation.add_operator_product(product.clone(), CalculatorComplex::new(0.0, -angle.sin()))?;
+    let (values, (rows, columns)) = rotation.sparse_matrix_coo(Some(number_spins))?;
+
+    let dimension = 2usize.pow(number_spins as u32);
+    let capacity = values.len() * values.len();
+    let mut superoperator_values: Vec<Complex64> = Vec::with_capacity(capacity);
+    let mut superoperator_rows: Vec<usize> = Vec::with_capacity(capacity);
+    let mut superoperator_columns: Vec<usize> = Vec::with_capacity(capacity);
+    for ((left_value, left_row), left_column) in values.iter().zip(rows.iter()).zip(columns.iter())
+    {
+        for ((right_value, right_row), right_column) in
+            values.iter().zip(rows.iter()).zip(columns.iter())
+        {
+            superoperator_values.push(left_value * right_value.conj());
+            superoperator_rows.push(left_row * dimension + right_row);
+            superoperator_columns.push(left_column * dimension + right_column);
+        }
+    }
+    Ok((
+        superoperator_values,
+        (superoperator_rows, superoperator_columns),
+    ))
+}
+
+/// Applies a product of Pauli operators to a computational basis state.
+///
+/// # Arguments
+///
+/// * `operators` - The Pauli operators and the spins they act on.
+/// * `column` - The index of the basis state (qubit `i` corresponds to the bit `2^i`).
+///
+/// # Returns
+///
+/// * `(usize, Complex64)` - The index of the resulting basis state and the prefactor.
+pub(crate) fn apply_pauli_operators(
+    operators: &[(usize, SingleSpinOperator)],
+    column: usize,
+) -> (usize, Complex64) {
+    let mut row = column;
+    let mut prefac = Complex64::new(1.0, 0.0);
+    for (spin, operator) in operators.iter() {
+        let bit = 2usize.pow(*spin as u32);
+        match operator {
+            SingleSpinOperator::X => row ^= bit,
+            SingleSpinOperator::Y => {
+                row ^= bit;
+                if row & bit == 0 {
+                    prefac *= Complex64::new(0.0, -1.0);
+                } else {
+                    prefac *= Complex64::new(0.0, 1.0);
+                }
+            }
+            SingleSpinOperator::Z => {
+                if row & bit != 0 {
+                    prefac *= -1.0;
+                }
+            }
+            SingleSpinOperator::Identity => (),
+        }
+    }
+    (row, prefac)
+}
+
+/// Shorthand type notation for the Pauli operators of a product together with its complex coefficient
+pub(crate) type PreparedTerm = (Vec<(usize, SingleSpinOperator)>, Complex64);
+
+/// Converts the terms of an operator into the Pauli operators of their products and complex coefficients.
+///
+/// # Arguments
+///
+/// * `terms` - The products and coefficients of the operator.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(Vec<(usize, SingleSpinOperator)>, Complex64)>)` - The Pauli operators and the coefficient of every term.
+/// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+pub(crate) fn prepared_terms<'b, I, V, T>(terms: T) -> Result<Vec<PreparedTerm>, StruqtureError>
+where
+    T: Iterator<Item = (&'b I, &'b V)>,
+    I: SpinIndex + 'b,
+    I::SingleSpinType: Copy,
+    V: Clone + 'b,
+    SingleSpinOperator: From<I::SingleSpinType>,
+    CalculatorComplex: From<V>,
+{
+    let mut prepared: Vec<PreparedTerm> = Vec::with_capacity(terms.size_hint().0);
+    for (index, value) in terms {
+        let ri_value = CalculatorComplex::from(value.clone());
+        let real_value: f64 = ri_value.re.try_into()?;
+        let imag_value: f64 = ri_value.im.try_into()?;
+        let operators: Vec<(usize, SingleSpinOperator)> = index
+            .iter()
+            .map(|(spin, pauliop)| (*spin, SingleSpinOperator::from(*pauliop)))
+            .collect();
+        prepared.push((operators, Complex64::new(real_value, imag_value)));
+    }
+    Ok(prepared)
+}
+
+/// Constructs the entries for one row of a superoperator acting on a density matrix from the left and the right.
+///
+/// The superoperator is `left_prefactor * O.kron(I) + right_prefactor * I.kron(O.T)` for the operator O,
+/// so that `flatten(left_prefactor * O p + right_prefactor * p O) = S flatten(p)`, where `flatten` flattens
+/// a matrix into a vector in row-major form. The commutator superoperator `-i [O, p]` corresponds to the
+/// prefactors `-i` and `i`, the anticommutator superoperator `{O, p}` to the prefactors `1` and `1`.
+///
+/// # Arguments
+///
+/// * `terms` - The products and coefficients of the operator O.
+/// * `row` - The row for which to get the superoperator entries.
+/// * `number_spins` - The number of spins for which to construct the sparse matrix entries.
+/// * `left_prefactor` - The prefactor of the operator acting from the left.
+/// * `right_prefactor` - The prefactor of the operator acting from the right.
+///
+/// # Returns
+///
+/// * `Ok(HashMap<usize, Complex64>)` - The entries of the given row of the superoperator.
+/// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+fn superoperator_entries_on_row<'b, I, V, T>(
+    terms: T,
+    row: usize,
+    number_spins: usize,
+    left_prefactor: Complex64,
+    right_prefactor: Complex64,
+) -> Result<HashMap<usize, Complex64>, StruqtureError>
+where
+    T: Iterator<Item = (&'b I, &'b V)>,
+    I: SpinIndex + 'b,
+    I::SingleSpinType: Copy,
+    V: Clone + 'b,
+    SingleSpinOperator: From<I::SingleSpinType>,
+    CalculatorComplex: From<V>,
+{
+    let mut entries: HashMap<usize, Complex64> = HashMap::new();
+    let dimension = 2_usize.pow(number_spins as u32);
+    for (index, value) in terms {
+        let ri_value = CalculatorComplex::from(value.clone());
+        let real_value: f64 = ri_value.re.try_into()?;
+        let imag_value: f64 = ri_value.im.try_into()?;
+        let complex_value = Complex64::new(real_value, imag_value);
+        if complex_value == Complex64::new(0.0, 0.0) {
+            continue;
+        }
+        // iterate over the terms corresponding to O p => O.kron(I) flatten(p) and p O => I.kron(O.T) flatten(p)
+        for (row_adjusted, side_prefactor, transpose_sign, shift) in [
+            (row.div_euclid(dimension), left_prefactor, 1.0, number_spins),
+            (row % dimension, right_prefactor, -1.0, 0),
+        ] {
+            let mut column = row;
+            let mut prefac: Complex64 = side_prefactor;
+            for (spin_op_index, pauliop) in index.iter() {
+                match SingleSpinOperator::from(*pauliop) {
+                    SingleSpinOperator::X => {
+                        match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
+                            0 => column += 2usize.pow((*spin_op_index + shift) as u32),
+                            1 => column -= 2usize.pow((*spin_op_index + shift) as u32),
+                            _ => panic!("Internal error in constructing matrix"),
+                        }
+                    }
+                    SingleSpinOperator::Y => {
+                        match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
+                            0 => {
+                                column += 2usize.pow((*spin_op_index + shift) as u32);
+                                // due to the transpose in p O => I.kron(O.T) only the Y Pauli operator picks up an extra sign
+                                prefac *= Complex64::new(0.0, -1.0) * transpose_sign;
+                            }
+                            1 => {
+                                column -= 2usize.pow((*spin_op_index + shift) as u32);
+                                prefac *= Complex64::new(0.0, 1.0) * transpose_sign;
+                            }
+                            _ => panic!("Internal error in constructing matrix"),
+                        };
+                    }
+                    SingleSpinOperator::Z => {
+                        match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
+                            0 => (),
+                            1 => {
+                                prefac *= Complex64::new(-1.0, 0.0);
+                            }
+                            _ => panic!("Internal error in constructing matrix"),
+                        };
+                    }
+                    SingleSpinOperator::Identity => (),
+                }
+            }
+            match entries.get_mut(&column) {
+                Some(x) => {
+                    if *x + prefac * complex_value == Complex64::new(0.0, 0.0) {
+                        entries.remove(&column);
+                    } else {
+                        *x += prefac * complex_value;
+                    }
+                }
+                None => {
+                    entries.insert(column, prefac * complex_value);
+                }
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Checks that a state vector and an output vector have the same length, which is a power of two.
+///
+/// # Arguments
+///
+/// * `input` - The input state vector.
+/// * `output` - The output vector.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of spins of the state vector.
+/// * `Err(StruqtureError::GenericError)` - The length of `input` is not a power of two or does not match the length of `output`.
+pub(crate) fn check_statevector_lengths(
+    input: &[Complex64],
+    output: &[Complex64],
+) -> Result<usize, StruqtureError> {
+    if !input.len().is_power_of_two() {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Length of state vector {} is not a power of two.",
+                input.len()
+            ),
+        });
+    }
+    if input.len() != output.len() {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Length of output vector {} does not match the length of the state vector {}.",
+                output.len(),
+                input.len()
+            ),
+        });
+    }
+    Ok(input.len().trailing_zeros() as usize)
+}
+
+/// Maximal number of spins for which `truth_table` constructs a table, the table has `2^number_spins` rows.
+const TRUTH_TABLE_MAX_SPINS: usize = 20;
+
+/// Number of rows constructed in parallel before they are merged into the sparse matrix, per rayon thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_ROWS_PER_THREAD: usize = 64;
+
+/// Assembles the rows of a sparse matrix in COO form.
+pub(crate) struct CooAssembler {
+    values: Vec<Complex64>,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+}
+
+impl CooAssembler {
+    /// Creates a new CooAssembler with capacity for `capacity` entries.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            rows: Vec::with_capacity(capacity),
+            columns: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends the entries of a row, given as a map from column to value.
+    pub(crate) fn push_row(&mut self, row: usize, entries: HashMap<usize, Complex64>) {
+        for (col, val) in entries.into_iter() {
+            self.rows.push(row);
+            self.columns.push(col);
+            self.values.push(val);
+        }
+    }
+
+    /// Returns the assembled sparse matrix.
+    pub(crate) fn finish(self) -> CooSparseMatrix {
+        (self.values, (self.rows, self.columns))
+    }
+}
+
+/// Assembles the rows of a sparse matrix in CSR form, the column indices are sorted within every row.
+pub(crate) struct CsrAssembler {
+    values: Vec<Complex64>,
+    indices: Vec<usize>,
+    pointers: Vec<usize>,
+}
+
+impl CsrAssembler {
+    /// Creates a new CsrAssembler for `number_rows` rows.
+    pub(crate) fn new(number_rows: usize) -> Self {
+        let mut pointers: Vec<usize> = Vec::with_capacity(number_rows + 1);
+        pointers.push(0);
+        Self {
+            values: Vec::with_capacity(number_rows),
+            indices: Vec::with_capacity(number_rows),
+            pointers,
+        }
+    }
+
+    /// Appends the entries of the next row, given as a map from column to value.
+    pub(crate) fn push_row(&mut self, entries: HashMap<usize, Complex64>) {
+        let mut entries: Vec<(usize, Complex64)> = entries.into_iter().collect();
+        entries.sort_by_key(|(index, _)| *index);
+        for (index, val) in entries.into_iter() {
+            self.indices.push(index);
+            self.values.push(val);
+        }
+        self.pointers.push(self.values.len());
+    }
+
+    /// Returns the assembled sparse matrix.
+    pub(crate) fn finish(self) -> CsrSparseMatrix {
+        (self.values, self.indices, self.pointers)
+    }
+}
+
+/// Collects the entries of the rows `0..number_rows` of a sparse matrix into COO form.
+///
+/// # Arguments
+///
+/// * `number_rows` - The number of rows of the sparse matrix.
+/// * `entries_on_row` - Returns the non-zero entries of a row as a map from column to value.
+///
+/// # Returns
+///
+/// * `Ok(CooSparseMatrix)` - The sparse matrix in COO form.
+/// * `Err(StruqtureError)` - The entries of a row could not be constructed.
+pub(crate) fn collect_sparse_rows<F>(
+    number_rows: usize,
+    entries_on_row: F,
+) -> Result<CooSparseMatrix, StruqtureError>
+where
+    F: Fn(usize) -> Result<HashMap<usize, Complex64>, StruqtureError>,
+{
+    let mut assembler = CooAssembler::with_capacity(number_rows);
+    for row in 0..number_rows {
+        assembler.push_row(row, entries_on_row(row)?);
+    }
+    Ok(assembler.finish())
+}
+
+/// Collects the entries of the rows `0..number_rows` of a sparse matrix into CSR form.
+///
+/// The column indices are sorted within every row.
+///
+/// # Arguments
+///
+/// * `number_rows` - The number of rows of the sparse matrix.
+/// * `entries_on_row` - Returns the non-zero entries of a row as a map from column to value.
+///
+/// # Returns
+///
+/// * `Ok(CsrSparseMatrix)` - The sparse matrix in CSR form.
+/// * `Err(StruqtureError)` - The entries of a row could not be constructed.
+pub(crate) fn collect_sparse_rows_csr<F>(
+    number_rows: usize,
+    entries_on_row: F,
+) -> Result<CsrSparseMatrix, StruqtureError>
+where
+    F: Fn(usize) -> Result<HashMap<usize, Complex64>, StruqtureError>,
+{
+    let mut assembler = CsrAssembler::new(number_rows);
+    for row in 0..number_rows {
+        assembler.push_row(entries_on_row(row)?);
+    }
+    Ok(assembler.finish())
+}
+
+/// Returns the number of spins for which a sparse matrix is constructed.
+///
+/// When no number of spins is given, it is inferred from the object. An empty object acting on no spins
+/// does not determine the size of its sparse matrix, so the number of spins has to be given.
+/// A non-empty object acting on no spins, e.g. an identity-only operator, is represented for zero spins.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins given by the user, if any.
+/// * `inferred_number_spins` - The number of spins of the object.
+/// * `is_empty` - Whether the object contains no terms.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of spins for which to construct the sparse matrix.
+/// * `Err(StruqtureError::GenericError)` - No number of spins was given and the object is empty and acts on no spins.
+pub(crate) fn sparse_number_spins(
+    number_spins: Option<usize>,
+    inferred_number_spins: usize,
+    is_empty: bool,
+) -> Result<usize, StruqtureError> {
+    match number_spins {
+        Some(num_spins) => Ok(num_spins),
+        None if is_empty && inferred_number_spins == 0 => Err(StruqtureError::GenericError {
+            msg: "Cannot infer the size of the sparse matrix of an empty object, please provide the number of spins.".to_string(),
+        }),
+        None => Ok(inferred_number_spins),
+    }
+}
+
+/// Returns the length of the flattened density matrix of a number of spins.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins of the density matrix.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The length `4^number_spins` of the flattened density matrix.
+/// * `Err(StruqtureError::GenericError)` - The length of the flattened density matrix overflows usize.
+fn flattened_density_matrix_length(number_spins: usize) -> Result<usize, StruqtureError> {
+    u32::try_from(number_spins)
+        .ok()
+        .and_then(|number_spins| 4usize.checked_pow(number_spins))
+        .ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "The flattened density matrix of {} spins cannot be represented, at most {} spins are supported.",
+                number_spins,
+                usize::BITS / 2 - 1
+            ),
+        })
+}
+
+/// Returns the dimension of the Hilbert space of a number of spins.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension `2^number_spins`.
+/// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+fn spin_basis_dimension(number_spins: usize) -> Result<usize, StruqtureError> {
+    u32::try_from(number_spins)
+        .ok()
+        .and_then(|number_spins| 2usize.checked_pow(number_spins))
+        .ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "The basis state indices of {} spins cannot be represented, at most {} spins are supported.",
+                number_spins,
+                usize::BITS - 1
+            ),
+        })
+}
+
+/// Constructs the entries of the rows `0..number_rows` of a sparse matrix in parallel and passes them to `consume_row` in row order.
+///
+/// The rows are constructed in chunks of a fixed number of rows per rayon thread, so that only the entries
+/// of one chunk are held in memory before they are consumed.
+///
+/// # Arguments
+///
+/// * `number_rows` - The number of rows of the sparse matrix.
+/// * `entries_on_row` - Returns the non-zero entries of a row as a map from column to value.
+/// * `consume_row` - Called with the index and the entries of every row.
+///
+/// # Returns
+///
+/// * `Ok(())` - All rows were constructed.
+/// * `Err(StruqtureError)` - The entries of a row could not be constructed.
+#[cfg(feature = "parallel")]
+pub(crate) fn for_each_sparse_row_parallel<F, G>(
+    number_rows: usize,
+    entries_on_row: F,
+    mut consume_row: G,
+) -> Result<(), StruqtureError>
+where
+    F: Fn(usize) -> Result<HashMap<usize, Complex64>, StruqtureError> + Sync + Send,
+    G: FnMut(usize, HashMap<usize, Complex64>),
+{
+    let chunk_size = rayon::current_num_threads() * PARALLEL_ROWS_PER_THREAD;
+    let mut chunk_start = 0;
+    while chunk_start < number_rows {
+        let chunk_end = number_rows.min(chunk_start + chunk_size);
+        let chunk_entries: Vec<HashMap<usize, Complex64>> = (chunk_start..chunk_end)
+            .into_par_iter()
+            .map(&entries_on_row)
+            .collect::<Result<Vec<HashMap<usize, Complex64>>, StruqtureError>>()?;
+        for (row, entries) in (chunk_start..chunk_end).zip(chunk_entries) {
+            consume_row(row, entries);
+        }
+        chunk_start = chunk_end;
+    }
+    Ok(())
+}
+
+/// Reverses the order of the lowest `number_bits` bits of `index`.
+fn reverse_bits(index: usize, number_bits: usize) -> usize {
+    let mut reversed: usize = 0;
+    for bit in 0..number_bits {
+        if index.div_euclid(2usize.pow(bit as u32)) % 2 == 1 {
+            reversed += 2usize.pow((number_bits - 1 - bit) as u32);
+        }
+    }
+    reversed
+}
+
+/// Partitions PauliProducts into groups of commuting products.
+///
+/// # Arguments
+///
+/// * `products` - The PauliProducts to partition.
+/// * `strategy` - The grouping strategy.
+///
+/// # Returns
+///
+/// * `Vec<Vec<usize>>` - The positions of the products in `products` for each group.
+pub(crate) fn group_commuting_products(
+    products: &[&PauliProduct],
+    strategy: GroupingStrategy,
+) -> Vec<Vec<usize>> {
+    let kind = match strategy {
+        GroupingStrategy::Greedy(kind) => kind,
+        GroupingStrategy::LargestDegreeFirst(kind) => kind,
+    };
+    let commute = |left: &PauliProduct, right: &PauliProduct| match kind {
+        CommutationKind::QubitWise => left.qubit_wise_commutes_with(right),
+        CommutationKind::General => left.commutes_with(right),
+    };
+
+    let mut order: Vec<usize> = (0..products.len()).collect();
+    order.sort_by(|a, b| products[*a].cmp(products[*b]));
+    if let GroupingStrategy::LargestDegreeFirst(_) = strategy {
+        let degrees: Vec<usize> = products
+            .iter()
+            .map(|product| {
+                products
+                    .iter()
+                    .filter(|other| !commute(product, other))
+                    .count()
+            })
+            .collect();
+        order.sort_by(|a, b| degrees[*b].cmp(&degrees[*a]));
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for position in order {
+        match groups.iter_mut().find(|group| {
+            group
+                .iter()
+                .all(|member| commute(products[*member], products[position]))
+        }) {
+            Some(group) => group.push(position),
+            None => groups.push(vec![position]),
+        }
+    }
+    groups
+}
+
+/// Returns the JSON schemas of all serializable spin types bundled in one JSON object.
+///
+/// The object maps the name of each type to the output of `schemars::schema_for!` for that type.
+///
+/// # Returns
+///
+/// * `serde_json::Value` - The JSON object containing the schemas of all spin types.
+///
+/// # Panics
+///
+/// * Internal error in serializing a JSON schema.
+#[cfg(all(feature = "json_schema", feature = "json"))]
+pub fn all_json_schemas() -> serde_json::Value {
+    let schemas = vec![
+        ("PauliProduct", schemars::schema_for!(PauliProduct)),
+        (
+            "DecoherenceProduct",
+            schemars::schema_for!(DecoherenceProduct),
+        ),
+        ("PlusMinusProduct", schemars::schema_for!(PlusMinusProduct)),
+        ("SpinOperator", schemars::schema_for!(SpinOperator)),
+        (
+            "FrozenSpinOperator",
+            schemars::schema_for!(FrozenSpinOperator),
+        ),
+        ("SpinHamiltonian", schemars::schema_for!(SpinHamiltonian)),
+        (
+            "DecoherenceOperator",
+            schemars::schema_for!(DecoherenceOperator),
+        ),
+        (
+            "PlusMinusOperator",
+            schemars::schema_for!(PlusMinusOperator),
+        ),
+        ("SpinSystem", schemars::schema_for!(SpinSystem)),
+        (
+            "SpinHamiltonianSystem",
+            schemars::schema_for!(SpinHamiltonianSystem),
+        ),
+        (
+            "SpinLindbladNoiseOperator",
+            schemars::schema_for!(SpinLindbladNoiseOperator),
+        ),
+        (
+            "PlusMinusLindbladNoiseOperator",
+            schemars::schema_for!(PlusMinusLindbladNoiseOperator),
+        ),
+        (
+            "SpinLindbladNoiseSystem",
+            schemars::schema_for!(SpinLindbladNoiseSystem),
+        ),
+        (
+            "SpinLindbladOpenSystem",
+            schemars::schema_for!(SpinLindbladOpenSystem),
+        ),
+    ];
+    let mut bundle = serde_json::Map::with_capacity(schemas.len());
+    for (name, schema) in schemas {
+        bundle.insert(
+            name.to_string(),
+            serde_json::to_value(schema).expect("Internal error in serializing JSON schema"),
+        );
+    }
+    serde_json::Value::Object(bundle)
+}
 
 /// Trait for non-Hermitian operations on spins.
 ///
@@ -129,21 +861,18 @@ where
     ///
     /// * `Ok(HashMap<(usize, usize), CalculatorComplex>)` - The matrix representation of the operator-like object.
     /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
     fn sparse_matrix(
         &'a self,
         number_spins: Option<usize>,
     ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
-        let dimension = match number_spins {
-            None => 2usize.pow(self.number_spins() as u32),
-            Some(num_spins) => 2usize.pow(num_spins as u32),
-        };
-        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
-        for row in 0..dimension {
-            for (column, val) in self.sparse_matrix_entries_on_row(row)?.into_iter() {
-                matrix.insert((row, column), val);
-            }
-        }
-        Ok(matrix)
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        let (values, (rows, columns)) =
+            collect_sparse_rows(dimension, |row| self.sparse_matrix_entries_on_row(row))?;
+        Ok(rows.into_iter().zip(columns).zip(values).collect())
     }
 
     /// Constructs the sparse matrix representation of the operator-like object as a scipy COO matrix with a given number of spins.
@@ -156,28 +885,412 @@ where
     ///
     /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the operator-like object.
     /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
     fn sparse_matrix_coo(
         &'a self,
         number_spins: Option<usize>,
     ) -> Result<CooSparseMatrix, StruqtureError> {
-        let dimension = match number_spins {
-            None => 2usize.pow(self.number_spins() as u32),
-            Some(num_spins) => 2usize.pow(num_spins as u32),
-        };
-
-        let capacity = dimension;
-        let mut values: Vec<Complex64> = Vec::with_capacity(capacity);
-        let mut rows: Vec<usize> = Vec::with_capacity(capacity);
-        let mut columns: Vec<usize> = Vec::with_capacity(capacity);
-
-        for row in 0..dimension {
-            for (col, val) in self.sparse_matrix_entries_on_row(row)?.into_iter() {
-                rows.push(row);
-                columns.push(col);
-                values.push(val);
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+
+        collect_sparse_rows(dimension, |row| self.sparse_matrix_entries_on_row(row))
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in CSR form with a given number of spins.
+    ///
+    /// The matrix is constructed row by row directly in CSR form, the column indices are sorted within every row.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    fn sparse_matrix_csr(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        collect_sparse_rows_csr(dimension, |row| self.sparse_matrix_entries_on_row(row))
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in COO form, constructing the rows in parallel.
+    ///
+    /// The entries are ordered row by row like the entries of [Self::sparse_matrix_coo].
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the operator-like object.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    #[cfg(feature = "parallel")]
+    fn sparse_matrix_coo_parallel(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError>
+    where
+        Self: Sync,
+    {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        let mut assembler = CooAssembler::with_capacity(dimension);
+        for_each_sparse_row_parallel(
+            dimension,
+            |row| self.sparse_matrix_entries_on_row(row),
+            |row, entries| assembler.push_row(row, entries),
+        )?;
+        Ok(assembler.finish())
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in CSR form, constructing the rows in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    #[cfg(feature = "parallel")]
+    fn sparse_matrix_csr_parallel(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError>
+    where
+        Self: Sync,
+    {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        let mut assembler = CsrAssembler::new(dimension);
+        for_each_sparse_row_parallel(
+            dimension,
+            |row| self.sparse_matrix_entries_on_row(row),
+            |_, entries| assembler.push_row(entries),
+        )?;
+        Ok(assembler.finish())
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in CSC form with a given number of spins.
+    ///
+    /// The matrix is constructed column by column from the action of the PauliProducts on the basis states,
+    /// the row indices are sorted within every column.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSC form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, row indices and column pointers of the matrix representation.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    fn sparse_matrix_csc(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        let terms = prepared_terms(self.iter())?;
+        collect_sparse_rows_csr(dimension, |column| {
+            let mut entries: HashMap<usize, Complex64> = HashMap::with_capacity(terms.len());
+            for (operators, value) in terms.iter() {
+                let (row, prefac) = apply_pauli_operators(operators, column);
+                *entries
+                    .entry(row)
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += value * prefac;
+            }
+            Ok(entries)
+        })
+    }
+
+    /// Returns a lazy iterator over the non-zero entries of the sparse matrix representation of Self.
+    ///
+    /// The entries are yielded as `(row, column, value)` triples row by row, so that only the entries
+    /// of a single row are held in memory at any time.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Box<dyn Iterator<Item = (usize, usize, Complex64)>>)` - The iterator over the entries of the matrix.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    fn sparse_matrix_entries(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<SparseMatrixEntries<'a>, StruqtureError> {
+        let dimension =
+            2usize.pow(
+                sparse_number_spins(number_spins, self.number_spins(), self.is_empty())? as u32,
+            );
+        // Check all values beforehand, so that constructing the rows lazily can not fail
+        for value in self.values() {
+            let ri_value = CalculatorComplex::from(value.clone());
+            let _: f64 = ri_value.re.try_into()?;
+            let _: f64 = ri_value.im.try_into()?;
+        }
+        Ok(Box::new((0..dimension).flat_map(move |row| {
+            self.sparse_matrix_entries_on_row(row)
+                .expect("Internal error: coefficients were checked to be convertible to f64")
+                .into_iter()
+                .map(move |(column, value)| (row, column, value))
+        })))
+    }
+
+    /// Applies Self to a sparse state vector given as a map from basis state index to amplitude.
+    ///
+    /// Only the non-zero amplitudes of the state are visited, for each of them the contribution of
+    /// every term of Self is computed directly, so that the cost is proportional to the number of
+    /// non-zero amplitudes times the number of terms. Amplitudes that cancel exactly are not included
+    /// in the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The sparse state as a map from basis state index to amplitude.
+    /// * `number_spins` - The number of spins of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, Complex64>)` - The sparse state after applying Self.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - A basis state index of the state exceeds the dimension.
+    /// * `Err(StruqtureError::GenericError)` - The dimension of `number_spins` spins does not fit into usize.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn apply_to_sparse_state(
+        &'a self,
+        state: &HashMap<usize, Complex64>,
+        number_spins: usize,
+    ) -> Result<HashMap<usize, Complex64>, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = spin_basis_dimension(number_spins)?;
+        if let Some(index) = state.keys().find(|index| **index >= dimension) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Basis state index {} exceeds the dimension {} of {} spins.",
+                    index, dimension, number_spins
+                ),
+            });
+        }
+        let terms = prepared_terms(self.iter())?;
+        let mut new_state: HashMap<usize, Complex64> = HashMap::with_capacity(state.len());
+        for (column, amplitude) in state.iter() {
+            for (operators, value) in terms.iter() {
+                let (row, prefac) = apply_pauli_operators(operators, *column);
+                *new_state
+                    .entry(row)
+                    .or_insert_with(|| Complex64::new(0.0, 0.0)) += value * prefac * amplitude;
+            }
+        }
+        new_state.retain(|_, amplitude| *amplitude != Complex64::new(0.0, 0.0));
+        Ok(new_state)
+    }
+
+    /// Applies Self to a dense state vector without constructing the matrix representation of Self.
+    ///
+    /// The number of spins is determined from the length of `input`, `output` is overwritten with the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The amplitudes of the state in the computational basis (qubit `i` corresponds to the bit `2^i`).
+    /// * `output` - The vector the amplitudes of the resulting state are written to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Self was applied to the state vector.
+    /// * `Err(StruqtureError::GenericError)` - The length of `input` is not a power of two or does not match the length of `output`.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than the state vector.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn apply_to_statevector(
+        &'a self,
+        input: &[Complex64],
+        output: &mut [Complex64],
+    ) -> Result<(), StruqtureError> {
+        let number_spins = check_statevector_lengths(input, output)?;
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let terms = prepared_terms(self.iter())?;
+        output.fill(Complex64::new(0.0, 0.0));
+        for (column, amplitude) in input.iter().enumerate() {
+            if *amplitude == Complex64::new(0.0, 0.0) {
+                continue;
+            }
+            for (operators, value) in terms.iter() {
+                let (row, prefac) = apply_pauli_operators(operators, column);
+                output[row] += value * prefac * amplitude;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the expectation value of Self for a state vector.
+    ///
+    /// The expectation value `<psi| Self |psi>` is computed term by term from the action of the PauliProducts
+    /// on the basis states, without constructing the matrix representation of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_vector` - The amplitudes of the state in the computational basis (qubit `i` corresponds to the bit `2^i`).
+    /// * `number_spins` - The number of spins of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value of Self.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The length of `state_vector` does not match `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The dimension of `number_spins` spins does not fit into usize.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn expectation_value(
+        &'a self,
+        state_vector: &[Complex64],
+        number_spins: usize,
+    ) -> Result<Complex64, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = spin_basis_dimension(number_spins)?;
+        if state_vector.len() != dimension {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Length of state vector {} does not match the dimension {} of {} spins.",
+                    state_vector.len(),
+                    dimension,
+                    number_spins
+                ),
+            });
+        }
+        let mut expectation_value = Complex64::new(0.0, 0.0);
+        for (operators, value) in prepared_terms(self.iter())? {
+            let mut term_value = Complex64::new(0.0, 0.0);
+            for (column, amplitude) in state_vector.iter().enumerate() {
+                let (row, prefac) = apply_pauli_operators(&operators, column);
+                term_value += state_vector[row].conj() * prefac * amplitude;
+            }
+            expectation_value += value * term_value;
+        }
+        Ok(expectation_value)
+    }
+
+    /// Returns the expectation value of Self for a density matrix.
+    ///
+    /// The expectation value `Tr(Self rho)` is computed term by term from the action of the PauliProducts
+    /// on the basis states, without constructing the matrix representation of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `density_matrix` - The density matrix flattened into a vector in row-major form.
+    /// * `number_spins` - The number of spins of the density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value of Self.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The length of `density_matrix` does not match `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The flattened density matrix of `number_spins` spins cannot be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn expectation_value_density_matrix(
+        &'a self,
+        density_matrix: &[Complex64],
+        number_spins: usize,
+    ) -> Result<Complex64, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let length = flattened_density_matrix_length(number_spins)?;
+        if density_matrix.len() != length {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Length of flattened density matrix {} does not match the dimension {} of {} spins.",
+                    density_matrix.len(),
+                    length,
+                    number_spins
+                ),
+            });
+        }
+        // Cannot fail as the squared dimension fits into usize
+        let dimension = spin_basis_dimension(number_spins)?;
+        let mut expectation_value = Complex64::new(0.0, 0.0);
+        for (operators, value) in prepared_terms(self.iter())? {
+            // Tr(P rho) = sum_column P[row, column] rho[column, row]
+            let mut term_value = Complex64::new(0.0, 0.0);
+            for column in 0..dimension {
+                let (row, prefac) = apply_pauli_operators(&operators, column);
+                term_value += prefac * density_matrix[column * dimension + row];
             }
+            expectation_value += value * term_value;
         }
-        Ok((values, (rows, columns)))
+        Ok(expectation_value)
+    }
+
+    /// Returns the action of Self on every computational basis state as a table.
+    ///
+    /// For each basis input index the table contains the `(output_index, amplitude)` pairs of the state
+    /// Self maps the input state to, sorted by the output index. This corresponds to the columns of the
+    /// matrix representation of Self and is intended for inspecting operators acting on few spins,
+    /// so at most 20 spins are supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the table.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Vec<(usize, Complex64)>>)` - The output states for all basis input states.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - `number_spins` exceeds the maximum of 20 spins.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn truth_table(
+        &'a self,
+        number_spins: usize,
+    ) -> Result<Vec<Vec<(usize, Complex64)>>, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        if number_spins > TRUTH_TABLE_MAX_SPINS {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The truth table of {} spins is too large, at most {} spins are supported.",
+                    number_spins, TRUTH_TABLE_MAX_SPINS
+                ),
+            });
+        }
+        let dimension = spin_basis_dimension(number_spins)?;
+        let mut table: Vec<Vec<(usize, Complex64)>> = Vec::with_capacity(dimension);
+        for input in 0..dimension {
+            let mut basis_state: HashMap<usize, Complex64> = HashMap::with_capacity(1);
+            basis_state.insert(input, Complex64::new(1.0, 0.0));
+            let mut outputs: Vec<(usize, Complex64)> = self
+                .apply_to_sparse_state(&basis_state, number_spins)?
+                .into_iter()
+                .collect();
+            outputs.sort_by_key(|(output, _)| *output);
+            table.push(outputs);
+        }
+        Ok(table)
     }
 
     /// Constructs the sparse matrix entries for one row of the sparse matrix.
@@ -266,79 +1379,104 @@ where
         row: usize,
         number_spins: usize,
     ) -> Result<HashMap<usize, Complex<f64>>, StruqtureError> {
-        let mut entries: HashMap<usize, Complex<f64>> = HashMap::new();
-        let dimension = 2_usize.pow(number_spins as u32);
-        let constant_prefactor = Complex64::new(0.0, -1.0);
-        for (index, value) in self.iter() {
-            // iterate over terms corresponding to -i H p => -i H.kron(I) flatten(p) and i p H => i I.kron(H.T)
-            for (row_adjusted, commutator_prefactor, shift) in [
-                (row.div_euclid(dimension), 1.0, number_spins),
-                (row % dimension, -1.0, 0),
-            ] {
-                let mut column = row;
-                let mut prefac: Complex<f64> = 1.0.into();
-                // first the terms corresponding to -i H p => -i H.kron(I) flatten(p)
-                for (spin_op_index, pauliop) in index.iter() {
-                    match SingleSpinOperator::from(*pauliop) {
-                        SingleSpinOperator::X => {
-                            match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
-                                0 => column += 2usize.pow((*spin_op_index + shift) as u32),
-                                1 => column -= 2usize.pow((*spin_op_index + shift) as u32),
-                                _ => panic!("Internal error in constructing matrix"),
-                            }
-                        }
-                        SingleSpinOperator::Y => {
-                            match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
-                                0 => {
-                                    column += 2usize.pow((*spin_op_index + shift) as u32);
-                                    // due to the transpose in i p H => i I.kron(H.T) only the Y Pauli operator picks up an extra
-                                    // sign equal to the commutator_prefactor
-                                    prefac *= Complex::<f64>::new(0.0, -1.0) * commutator_prefactor;
-                                }
-                                1 => {
-                                    column -= 2usize.pow((*spin_op_index + shift) as u32);
-                                    prefac *= Complex::<f64>::new(0.0, 1.0) * commutator_prefactor;
-                                }
-                                _ => panic!("Internal error in constructing matrix"),
-                            };
-                        }
-                        SingleSpinOperator::Z => {
-                            match row_adjusted.div_euclid(2usize.pow(*spin_op_index as u32)) % 2 {
-                                0 => {
-                                    prefac *= Complex::<f64>::new(1.0, 0.0);
-                                }
-                                1 => {
-                                    prefac *= Complex::<f64>::new(-1.0, 0.0);
-                                }
-                                _ => panic!("Internal error in constructing matrix"),
-                            };
-                        }
-                        SingleSpinOperator::Identity => (),
-                    }
-                }
-                prefac *= commutator_prefactor * constant_prefactor;
-                let mut_value = entries.get_mut(&column);
-                let ri_value = CalculatorComplex::from(value.clone());
-                let real_value: f64 = ri_value.re.try_into()?;
-                let imag_value: f64 = ri_value.im.try_into()?;
-                let complex_value = Complex::<f64>::new(real_value, imag_value);
-                if complex_value != Complex64::new(0.0, 0.0) {
-                    match mut_value {
-                        Some(x) => {
-                            if *x + prefac * complex_value == Complex64::new(0.0, 0.0) {
-                                entries.remove(&column);
-                            } else {
-                                *x += prefac * complex_value;
-                            }
-                        }
-                        None => {
-                            entries.insert(column, prefac * complex_value);
-                        }
-                    }
-                }
+        superoperator_entries_on_row(
+            self.iter(),
+            row,
+            number_spins,
+            Complex64::new(0.0, -1.0),
+            Complex64::new(0.0, 1.0),
+        )
+    }
+
+    /// Constructs the sparse matrix representation of the anticommutator superoperator in COO representation.
+    ///
+    /// The anticommutator superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten({O, p}) = S flatten(p)` where `{,}` is the anticommutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    /// With this convention `S = O.kron(I) + I.kron(O.T)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the anticommutator superoperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and the operator-like object is empty.
+    /// * `Err(StruqtureError::GenericError)` - The flattened density matrix of `number_spins` spins cannot be represented.
+    fn sparse_matrix_anticommutator_superoperator_coo(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let number_spins = sparse_number_spins(number_spins, self.number_spins(), self.is_empty())?;
+        collect_sparse_rows(flattened_density_matrix_length(number_spins)?, |row| {
+            self.sparse_matrix_anticommutator_superoperator_entries_on_row(row, number_spins)
+        })
+    }
+
+    /// Constructs the sparse matrix entries for one row of the anticommutator superoperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row for which to get the superoperator entries.
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, Complex64>)` - The entries of the given row of the anticommutator superoperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_anticommutator_superoperator_entries_on_row(
+        &'a self,
+        row: usize,
+        number_spins: usize,
+    ) -> Result<HashMap<usize, Complex<f64>>, StruqtureError> {
+        superoperator_entries_on_row(
+            self.iter(),
+            row,
+            number_spins,
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 0.0),
+        )
+    }
+
+    /// Constructs the sparse matrix representation of Self as a scipy COO matrix with a chosen qubit ordering.
+    ///
+    /// For `MatrixEndianness::LittleEndian` this is identical to `sparse_matrix_coo`.
+    /// For `MatrixEndianness::BigEndian` the bit position of every qubit in the row and column indices is reversed,
+    /// so that qubit 0 corresponds to the most significant bit.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    /// * `endianness` - The qubit ordering of the row and column indices.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The matrix representation of the operator-like object.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_coo_ordered(
+        &'a self,
+        number_spins: Option<usize>,
+        endianness: MatrixEndianness,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let (values, (rows, columns)) = self.sparse_matrix_coo(number_spins)?;
+        match endianness {
+            MatrixEndianness::LittleEndian => Ok((values, (rows, columns))),
+            MatrixEndianness::BigEndian => {
+                let number_bits =
+                    sparse_number_spins(number_spins, self.number_spins(), self.is_empty())?;
+                let rows = rows
+                    .into_iter()
+                    .map(|row| reverse_bits(row, number_bits))
+                    .collect();
+                let columns = columns
+                    .into_iter()
+                    .map(|column| reverse_bits(column, number_bits))
+                    .collect();
+                Ok((values, (rows, columns)))
             }
         }
-        Ok(entries)
     }
 }
 
@@ -346,7 +1484,7 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
     /// Constructs the sparse matrix representation of the superoperator as a HashMap.
     ///
     /// The superoperator for the operator O is defined as the Matrix S so that
-    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
     /// and `flatten` flattens a matrix into a vector in row-major form.
     ///
     /// # Arguments
@@ -357,34 +1495,24 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
     ///
     /// * `HashMap<(usize, usize), CalculatorComplex>` - The matrix representation of Self.
     /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
     fn sparse_matrix_superoperator(
         &'a self,
         number_spins: Option<usize>,
     ) -> Result<HashMap<(usize, usize), Complex64>, StruqtureError> {
-        let dimension = match number_spins {
-            None => 2usize.pow(self.number_spins() as u32),
-            Some(num_spins) => 2usize.pow(num_spins as u32),
-        };
-        let number_spins = match number_spins {
-            None => self.number_spins(),
-            Some(num_spins) => num_spins,
-        };
-        let mut matrix: HashMap<(usize, usize), Complex64> = HashMap::new();
-        for row in 0..dimension.pow(2) {
-            for (column, val) in self
-                .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
-                .into_iter()
-            {
-                matrix.insert((row, column), val);
-            }
-        }
-        Ok(matrix)
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        let (values, (rows, columns)) = collect_sparse_rows(dimension.pow(2), |row| {
+            self.sparse_matrix_superoperator_entries_on_row(row, number_spins)
+        })?;
+        Ok(rows.into_iter().zip(columns).zip(values).collect())
     }
 
     /// Constructs the sparse matrix representation of the superoperator in COO representation.
     ///
     /// The superoperator for the operator O is defined as the Matrix S so that
-    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
     /// and `flatten` flattens a matrix into a vector in row-major form.
     ///
     /// # Arguments
@@ -395,34 +1523,252 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
     ///
     /// * `(Vec<Complex64>, (Vec<usize>, Vec<usize>)` - The matrix representation of Self.
     /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
     fn sparse_matrix_superoperator_coo(
         &'a self,
         number_spins: Option<usize>,
     ) -> Result<CooSparseMatrix, StruqtureError> {
-        let dimension = match number_spins {
-            None => 2usize.pow(self.current_number_spins() as u32),
-            Some(num_spins) => 2usize.pow(num_spins as u32),
-        };
-        let number_spins = match number_spins {
-            None => self.number_spins(),
-            Some(num_spins) => num_spins,
-        };
-        let capacity = dimension;
-        let mut values: Vec<Complex64> = Vec::with_capacity(capacity);
-        let mut rows: Vec<usize> = Vec::with_capacity(capacity);
-        let mut columns: Vec<usize> = Vec::with_capacity(capacity);
-
-        for row in 0..dimension.pow(2) {
-            for (col, val) in self
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        collect_sparse_rows(dimension.pow(2), |row| {
+            self.sparse_matrix_superoperator_entries_on_row(row, number_spins)
+        })
+    }
+
+    /// Returns a lazy iterator over the non-zero entries of the sparse matrix representation of the superoperator.
+    ///
+    /// The entries are yielded as `(row, column, value)` triples row by row, so that only the entries
+    /// of a single row are held in memory at any time.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Box<dyn Iterator<Item = (usize, usize, Complex64)>>)` - The iterator over the entries of the superoperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
+    fn sparse_matrix_superoperator_entries(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<SparseMatrixEntries<'a>, StruqtureError> {
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        // Every term contributes to every row of the superoperator, so constructing the first row
+        // eagerly checks all values and constructing the remaining rows lazily can not fail
+        let first_row = self.sparse_matrix_superoperator_entries_on_row(0, number_spins)?;
+        Ok(Box::new(
+            first_row
+                .into_iter()
+                .map(|(column, value)| (0, column, value))
+                .chain((1..dimension.pow(2)).flat_map(move |row| {
+                    self.sparse_matrix_superoperator_entries_on_row(row, number_spins)
+                        .expect(
+                            "Internal error: coefficients were checked to be convertible to f64",
+                        )
+                        .into_iter()
+                        .map(move |(column, value)| (row, column, value))
+                })),
+        ))
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in CSR representation.
+    ///
+    /// The superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten(-i [O, p]) = S flatten(p)` where `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    /// The matrix is constructed row by row directly in CSR form, the column indices are sorted within every row.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
+    fn sparse_matrix_superoperator_csr(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        collect_sparse_rows_csr(dimension.pow(2), |row| {
+            self.sparse_matrix_superoperator_entries_on_row(row, number_spins)
+        })
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in COO representation, constructing the rows in parallel.
+    ///
+    /// The entries are ordered row by row like the entries of [Self::sparse_matrix_superoperator_coo].
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `(Vec<Complex64>, (Vec<usize>, Vec<usize>)` - The matrix representation of Self.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
+    #[cfg(feature = "parallel")]
+    fn sparse_matrix_superoperator_coo_parallel(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError>
+    where
+        Self: Sync,
+    {
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut assembler = CooAssembler::with_capacity(dimension.pow(2));
+        for_each_sparse_row_parallel(
+            dimension.pow(2),
+            |row| self.sparse_matrix_superoperator_entries_on_row(row, number_spins),
+            |row, entries| assembler.push_row(row, entries),
+        )?;
+        Ok(assembler.finish())
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in CSR representation, constructing the rows in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - No number of spins was given and Self is empty.
+    #[cfg(feature = "parallel")]
+    fn sparse_matrix_superoperator_csr_parallel(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError>
+    where
+        Self: Sync,
+    {
+        let number_spins =
+            sparse_number_spins(number_spins, self.number_spins(), self.has_no_terms())?;
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut assembler = CsrAssembler::new(dimension.pow(2));
+        for_each_sparse_row_parallel(
+            dimension.pow(2),
+            |row| self.sparse_matrix_superoperator_entries_on_row(row, number_spins),
+            |_, entries| assembler.push_row(entries),
+        )?;
+        Ok(assembler.finish())
+    }
+
+    /// Applies the superoperator `n` times to a flattened density matrix.
+    ///
+    /// The superoperator is constructed once in COO form and applied iteratively by sparse
+    /// matrix-vector multiplication, without constructing the dense matrix power.
+    ///
+    /// # Arguments
+    ///
+    /// * `rho_flat` - The density matrix flattened into a vector in row-major form.
+    /// * `n` - The number of times the superoperator is applied.
+    /// * `number_spins` - The number of spins of the density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The flattened density matrix after applying the superoperator `n` times.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The length of `rho_flat` does not match `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The flattened density matrix of `number_spins` spins cannot be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn apply_superoperator_n_times(
+        &'a self,
+        rho_flat: &[Complex64],
+        n: usize,
+        number_spins: usize,
+    ) -> Result<Vec<Complex64>, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = flattened_density_matrix_length(number_spins)?;
+        if rho_flat.len() != dimension {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Length of flattened density matrix {} does not match the dimension {} of {} spins.",
+                    rho_flat.len(),
+                    dimension,
+                    number_spins
+                ),
+            });
+        }
+        let (values, (rows, columns)) = self.sparse_matrix_superoperator_coo(Some(number_spins))?;
+        let mut state: Vec<Complex64> = rho_flat.to_vec();
+        for _ in 0..n {
+            let mut new_state: Vec<Complex64> = vec![Complex64::new(0.0, 0.0); dimension];
+            for (value, (row, column)) in values.iter().zip(rows.iter().zip(columns.iter())) {
+                new_state[*row] += value * state[*column];
+            }
+            state = new_state;
+        }
+        Ok(state)
+    }
+
+    /// Returns the expectation value of the superoperator for a density matrix.
+    ///
+    /// Computes `<<rho| S |rho>> = Tr(rho^dagger S(rho))`, where `S` is the superoperator of Self acting on the
+    /// density matrix `rho` flattened in row-major form. The superoperator is constructed row by row
+    /// and never held in memory as a whole.
+    ///
+    /// # Arguments
+    ///
+    /// * `rho_flat` - The density matrix flattened into a vector in row-major form.
+    /// * `number_spins` - The number of spins of the density matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value of the superoperator.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The length of `rho_flat` does not match `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The flattened density matrix of `number_spins` spins cannot be represented.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn superoperator_expectation_value(
+        &'a self,
+        rho_flat: &[Complex64],
+        number_spins: usize,
+    ) -> Result<Complex64, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = flattened_density_matrix_length(number_spins)?;
+        if rho_flat.len() != dimension {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Length of flattened density matrix {} does not match the dimension {} of {} spins.",
+                    rho_flat.len(),
+                    dimension,
+                    number_spins
+                ),
+            });
+        }
+        let mut expectation_value = Complex64::new(0.0, 0.0);
+        for (row, row_value) in rho_flat.iter().enumerate() {
+            if *row_value == Complex64::new(0.0, 0.0) {
+                continue;
+            }
+            let mut row_product = Complex64::new(0.0, 0.0);
+            for (column, value) in self
                 .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
                 .into_iter()
             {
-                rows.push(row);
-                columns.push(col);
-                values.push(val);
+                row_product += value * rho_flat[column];
             }
+            expectation_value += row_value.conj() * row_product;
         }
-        Ok((values, (rows, columns)))
+        Ok(expectation_value)
     }
 
     /// Constructs the sparse matrix entries for one row of the sparse matrix superoperator.
@@ -442,6 +1788,16 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
         number_spins: usize,
     ) -> Result<HashMap<usize, Complex<f64>>, StruqtureError>;
 
+    /// Returns whether Self contains no terms.
+    ///
+    /// When no number of spins is given, the sparse matrices of an object without terms acting on no spins
+    /// cannot be constructed, as their size is undetermined.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether Self contains no terms.
+    fn has_no_terms(&'a self) -> bool;
+
     /// Return the unitary part of the superoperator in the sparse COO format.
     ///
     /// # Returns
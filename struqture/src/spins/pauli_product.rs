@@ -618,6 +618,119 @@ impl PauliProduct {
             items: TinyVec::<[(usize, SingleSpinOperator); 5]>::with_capacity(cap),
         }
     }
+
+    /// Creates a new PauliProduct from a pair of bitmasks.
+    ///
+    /// Bit `i` of the masks corresponds to qubit `i`. A qubit acted on by X has only its bit in `x_mask` set,
+    /// a qubit acted on by Z has only its bit in `z_mask` set and a qubit acted on by Y has its bit set in both masks.
+    /// Every pair of masks describes a valid PauliProduct, so the conversion can not fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_mask` - The bitmask of the X-part of the PauliProduct.
+    /// * `z_mask` - The bitmask of the Z-part of the PauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PauliProduct corresponding to the bitmasks.
+    pub fn from_bitmasks(x_mask: u64, z_mask: u64) -> Self {
+        let mut pauli_product =
+            PauliProduct::with_capacity((x_mask | z_mask).count_ones() as usize);
+        for index in 0..64 {
+            let x_bit = (x_mask >> index) & 1 == 1;
+            let z_bit = (z_mask >> index) & 1 == 1;
+            pauli_product = match (x_bit, z_bit) {
+                (true, false) => pauli_product.x(index),
+                (true, true) => pauli_product.y(index),
+                (false, true) => pauli_product.z(index),
+                (false, false) => pauli_product,
+            };
+        }
+        pauli_product
+    }
+
+    /// Returns the bitmask representation of the PauliProduct.
+    ///
+    /// This is the inverse of `from_bitmasks`.
+    ///
+    /// # Returns
+    ///
+    /// * `(u64, u64)` - The X-mask and Z-mask of the PauliProduct.
+    ///
+    /// # Panics
+    ///
+    /// * The PauliProduct acts on a qubit with index 64 or larger.
+    pub fn to_bitmasks(&self) -> (u64, u64) {
+        let mut x_mask: u64 = 0;
+        let mut z_mask: u64 = 0;
+        for (index, operator) in self.iter() {
+            assert!(
+                *index < 64,
+                "PauliProduct with qubit index {} can not be represented as 64 bit masks",
+                index
+            );
+            match operator {
+                SingleSpinOperator::X => x_mask |= 1 << *index,
+                SingleSpinOperator::Y => {
+                    x_mask |= 1 << *index;
+                    z_mask |= 1 << *index;
+                }
+                SingleSpinOperator::Z => z_mask |= 1 << *index,
+                SingleSpinOperator::Identity => (),
+            }
+        }
+        (x_mask, z_mask)
+    }
+
+    /// Returns whether the PauliProduct commutes with another PauliProduct.
+    ///
+    /// Two PauliProducts commute if the number of sites on which both act with different
+    /// non-identity Pauli matrices (which anticommute) is even.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliProduct to compare with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two PauliProducts commute.
+    pub fn commutes_with(&self, other: &PauliProduct) -> bool {
+        let anticommuting_sites = self
+            .iter()
+            .filter(|(index, operator)| match other.get(index) {
+                Some(other_operator) => {
+                    *operator != SingleSpinOperator::Identity
+                        && *other_operator != SingleSpinOperator::Identity
+                        && other_operator != operator
+                }
+                None => false,
+            })
+            .count();
+        anticommuting_sites % 2 == 0
+    }
+
+    /// Returns whether the PauliProduct commutes qubit-wise with another PauliProduct.
+    ///
+    /// Two PauliProducts commute qubit-wise if they act with the same Pauli matrix or with the identity
+    /// on every site. Qubit-wise commuting products can be measured simultaneously in a product basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PauliProduct to compare with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the two PauliProducts commute qubit-wise.
+    pub fn qubit_wise_commutes_with(&self, other: &PauliProduct) -> bool {
+        self.iter().all(|(index, operator)| match other.get(index) {
+            Some(other_operator) => {
+                *operator == SingleSpinOperator::Identity
+                    || *other_operator == SingleSpinOperator::Identity
+                    || other_operator == operator
+            }
+            None => true,
+        })
+    }
 }
 
 /// Implements the default function (Default trait) of PauliProduct (an empty PauliProduct).
@@ -257,6 +257,73 @@ impl PlusMinusLindbladNoiseOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the PlusMinusLindbladNoiseOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the PlusMinusLindbladNoiseOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Creates the PlusMinusLindbladNoiseOperator of the standard relaxation of a single qubit.
+    ///
+    /// The noise consists of the decay with the Lindblad operator `sigma^-` with rate `gamma_minus`
+    /// (T1 processes) and the dephasing with the Lindblad operator `Z` with rate `gamma_z` (T2 processes).
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the relaxation acts on.
+    /// * `gamma_minus` - The rate of the decay.
+    /// * `gamma_z` - The rate of the dephasing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladNoiseOperator of the relaxation.
+    /// * `Err(StruqtureError::GenericError)` - One of the rates is negative or not a number.
+    pub fn relaxation(
+        qubit: usize,
+        gamma_minus: f64,
+        gamma_z: f64,
+    ) -> Result<Self, StruqtureError> {
+        for (name, rate) in [("gamma_minus", gamma_minus), ("gamma_z", gamma_z)] {
+            if rate.is_nan() || rate < 0.0 {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Relaxation rate {} must be non-negative, got {}.",
+                        name, rate
+                    ),
+                });
+            }
+        }
+        let mut noise = Self::with_capacity(2);
+        let minus = PlusMinusProduct::new().minus(qubit);
+        let z = PlusMinusProduct::new().z(qubit);
+        noise.set((minus.clone(), minus), CalculatorComplex::from(gamma_minus))?;
+        noise.set((z.clone(), z), CalculatorComplex::from(gamma_z))?;
+        Ok(noise)
+    }
+
+    /// Converts the PlusMinusLindbladNoiseOperator into a SpinLindbladNoiseOperator.
+    ///
+    /// Each entry of the rate matrix in the (PlusMinusProduct, PlusMinusProduct) basis is expanded
+    /// into the (DecoherenceProduct, DecoherenceProduct) basis using `+ = (X + iY) / 2` and `- = (X - iY) / 2`.
+    /// This is the inverse of [crate::spins::SpinLindbladNoiseOperator::to_plus_minus_noise_operator].
+    ///
+    /// # Returns
+    ///
+    /// * `SpinLindbladNoiseOperator` - The noise operator in the decoherence basis.
+    pub fn to_spin_noise_operator(&self) -> SpinLindbladNoiseOperator {
+        SpinLindbladNoiseOperator::from(self.clone())
+    }
+
     /// Adds all noise entries corresponding to a ((PlusMinusOperator, PlusMinusOperator), CalculatorFloat).
     ///
     /// In the Lindblad equation, Linblad noise operator L_i are not limited to [crate::spins::PlusMinusProduct] style operators.
@@ -347,6 +414,50 @@ impl PlusMinusLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the PlusMinusLindbladNoiseOperator into an HDF5 group.
+    ///
+    /// The left and right PlusMinusProducts of the sorted terms are stored as strings in the `left_keys` and `right_keys`
+    /// datasets, the real and imaginary parts of the coefficients in the `real` and `imag` datasets.
+    /// The minimum struqture version required to read the data is stored in the `struqture_major_version`
+    /// and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the PlusMinusLindbladNoiseOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The PlusMinusLindbladNoiseOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_noise_terms(group, self.iter())
+    }
+
+    /// Reads a PlusMinusLindbladNoiseOperator from an HDF5 group written with [PlusMinusLindbladNoiseOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the PlusMinusLindbladNoiseOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladNoiseOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a PlusMinusProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<((PlusMinusProduct, PlusMinusProduct), CalculatorComplex)> =
+            crate::hdf5_io::read_noise_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 impl From<PlusMinusLindbladNoiseOperator> for SpinLindbladNoiseOperator {
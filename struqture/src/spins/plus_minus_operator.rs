@@ -260,6 +260,108 @@ impl PlusMinusOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the PlusMinusOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the PlusMinusOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Creates a PlusMinusOperator directly from a HashMap of PlusMinusProducts and coefficients.
+    ///
+    /// In contrast to `FromIterator` the keys of the HashMap are already unique,
+    /// so the map is moved into the internal storage without accumulating duplicate terms.
+    /// Only terms with a coefficient of zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The HashMap of PlusMinusProducts and their coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusOperator containing the terms of the map.
+    pub fn from_hashmap(
+        map: std::collections::HashMap<PlusMinusProduct, CalculatorComplex>,
+    ) -> Self {
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal_map = map;
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal_map: IndexMap<PlusMinusProduct, CalculatorComplex> =
+            map.into_iter().collect();
+        internal_map.retain(|_, value| *value != CalculatorComplex::ZERO);
+        PlusMinusOperator { internal_map }
+    }
+
+    /// Consumes the PlusMinusOperator and rebuilds it with every PlusMinusProduct transformed by a function.
+    ///
+    /// The coefficients of terms that are mapped to the same PlusMinusProduct are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function transforming the PlusMinusProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusOperator with transformed keys.
+    /// * `Err(StruqtureError)` - A transformed term could not be added to the PlusMinusOperator.
+    pub fn convert_keys<F: Fn(PlusMinusProduct) -> PlusMinusProduct>(
+        self,
+        f: F,
+    ) -> Result<Self, StruqtureError> {
+        let mut converted = self.empty_clone(Some(self.len()));
+        for (key, value) in self.into_iter() {
+            converted.add_operator_product(f(key), value)?;
+        }
+        Ok(converted)
+    }
+
+    /// Writes the PlusMinusOperator into an HDF5 group.
+    ///
+    /// The PlusMinusProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the PlusMinusOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The PlusMinusOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a PlusMinusOperator from an HDF5 group written with [PlusMinusOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the PlusMinusOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a PlusMinusProduct.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(PlusMinusProduct, CalculatorComplex)> = crate::hdf5_io::read_terms(group)?;
+        Ok(terms.into_iter().collect())
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -660,7 +762,7 @@ impl JordanWignerSpinToFermion for PlusMinusOperator {
     fn jordan_wigner(&self) -> Self::Output {
         let mut out = FermionOperator::new();
         for pmp in self.keys() {
-            out = out + pmp.jordan_wigner() * self.get(pmp);
+            out += pmp.jordan_wigner() * self.get(pmp);
         }
         out
     }
@@ -10,11 +10,14 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, SpinOperator, ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
+use super::{
+    group_commuting_products, CommutationKind, GroupingStrategy, OperateOnSpins, SpinOperator,
+    ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+};
 use crate::fermions::{FermionHamiltonian, FermionOperator};
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::prelude::*;
-use crate::spins::{HermitianOperateOnSpins, PauliProduct, SpinIndex};
+use crate::spins::{HermitianOperateOnSpins, PauliProduct, SingleSpinOperator, SpinIndex};
 use crate::{
     CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError,
     StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
@@ -232,6 +235,11 @@ impl HermitianOperateOnSpins<'_> for SpinHamiltonian {}
 
 impl ToSparseMatrixOperator<'_> for SpinHamiltonian {}
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinHamiltonian {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -247,7 +255,7 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinHamiltonian {
 
     // From trait
     fn unitary_sparse_matrix_coo(&'a self) -> Result<crate::CooSparseMatrix, StruqtureError> {
-        self.sparse_matrix_coo(None)
+        self.sparse_matrix_coo(Some(self.number_spins()))
     }
 
     // From trait
@@ -304,6 +312,22 @@ impl SpinHamiltonian {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the SpinHamiltonian.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the SpinHamiltonian while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -328,6 +352,364 @@ impl SpinHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Truncates the SpinHamiltonian like `truncate`, but always keeps the identity term.
+    ///
+    /// The identity term (the empty PauliProduct) is kept with its original coefficient regardless
+    /// of its magnitude, as it sets the energy reference. Only an identity term with a coefficient of exactly zero is absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated version of the SpinHamiltonian including the identity term.
+    pub fn truncate_keep_identity(&self, threshold: f64) -> Self {
+        let mut truncated = self.truncate(threshold);
+        let identity = PauliProduct::new();
+        let value = self.get(&identity);
+        if *value != CalculatorFloat::ZERO {
+            truncated.internal_map.insert(identity, value.clone());
+        }
+        truncated
+    }
+
+    /// Creates the SpinHamiltonian of the transverse-field Ising model on a chain.
+    ///
+    /// The Hamiltonian is given by `-j * sum_i Z_i Z_{i+1} - h * sum_i X_i`.
+    /// For periodic boundary conditions the coupling `Z_{sites-1} Z_0` is added for chains with more than two sites.
+    ///
+    /// # Arguments
+    ///
+    /// * `sites` - The number of sites (spins) of the chain.
+    /// * `j` - The strength of the nearest-neighbour ZZ coupling.
+    /// * `h` - The strength of the transverse field.
+    /// * `periodic` - Whether to use periodic boundary conditions.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinHamiltonian of the transverse-field Ising model.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn transverse_field_ising(
+        sites: usize,
+        j: CalculatorFloat,
+        h: CalculatorFloat,
+        periodic: bool,
+    ) -> Self {
+        let mut hamiltonian = Self::with_capacity(2 * sites);
+        let field = h * (-1.0);
+        hamiltonian.add_nearest_neighbour_coupling(
+            sites,
+            SingleSpinOperator::Z,
+            j * (-1.0),
+            periodic,
+        );
+        for site in 0..sites {
+            hamiltonian
+                .add_operator_product(PauliProduct::new().x(site), field.clone())
+                .expect("Internal bug in add_operator_product");
+        }
+        hamiltonian
+    }
+
+    /// Creates the SpinHamiltonian of the anisotropic Heisenberg model on a chain.
+    ///
+    /// The Hamiltonian is given by `sum_i (jx * X_i X_{i+1} + jy * Y_i Y_{i+1} + jz * Z_i Z_{i+1})`.
+    /// For periodic boundary conditions the couplings between site `sites-1` and site 0 are added for chains with more than two sites.
+    ///
+    /// # Arguments
+    ///
+    /// * `sites` - The number of sites (spins) of the chain.
+    /// * `jx` - The strength of the nearest-neighbour XX coupling.
+    /// * `jy` - The strength of the nearest-neighbour YY coupling.
+    /// * `jz` - The strength of the nearest-neighbour ZZ coupling.
+    /// * `periodic` - Whether to use periodic boundary conditions.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinHamiltonian of the Heisenberg model.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn heisenberg(
+        sites: usize,
+        jx: CalculatorFloat,
+        jy: CalculatorFloat,
+        jz: CalculatorFloat,
+        periodic: bool,
+    ) -> Self {
+        let mut hamiltonian = Self::with_capacity(3 * sites);
+        hamiltonian.add_nearest_neighbour_coupling(sites, SingleSpinOperator::X, jx, periodic);
+        hamiltonian.add_nearest_neighbour_coupling(sites, SingleSpinOperator::Y, jy, periodic);
+        hamiltonian.add_nearest_neighbour_coupling(sites, SingleSpinOperator::Z, jz, periodic);
+        hamiltonian
+    }
+
+    /// Partitions the terms of the SpinHamiltonian into groups of mutually commuting PauliProducts.
+    ///
+    /// The groups are determined with a greedy graph-coloring heuristic: the terms are visited in the
+    /// order of their PauliProducts and each term is added to the first group in which it commutes
+    /// with all terms (see [PauliProduct::commutes_with]). A new group is opened if no such group exists.
+    /// The sum of all groups is the original SpinHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SpinOperator>` - The groups of mutually commuting terms.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn commuting_groups(&self) -> Vec<SpinOperator> {
+        self.group_commuting_terms(GroupingStrategy::Greedy(CommutationKind::General))
+    }
+
+    /// Partitions the terms of the SpinHamiltonian into groups of commuting PauliProducts.
+    ///
+    /// Depending on the strategy, the PauliProducts in each group commute qubit-wise or as operators.
+    /// The sum of all groups is the original SpinHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The grouping strategy, see [GroupingStrategy].
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SpinOperator>` - The groups of commuting terms.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn group_commuting_terms(&self, strategy: GroupingStrategy) -> Vec<SpinOperator> {
+        let terms: Vec<(&PauliProduct, &CalculatorFloat)> = self.internal_map.iter().collect();
+        let products: Vec<&PauliProduct> = terms.iter().map(|(product, _)| *product).collect();
+        group_commuting_products(&products, strategy)
+            .into_iter()
+            .map(|group| {
+                let mut operator = SpinOperator::with_capacity(group.len());
+                for position in group {
+                    let (product, value) = terms[position];
+                    operator
+                        .add_operator_product(
+                            product.clone(),
+                            CalculatorComplex::from(value.clone()),
+                        )
+                        .expect("Internal bug in add_operator_product");
+                }
+                operator
+            })
+            .collect()
+    }
+
+    /// Constructs a SpinHamiltonian from a Pauli-sum text input.
+    ///
+    /// Each line contains one term given by its real coefficient followed by the Pauli operators,
+    /// each written as the operator (`X`, `Y`, `Z` or `I`) directly followed by the qubit index,
+    /// e.g. `0.5 X0 Z1`. A line containing only a coefficient is a term proportional to the identity.
+    /// Everything after a `#` is a comment, empty lines are skipped. Coefficients of duplicate terms are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the Pauli-sum input.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonian constructed from the input.
+    /// * `Err(StruqtureError::ParsingError)` - A line of the input is malformed, the error message contains the line number.
+    /// * `Err(StruqtureError::GenericError)` - The input could not be read.
+    pub fn from_pauli_sum_file<R: std::io::Read>(reader: R) -> Result<Self, StruqtureError> {
+        let parsing_error = |line_number: usize, msg: String| StruqtureError::ParsingError {
+            target_type: "SpinHamiltonian".to_string(),
+            msg: format!("Line {}: {}", line_number, msg),
+        };
+        let mut hamiltonian = Self::new();
+        for (index, line) in std::io::BufRead::lines(std::io::BufReader::new(reader)).enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|err| StruqtureError::GenericError {
+                msg: format!("Could not read line {}: {}", line_number, err),
+            })?;
+            let content = match line.split_once('#') {
+                Some((content, _)) => content,
+                None => line.as_str(),
+            };
+            let mut tokens = content.split_whitespace();
+            let coefficient = match tokens.next() {
+                Some(coefficient) => coefficient,
+                None => continue,
+            };
+            let coefficient: f64 = coefficient.parse().map_err(|_| {
+                parsing_error(
+                    line_number,
+                    format!("Invalid real coefficient {}.", coefficient),
+                )
+            })?;
+            let mut product = PauliProduct::new();
+            for token in tokens {
+                let mut characters = token.chars();
+                let pauli = match characters.next() {
+                    Some('X') | Some('x') => SingleSpinOperator::X,
+                    Some('Y') | Some('y') => SingleSpinOperator::Y,
+                    Some('Z') | Some('z') => SingleSpinOperator::Z,
+                    Some('I') | Some('i') => SingleSpinOperator::Identity,
+                    _ => {
+                        return Err(parsing_error(
+                            line_number,
+                            format!("Invalid Pauli operator {}.", token),
+                        ))
+                    }
+                };
+                let qubit: usize = characters.as_str().parse().map_err(|_| {
+                    parsing_error(line_number, format!("Invalid qubit index in {}.", token))
+                })?;
+                if product.get(&qubit).is_some() {
+                    return Err(parsing_error(
+                        line_number,
+                        format!("Qubit {} appears more than once.", qubit),
+                    ));
+                }
+                product = product.set_pauli(qubit, pauli);
+            }
+            hamiltonian.add_operator_product(product, CalculatorFloat::from(coefficient))?;
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Adds the coupling `coupling * P_i P_{i+1}` between all nearest neighbours of a chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `sites` - The number of sites (spins) of the chain.
+    /// * `pauli` - The Pauli operator P of the coupling.
+    /// * `coupling` - The strength of the coupling.
+    /// * `periodic` - Whether to add the coupling between the last and the first site (for more than two sites).
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    fn add_nearest_neighbour_coupling(
+        &mut self,
+        sites: usize,
+        pauli: SingleSpinOperator,
+        coupling: CalculatorFloat,
+        periodic: bool,
+    ) {
+        for site in 1..sites {
+            self.add_operator_product(
+                PauliProduct::new()
+                    .set_pauli(site - 1, pauli)
+                    .set_pauli(site, pauli),
+                coupling.clone(),
+            )
+            .expect("Internal bug in add_operator_product");
+        }
+        if periodic && sites > 2 {
+            self.add_operator_product(
+                PauliProduct::new()
+                    .set_pauli(0, pauli)
+                    .set_pauli(sites - 1, pauli),
+                coupling,
+            )
+            .expect("Internal bug in add_operator_product");
+        }
+    }
+
+    /// Returns the commutator `[self, other] = self * other - other * self` of two SpinHamiltonians.
+    ///
+    /// Two PauliProducts either commute or anticommute, so only anticommuting pairs of terms contribute,
+    /// each with a purely imaginary coefficient. The result is therefore a SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinHamiltonian to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The commutator of the two SpinHamiltonians.
+    pub fn commutator(&self, other: &Self) -> SpinOperator {
+        (self.clone() * other.clone()) - (other.clone() * self.clone())
+    }
+
+    /// Returns the anticommutator `{self, other} = self * other + other * self` of two SpinHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinHamiltonian to anticommute with.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The anticommutator of the two SpinHamiltonians.
+    pub fn anticommutator(&self, other: &Self) -> SpinOperator {
+        (self.clone() * other.clone()) + (other.clone() * self.clone())
+    }
+
+    /// Writes the SpinHamiltonian into an HDF5 group.
+    ///
+    /// The PauliProducts are sorted and stored as strings in the `keys` dataset, the real coefficients
+    /// in the `real` dataset. The minimum struqture version required to read the data is stored in the
+    /// `struqture_major_version` and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinHamiltonian to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinHamiltonian has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_real_terms(group, self.iter())
+    }
+
+    /// Reads a SpinHamiltonian from an HDF5 group written with [SpinHamiltonian::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinHamiltonian from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonian stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a PauliProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(PauliProduct, CalculatorFloat)> = crate::hdf5_io::read_real_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
+}
+
+/// Implements the plus function of SpinHamiltonian by SpinOperator.
+///
+/// The generic `+` of SpinHamiltonian keeps real coefficients, so the sum with a SpinOperator is
+/// implemented for a reference to the SpinHamiltonian.
+///
+impl ops::Add<SpinOperator> for &SpinHamiltonian {
+    type Output = SpinOperator;
+    /// Implements `+` (add) for a SpinHamiltonian and a SpinOperator.
+    ///
+    /// The real coefficients of the SpinHamiltonian are promoted to complex coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The sum of the SpinHamiltonian and the SpinOperator.
+    fn add(self, other: SpinOperator) -> SpinOperator {
+        SpinOperator::from(self.clone()) + other
+    }
 }
 
 impl TryFrom<SpinOperator> for SpinHamiltonian {
@@ -644,8 +1026,8 @@ impl JordanWignerSpinToFermion for SpinHamiltonian {
         let mut out = FermionOperator::new();
         for pp in self.keys() {
             let mut new_term = pp.jordan_wigner();
-            new_term = new_term * self.get(pp);
-            out = out + new_term;
+            new_term *= self.get(pp);
+            out += new_term;
         }
         let filtered_fermion_operator = FermionOperator::from_iter(out.into_iter().filter(|x| {
             x.0.is_natural_hermitian() || x.0.creators().min() < x.0.annihilators().min()
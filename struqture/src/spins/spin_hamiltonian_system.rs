@@ -197,6 +197,11 @@ impl HermitianOperateOnSpins<'_> for SpinHamiltonianSystem {}
 
 impl ToSparseMatrixOperator<'_> for SpinHamiltonianSystem {}
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinHamiltonianSystem {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -212,8 +217,8 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinHamiltonianSystem {
 
     // From trait
     fn unitary_sparse_matrix_coo(&'a self) -> Result<CooSparseMatrix, StruqtureError> {
-        self.sparse_matrix_coo(self.number_spins)
-        // However this would also work: self.hamiltonian.sparse_matrix_coo(self.number_spins)
+        self.sparse_matrix_coo(Some(self.number_spins()))
+        // However this would also work: self.hamiltonian.sparse_matrix_coo(Some(self.number_spins()))
     }
 
     // From trait
@@ -263,6 +268,13 @@ impl SpinHamiltonianSystem {
         }
     }
 
+    /// Removes all terms from the SpinHamiltonianSystem while keeping the number of spins and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.hamiltonian.clear();
+    }
+
     /// Returns the SpinHamiltonian of the SpinHamiltonianSystem.
     ///
     /// # Returns
@@ -339,6 +351,46 @@ impl SpinHamiltonianSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the SpinHamiltonianSystem into an HDF5 group.
+    ///
+    /// The hamiltonian of the SpinHamiltonianSystem is written with [SpinHamiltonian::to_hdf5], the `number_spins` attribute
+    /// is only written when the number spins of the SpinHamiltonianSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinHamiltonianSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinHamiltonianSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.hamiltonian.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_spins", self.number_spins)
+    }
+
+    /// Reads a SpinHamiltonianSystem from an HDF5 group written with [SpinHamiltonianSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinHamiltonianSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonianSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The hamiltonian could not be read or does not fit into the number spins.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_hamiltonian(
+            SpinHamiltonian::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_spins")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of SpinHamiltonianSystem.
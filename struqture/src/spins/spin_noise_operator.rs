@@ -10,10 +10,13 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{OperateOnSpins, SingleDecoherenceOperator, ToSparseMatrixSuperOperator};
+use super::{
+    is_valid_lindblad_operator, OperateOnSpins, SingleDecoherenceOperator,
+    ToSparseMatrixSuperOperator,
+};
 use crate::fermions::FermionLindbladNoiseOperator;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{DecoherenceOperator, DecoherenceProduct};
+use crate::spins::{DecoherenceOperator, DecoherenceProduct, PlusMinusLindbladNoiseOperator};
 use crate::{
     CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError,
     StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
@@ -201,7 +204,7 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if key.0.is_empty() || key.1.is_empty() {
+        if !is_valid_lindblad_operator(&key.0) || !is_valid_lindblad_operator(&key.1) {
             return Err(StruqtureError::InvalidLindbladTerms);
         }
 
@@ -245,6 +248,11 @@ impl OperateOnSpins<'_> for SpinLindbladNoiseOperator {
 }
 
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinLindbladNoiseOperator {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &self,
@@ -346,6 +354,37 @@ impl SpinLindbladNoiseOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the SpinLindbladNoiseOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the SpinLindbladNoiseOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Converts the SpinLindbladNoiseOperator into a PlusMinusLindbladNoiseOperator.
+    ///
+    /// Each entry of the rate matrix in the (DecoherenceProduct, DecoherenceProduct) basis is expanded
+    /// into the (PlusMinusProduct, PlusMinusProduct) basis using `X = + + -` and `iY = + - -`.
+    /// Because the X and iY operators both mix into `+` and `-`, a single entry can produce cross terms
+    /// such as (`+`, `-`). As the expansion coefficients are real, the right-hand side Lindblad operator,
+    /// which enters the Lindblad equation in hermitian conjugated form, does not need to be conjugated.
+    ///
+    /// # Returns
+    ///
+    /// * `PlusMinusLindbladNoiseOperator` - The noise operator in the plus/minus basis.
+    pub fn to_plus_minus_noise_operator(&self) -> PlusMinusLindbladNoiseOperator {
+        PlusMinusLindbladNoiseOperator::from(self.clone())
+    }
+
     /// Adds all noise entries corresponding to a ((DecoherenceOperator, DecoherenceOperator), CalculatorFloat).
     ///
     /// In the Lindblad equation, Linblad noise operator L_i are not limited to [crate::spins::DecoherenceProduct] style operators.
@@ -437,6 +476,50 @@ impl SpinLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the SpinLindbladNoiseOperator into an HDF5 group.
+    ///
+    /// The left and right DecoherenceProducts of the sorted terms are stored as strings in the `left_keys` and `right_keys`
+    /// datasets, the real and imaginary parts of the coefficients in the `real` and `imag` datasets.
+    /// The minimum struqture version required to read the data is stored in the `struqture_major_version`
+    /// and `struqture_minor_version` attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinLindbladNoiseOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinLindbladNoiseOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_noise_terms(group, self.iter())
+    }
+
+    /// Reads a SpinLindbladNoiseOperator from an HDF5 group written with [SpinLindbladNoiseOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinLindbladNoiseOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a DecoherenceProduct or a term could not be set.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<((DecoherenceProduct, DecoherenceProduct), CalculatorComplex)> =
+            crate::hdf5_io::read_noise_terms(group)?;
+        let mut operator = Self::new();
+        for (key, value) in terms {
+            operator.set(key, value)?;
+        }
+        Ok(operator)
+    }
 }
 
 /// Implements the negative sign function of SpinLindbladNoiseOperator.
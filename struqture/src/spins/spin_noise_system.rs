@@ -191,6 +191,11 @@ impl OperateOnSpins<'_> for SpinLindbladNoiseSystem {
 }
 
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinLindbladNoiseSystem {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -262,6 +267,13 @@ impl SpinLindbladNoiseSystem {
         }
     }
 
+    /// Removes all terms from the SpinLindbladNoiseSystem while keeping the number of spins and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the SpinLindbladNoiseOperator of the SpinLindbladNoiseSystem.
     ///
     /// # Returns
@@ -331,6 +343,46 @@ impl SpinLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the SpinLindbladNoiseSystem into an HDF5 group.
+    ///
+    /// The operator of the SpinLindbladNoiseSystem is written with [SpinLindbladNoiseOperator::to_hdf5], the `number_spins` attribute
+    /// is only written when the number spins of the SpinLindbladNoiseSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinLindbladNoiseSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinLindbladNoiseSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_spins", self.number_spins)
+    }
+
+    /// Reads a SpinLindbladNoiseSystem from an HDF5 group written with [SpinLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinLindbladNoiseSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number spins.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            SpinLindbladNoiseOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_spins")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of SpinLindbladNoiseSystem.
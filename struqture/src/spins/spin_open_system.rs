@@ -13,10 +13,13 @@
 use super::SpinLindbladNoiseSystem;
 use crate::fermions::FermionLindbladOpenSystem;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{OperateOnSpins, SpinHamiltonianSystem, ToSparseMatrixSuperOperator};
-use crate::{CooSparseMatrix, OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use crate::spins::{
+    is_valid_lindblad_operator, DecoherenceProduct, OperateOnSpins, PauliProduct, SpinHamiltonian,
+    SpinHamiltonianSystem, SpinOperator, ToSparseMatrixSuperOperator,
+};
+use crate::{CooSparseMatrix, OpenSystem, OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use num_complex::Complex64;
-use qoqo_calculator::CalculatorFloat;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{self, Write};
@@ -155,6 +158,11 @@ impl OperateOnSpins<'_> for SpinLindbladOpenSystem {
 }
 
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinLindbladOpenSystem {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.system.is_empty() && self.noise.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -163,12 +171,10 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinLindbladOpenSystem {
     ) -> Result<HashMap<usize, Complex64>, StruqtureError> {
         let mut system_row = self
             .system
-            .sparse_matrix_superoperator_entries_on_row(row, number_spins)
-            .unwrap();
+            .sparse_matrix_superoperator_entries_on_row(row, number_spins)?;
         let noise_row = self
             .noise
-            .sparse_matrix_superoperator_entries_on_row(row, number_spins)
-            .unwrap();
+            .sparse_matrix_superoperator_entries_on_row(row, number_spins)?;
         for (key, val) in noise_row.into_iter() {
             match system_row.get_mut(&key) {
                 Some(x) => *x += val,
@@ -223,6 +229,158 @@ impl SpinLindbladOpenSystem {
             noise: SpinLindbladNoiseSystem::new(number_spins),
         }
     }
+
+    /// Composes two SpinLindbladOpenSystems describing concurrent processes.
+    ///
+    /// Since the Lindbladians of concurrent processes add up, the Hamiltonians of both systems are added
+    /// and the noise rate matrices are merged, summing the rates of shared keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinLindbladOpenSystem to compose with Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The composed SpinLindbladOpenSystem.
+    /// * `Err(StruqtureError::MissmatchedNumberSpins)` - The number of spins of Self and other do not match.
+    /// * `Err(StruqtureError)` - Error propagated from adding the systems.
+    pub fn concurrent_compose(&self, other: &Self) -> Result<Self, StruqtureError> {
+        if self.system.number_spins != other.system.number_spins
+            || self.noise.number_spins != other.noise.number_spins
+        {
+            return Err(StruqtureError::MissmatchedNumberSpins);
+        }
+        self.clone() + other.clone()
+    }
+
+    /// Returns the effective non-hermitian Hamiltonian of the SpinLindbladOpenSystem.
+    ///
+    /// The effective Hamiltonian used in the quantum-trajectory method is given by
+    /// `H_eff = H - i/2 * sum_{l,r} gamma_{l,r} A_r^dagger A_l`, where H is the Hamiltonian of the system
+    /// and `gamma_{l,r}` is the rate of the noise term with the left DecoherenceProduct `A_l`
+    /// and the right DecoherenceProduct `A_r`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The effective Hamiltonian.
+    /// * `Err(StruqtureError)` - Error propagated from adding the operator products.
+    pub fn effective_hamiltonian(&self) -> Result<SpinOperator, StruqtureError> {
+        let mut effective_hamiltonian = SpinOperator::from(self.system.hamiltonian.clone());
+        for ((left, right), rate) in self.noise.operator.iter() {
+            let (left_pauli, left_prefactor) =
+                DecoherenceProduct::decoherence_to_spin(left.clone());
+            let (right_pauli, right_prefactor) =
+                DecoherenceProduct::decoherence_to_spin(right.clone());
+            let (product, product_prefactor) = right_pauli * left_pauli;
+            let prefactor = right_prefactor.conj()
+                * left_prefactor
+                * product_prefactor
+                * Complex64::new(0.0, -0.5);
+            effective_hamiltonian.add_operator_product(
+                product,
+                rate.clone() * CalculatorComplex::new(prefactor.re, prefactor.im),
+            )?;
+        }
+        Ok(effective_hamiltonian)
+    }
+
+    /// Adds a constant energy offset to the Hamiltonian of the SpinLindbladOpenSystem.
+    ///
+    /// The scalar is added to the coefficient of the identity term of the system, the noise is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar` - The energy offset to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The offset was added.
+    /// * `Err(StruqtureError)` - Error propagated from adding the operator product.
+    pub fn add_hamiltonian_scalar(
+        &mut self,
+        scalar: CalculatorFloat,
+    ) -> Result<(), StruqtureError> {
+        self.system
+            .add_operator_product(PauliProduct::new(), scalar)
+    }
+
+    /// Creates a SpinLindbladOpenSystem from a SpinHamiltonian and a list of single-channel jumps.
+    ///
+    /// Each jump `(A, gamma)` adds the diagonal entry `gamma` for the key `(A, A)` to the rate matrix
+    /// of the noise, i.e. the Lindblad term `gamma * (A rho A^dagger - 1/2 {A^dagger A, rho})`.
+    /// Rates of repeated jump operators are added up. The number of spins is not fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The SpinHamiltonian of the system.
+    /// * `jumps` - The jump operators and their rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladOpenSystem with the given Hamiltonian and noise.
+    /// * `Err(StruqtureError::InvalidLindbladTerms)` - A jump operator is not traceless (the identity).
+    /// * `Err(StruqtureError)` - Error propagated from constructing the system or the noise.
+    pub fn from_hamiltonian_and_jumps(
+        hamiltonian: SpinHamiltonian,
+        jumps: &[(DecoherenceProduct, f64)],
+    ) -> Result<Self, StruqtureError> {
+        let mut noise = SpinLindbladNoiseSystem::new(None);
+        for (jump, rate) in jumps.iter() {
+            if !is_valid_lindblad_operator(jump) {
+                return Err(StruqtureError::InvalidLindbladTerms);
+            }
+            noise.add_operator_product(
+                (jump.clone(), jump.clone()),
+                CalculatorComplex::from(*rate),
+            )?;
+        }
+        Self::group(
+            SpinHamiltonianSystem::from_hamiltonian(hamiltonian, None)?,
+            noise,
+        )
+    }
+
+    /// Writes the SpinLindbladOpenSystem into an HDF5 group.
+    ///
+    /// The system and noise parts are written into the subgroups `system` and `noise` with
+    /// [SpinHamiltonianSystem::to_hdf5] and [SpinLindbladNoiseSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinLindbladOpenSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinLindbladOpenSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::create_open_system_groups(group)?;
+        self.system.to_hdf5(&system_group)?;
+        self.noise.to_hdf5(&noise_group)
+    }
+
+    /// Reads a SpinLindbladOpenSystem from an HDF5 group written with [SpinLindbladOpenSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinLindbladOpenSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladOpenSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The system or noise could not be read or do not have matching sizes.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let (system_group, noise_group) = crate::hdf5_io::open_system_groups(group)?;
+        Self::group(
+            SpinHamiltonianSystem::from_hdf5(&system_group)?,
+            SpinLindbladNoiseSystem::from_hdf5(&noise_group)?,
+        )
+    }
 }
 
 /// Implements the negative sign function of SpinLindbladOpenSystem.
@@ -10,10 +10,15 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
+use super::{
+    group_commuting_products, GroupingStrategy, ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+};
 use crate::fermions::FermionOperator;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{OperateOnSpins, PauliProduct, SpinHamiltonian, SpinIndex};
+use crate::spins::{
+    FrozenSpinOperator, OperateOnSpins, PauliProduct, SingleSpinOperator, SpinHamiltonian,
+    SpinIndex,
+};
 use crate::{
     CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError,
     StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
@@ -32,6 +37,7 @@ use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
+use std::str::FromStr;
 
 /// SpinOperators are combinations of PauliProducts with specific CalculatorComplex coefficients.
 ///
@@ -164,6 +170,23 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinOperator {
         self.internal_map.remove(key)
     }
 
+    // From trait
+    fn drain_filter<F>(&mut self, predicate: F) -> Vec<(Self::Index, Self::Value)>
+    where
+        F: Fn(&Self::Index, &Self::Value) -> bool,
+    {
+        let mut drained: Vec<(Self::Index, Self::Value)> = Vec::new();
+        self.internal_map.retain(|key, value| {
+            if predicate(key, value) {
+                drained.push((key.clone(), value.clone()));
+                false
+            } else {
+                true
+            }
+        });
+        drained
+    }
+
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
         match capacity {
@@ -242,6 +265,11 @@ impl OperateOnSpins<'_> for SpinOperator {
 
 impl ToSparseMatrixOperator<'_> for SpinOperator {}
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinOperator {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -257,7 +285,7 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinOperator {
 
     // From trait
     fn unitary_sparse_matrix_coo(&'a self) -> Result<CooSparseMatrix, StruqtureError> {
-        self.sparse_matrix_coo(None)
+        self.sparse_matrix_coo(Some(self.number_spins()))
     }
 
     // From trait
@@ -314,6 +342,107 @@ impl SpinOperator {
         }
     }
 
+    /// Returns the number of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the internal map of the SpinOperator.
+    pub fn number_of_terms(&self) -> usize {
+        self.internal_map.len()
+    }
+
+    /// Removes all terms from the SpinOperator while keeping its configuration and allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.internal_map.clear();
+    }
+
+    /// Creates a SpinOperator directly from a HashMap of PauliProducts and coefficients.
+    ///
+    /// In contrast to `FromIterator` the keys of the HashMap are already unique,
+    /// so the map is moved into the internal storage without accumulating duplicate terms.
+    /// Only terms with a coefficient of zero are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The HashMap of PauliProducts and their coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinOperator containing the terms of the map.
+    pub fn from_hashmap(map: std::collections::HashMap<PauliProduct, CalculatorComplex>) -> Self {
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal_map = map;
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal_map: IndexMap<PauliProduct, CalculatorComplex> = map.into_iter().collect();
+        internal_map.retain(|_, value| *value != CalculatorComplex::ZERO);
+        SpinOperator { internal_map }
+    }
+
+    /// Consumes the SpinOperator and rebuilds it with every PauliProduct transformed by a function.
+    ///
+    /// The coefficients of terms that are mapped to the same PauliProduct are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function transforming the PauliProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator with transformed keys.
+    /// * `Err(StruqtureError)` - A transformed term could not be added to the SpinOperator.
+    pub fn convert_keys<F: Fn(PauliProduct) -> PauliProduct>(
+        self,
+        f: F,
+    ) -> Result<Self, StruqtureError> {
+        let mut converted = self.empty_clone(Some(self.len()));
+        for (key, value) in self.into_iter() {
+            converted.add_operator_product(f(key), value)?;
+        }
+        Ok(converted)
+    }
+
+    /// Writes the SpinOperator into an HDF5 group.
+    ///
+    /// The PauliProducts are sorted and stored as strings in the `keys` dataset, the real and imaginary parts
+    /// of the coefficients in the `real` and `imag` datasets. The minimum struqture version required to
+    /// read the data is stored in the `struqture_major_version` and `struqture_minor_version` attributes.
+    /// Symbolic coefficients can not be stored in the numeric datasets, a SpinOperator with a symbolic
+    /// coefficient is rejected with a CalculatorError before anything is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinOperator to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinOperator has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        crate::hdf5_io::write_terms(group, self.iter())
+    }
+
+    /// Reads a SpinOperator from an HDF5 group written with [SpinOperator::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinOperator from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - A key could not be parsed as a PauliProduct.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        let terms: Vec<(PauliProduct, CalculatorComplex)> = crate::hdf5_io::read_terms(group)?;
+        Ok(terms.into_iter().collect())
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -338,6 +467,723 @@ impl SpinOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Computes the trace distance between the matrix representations of Self and another SpinOperator.
+    ///
+    /// The trace distance is given by `1/2 * sum(|s_i|)`, where `s_i` are the singular values of the dense matrix `A - B`.
+    /// The singular values are obtained with a singular value decomposition of the dense difference matrix.
+    /// Note that the computational cost scales as `O(8^number_spins)`, so this should only be used for small systems.
+    /// Requires the `linalg` feature, which enables the optional nalgebra dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to compare Self to.
+    /// * `number_spins` - The number of spins for which to construct the matrices.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The trace distance between Self and other.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self or other act on more spins than `number_spins`.
+    /// * `Err(StruqtureError)` - Error constructing the sparse matrices.
+    #[cfg(feature = "linalg")]
+    pub fn trace_distance(&self, other: &Self, number_spins: usize) -> Result<f64, StruqtureError> {
+        if self.current_number_spins() > number_spins || other.current_number_spins() > number_spins
+        {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut difference = nalgebra::DMatrix::<Complex64>::zeros(dimension, dimension);
+        let (values, (rows, columns)) = self.sparse_matrix_coo(Some(number_spins))?;
+        for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+            difference[(row, column)] += value;
+        }
+        let (values, (rows, columns)) = other.sparse_matrix_coo(Some(number_spins))?;
+        for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+            difference[(row, column)] -= value;
+        }
+        Ok(0.5
+            * difference
+                .singular_values()
+                .iter()
+                .map(|s| s.abs())
+                .sum::<f64>())
+    }
+
+    /// Computes the normalized Hilbert-Schmidt overlap `Re<A, B> / (||A|| * ||B||)` of Self and another SpinOperator.
+    ///
+    /// Here `<A, B> = Tr(A^dagger B)` is the Hilbert-Schmidt product and `||A||` the corresponding Frobenius norm.
+    /// As PauliProducts are orthogonal with respect to the Hilbert-Schmidt product, the overlap is computed directly
+    /// from the coefficients and does not depend on the number of spins.
+    /// The overlap is 1.0 for identical operators and 0.0 for orthogonal ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to compare Self to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The normalized overlap of Self and other.
+    /// * `Err(StruqtureError::GenericError)` - Self or other is the zero operator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn normalized_overlap(&self, other: &Self) -> Result<f64, StruqtureError> {
+        let to_complex = |value: &CalculatorComplex| -> Result<Complex64, StruqtureError> {
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            Ok(Complex64::new(real, imaginary))
+        };
+        let mut norm_self = 0.0;
+        let mut overlap = 0.0;
+        for (key, value) in self.iter() {
+            let value = to_complex(value)?;
+            norm_self += value.norm_sqr();
+            if let Some(other_value) = other.internal_map.get(key) {
+                overlap += (value.conj() * to_complex(other_value)?).re;
+            }
+        }
+        let mut norm_other = 0.0;
+        for value in other.values() {
+            norm_other += to_complex(value)?.norm_sqr();
+        }
+        if norm_self == 0.0 || norm_other == 0.0 {
+            return Err(StruqtureError::GenericError {
+                msg: "The normalized overlap is not defined for a zero operator.".to_string(),
+            });
+        }
+        Ok(overlap / (norm_self.sqrt() * norm_other.sqrt()))
+    }
+
+    /// Returns the SpinOperator with the real parts of all coefficients of Self.
+    ///
+    /// Symbolic coefficients are split into their symbolic real part. Terms with a vanishing real part are removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinOperator with the real parts of the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in set.
+    pub fn real_part(&self) -> Self {
+        let mut real_part = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            real_part
+                .set(key.clone(), CalculatorComplex::new(value.re.clone(), 0.0))
+                .expect("Internal bug in set");
+        }
+        real_part
+    }
+
+    /// Returns the SpinOperator with the imaginary parts of all coefficients of Self.
+    ///
+    /// The coefficients of the returned SpinOperator are real, so that `real_part + i * imaginary_part`
+    /// reconstructs Self. Symbolic coefficients are split into their symbolic imaginary part.
+    /// Terms with a vanishing imaginary part are removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinOperator with the imaginary parts of the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in set.
+    pub fn imaginary_part(&self) -> Self {
+        let mut imaginary_part = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            imaginary_part
+                .set(key.clone(), CalculatorComplex::new(value.im.clone(), 0.0))
+                .expect("Internal bug in set");
+        }
+        imaginary_part
+    }
+
+    /// Returns a string representation of the SpinOperator that can be parsed by SymPy.
+    ///
+    /// Each term is rendered as the coefficient times a product of `Pauli` function calls,
+    /// e.g. `0.5*Pauli('X',0)*Pauli('Z',1)`, the identity term only consists of its coefficient.
+    /// Imaginary parts are multiplied with the SymPy imaginary unit `I`, symbolic coefficients are
+    /// put in parentheses and passed through unchanged so that SymPy parses them as symbols.
+    /// The terms are sorted by PauliProduct and joined with ` + `, an empty SpinOperator is rendered as `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_prefix` - The prefix of the Pauli function name (e.g. a module path), use `""` for plain `Pauli`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The SymPy-parseable representation of the SpinOperator.
+    pub fn to_sympy_string(&self, symbol_prefix: &str) -> String {
+        let float_to_string = |float: &CalculatorFloat| match float {
+            CalculatorFloat::Float(x) => format!("{}", x),
+            CalculatorFloat::Str(x) => format!("({})", x),
+        };
+        let mut terms: Vec<(&PauliProduct, &CalculatorComplex)> = self.iter().collect();
+        terms.sort_by_key(|(a, _)| *a);
+        let rendered: Vec<String> = terms
+            .into_iter()
+            .map(|(key, value)| {
+                let coefficient = match (&value.re, &value.im) {
+                    (re, CalculatorFloat::Float(im)) if *im == 0.0 => float_to_string(re),
+                    (CalculatorFloat::Float(re), im) if *re == 0.0 => {
+                        format!("{}*I", float_to_string(im))
+                    }
+                    (re, im) => format!("({} + {}*I)", float_to_string(re), float_to_string(im)),
+                };
+                let mut term = coefficient;
+                for (index, pauli) in key.iter() {
+                    term.push_str(&format!("*{}Pauli('{}',{})", symbol_prefix, pauli, index));
+                }
+                term
+            })
+            .collect();
+        if rendered.is_empty() {
+            "0".to_string()
+        } else {
+            rendered.join(" + ")
+        }
+    }
+
+    /// Multiplies every term of Self from the left with a PauliProduct in place.
+    ///
+    /// Each key `P` is replaced by the product `product * P` and the resulting phase as well as `coefficient`
+    /// are multiplied into the corresponding value.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to multiply Self with from the left.
+    /// * `coefficient` - The coefficient of the PauliProduct.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn multiply_by_product_left(
+        &mut self,
+        product: &PauliProduct,
+        coefficient: CalculatorComplex,
+    ) {
+        let capacity = self.len();
+        let old_operator = std::mem::replace(self, Self::with_capacity(capacity));
+        for (key, value) in old_operator {
+            let (new_key, phase) = PauliProduct::multiply(product.clone(), key);
+            self.add_operator_product(
+                new_key,
+                CalculatorComplex::from(phase) * coefficient.clone() * value,
+            )
+            .expect("Internal bug in add_operator_product");
+        }
+    }
+
+    /// Multiplies every term of Self from the right with a PauliProduct in place.
+    ///
+    /// Each key `P` is replaced by the product `P * product` and the resulting phase as well as `coefficient`
+    /// are multiplied into the corresponding value.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to multiply Self with from the right.
+    /// * `coefficient` - The coefficient of the PauliProduct.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn multiply_by_product_right(
+        &mut self,
+        product: &PauliProduct,
+        coefficient: CalculatorComplex,
+    ) {
+        let capacity = self.len();
+        let old_operator = std::mem::replace(self, Self::with_capacity(capacity));
+        for (key, value) in old_operator {
+            let (new_key, phase) = PauliProduct::multiply(key, product.clone());
+            self.add_operator_product(
+                new_key,
+                CalculatorComplex::from(phase) * coefficient.clone() * value,
+            )
+            .expect("Internal bug in add_operator_product");
+        }
+    }
+
+    /// Returns the maximum number of spins any single term of the SpinOperator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The maximum locality of the terms (zero for an empty or pure identity operator).
+    pub fn max_locality(&self) -> usize {
+        self.keys().map(|pp| pp.len()).max().unwrap_or(0)
+    }
+
+    /// Checks that the SpinOperator only acts on the spins `0..number_spins`.
+    ///
+    /// This is a cheap check that can be used before constructing a matrix representation for a fixed number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins the SpinOperator is allowed to act on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All terms only act on spins with an index smaller than `number_spins`.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - A term acts on a spin with an index of at least `number_spins`.
+    pub fn validate_within_qubits(&self, number_spins: usize) -> Result<(), StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            Err(StruqtureError::NumberSpinsExceeded)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Truncates the SpinOperator like `truncate`, but always keeps the identity term.
+    ///
+    /// The identity term (the empty PauliProduct) is kept with its original coefficient regardless
+    /// of its magnitude, as it sets the energy reference. Only an identity term with a coefficient of exactly zero is absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated version of the SpinOperator including the identity term.
+    pub fn truncate_keep_identity(&self, threshold: f64) -> Self {
+        let mut truncated = self.truncate(threshold);
+        let identity = PauliProduct::new();
+        let value = self.get(&identity);
+        if *value != CalculatorComplex::ZERO {
+            truncated.internal_map.insert(identity, value.clone());
+        }
+        truncated
+    }
+
+    /// Reverses the order of the qubits of all terms of the SpinOperator.
+    ///
+    /// Every qubit index `i` is mapped to `number_spins - 1 - i` using `remap_qubits`,
+    /// converting between little-endian and big-endian qubit conventions.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins defining the reversal.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator with reversed qubit order.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The SpinOperator acts on more than `number_spins` spins.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn reverse_qubit_order(&self, number_spins: usize) -> Result<Self, StruqtureError> {
+        self.validate_within_qubits(number_spins)?;
+        let mapping: std::collections::HashMap<usize, usize> = (0..number_spins)
+            .map(|index| (index, number_spins - 1 - index))
+            .collect();
+        let mut reversed = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            reversed
+                .add_operator_product(key.remap_qubits(&mapping), value.clone())
+                .expect("Internal bug in add_operator_product");
+        }
+        Ok(reversed)
+    }
+
+    /// Freezes the SpinOperator into an immutable, lookup-optimized FrozenSpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `FrozenSpinOperator` - The terms of the SpinOperator stored in a sorted Vec.
+    pub fn freeze(self) -> FrozenSpinOperator {
+        FrozenSpinOperator::from(self)
+    }
+
+    /// Returns all terms of the SpinOperator that act with the given operator on the given site.
+    ///
+    /// The other sites of the PauliProducts are ignored. Searching for `SingleSpinOperator::Identity`
+    /// returns all terms that do not act on the site.
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The spin index the terms need to act on.
+    /// * `op` - The SingleSpinOperator the terms need to apply on the site.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(&PauliProduct, &CalculatorComplex)>` - The matching terms of the SpinOperator.
+    pub fn find_terms_with(
+        &self,
+        site: usize,
+        op: SingleSpinOperator,
+    ) -> Vec<(&PauliProduct, &CalculatorComplex)> {
+        self.internal_map
+            .iter()
+            .filter(|(key, _)| {
+                key.get(&site)
+                    .copied()
+                    .unwrap_or(SingleSpinOperator::Identity)
+                    == op
+            })
+            .collect()
+    }
+
+    /// Returns the expectation value of the SpinOperator in a computational basis state.
+    ///
+    /// Only the diagonal terms (consisting of Z and identity operators) contribute to the expectation value.
+    /// The state is given by its index, where bit `i` of `state_index` is the state of spin `i`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_index` - The index of the computational basis state.
+    /// * `number_spins` - The number of spins of the computational basis state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value `<state|Self|state>`.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The state index exceeds the dimension of the Hilbert space.
+    /// * `Err(StruqtureError)` - CalculatorFloat could not be converted to f64.
+    pub fn expectation_value_computational_basis(
+        &self,
+        state_index: usize,
+        number_spins: usize,
+    ) -> Result<Complex64, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        if state_index >= 2usize.pow(number_spins as u32) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "State index {} exceeds the dimension of the Hilbert space of {} spins.",
+                    state_index, number_spins
+                ),
+            });
+        }
+        let mut expectation_value = Complex64::new(0.0, 0.0);
+        for (key, value) in self.iter() {
+            let mut sign = 1.0;
+            let mut diagonal = true;
+            for (index, op) in key.iter() {
+                match op {
+                    SingleSpinOperator::Z => {
+                        if state_index.div_euclid(2usize.pow(*index as u32)) % 2 == 1 {
+                            sign *= -1.0;
+                        }
+                    }
+                    SingleSpinOperator::Identity => (),
+                    _ => {
+                        diagonal = false;
+                        break;
+                    }
+                }
+            }
+            if diagonal {
+                let real_value: f64 = value.re.clone().try_into()?;
+                let imag_value: f64 = value.im.clone().try_into()?;
+                expectation_value += Complex64::new(real_value, imag_value) * sign;
+            }
+        }
+        Ok(expectation_value)
+    }
+
+    /// Returns the variance `<O^2> - <O>^2` of the SpinOperator in a computational basis state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_index` - The index of the computational basis state.
+    /// * `number_spins` - The number of spins of the computational basis state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The variance of Self in the computational basis state.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - The state index exceeds the dimension of the Hilbert space.
+    /// * `Err(StruqtureError)` - CalculatorFloat could not be converted to f64.
+    pub fn variance_computational_basis(
+        &self,
+        state_index: usize,
+        number_spins: usize,
+    ) -> Result<Complex64, StruqtureError> {
+        let expectation_value =
+            self.expectation_value_computational_basis(state_index, number_spins)?;
+        let squared = self.clone() * self.clone();
+        let squared_expectation_value =
+            squared.expectation_value_computational_basis(state_index, number_spins)?;
+        Ok(squared_expectation_value - expectation_value * expectation_value)
+    }
+
+    /// Returns the diagonal of the matrix representation of a diagonal SpinOperator.
+    ///
+    /// The SpinOperator must only contain identity and Z Pauli operators, so that its matrix is diagonal.
+    /// The entries are computed directly from the sign of each term for every computational basis state.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the diagonal.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The `2^number_spins` diagonal entries.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Self acts on more spins than `number_spins`.
+    /// * `Err(StruqtureError::GenericError)` - Self contains X or Y Pauli operators and is not diagonal.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn diagonal_vector(&self, number_spins: usize) -> Result<Vec<Complex64>, StruqtureError> {
+        if self.current_number_spins() > number_spins {
+            return Err(StruqtureError::NumberSpinsExceeded);
+        }
+        let dimension = 2usize.pow(number_spins as u32);
+        let mut diagonal = vec![Complex64::new(0.0, 0.0); dimension];
+        for (product, value) in self.iter() {
+            let mut z_mask: usize = 0;
+            for (index, pauli) in product.iter() {
+                match pauli {
+                    SingleSpinOperator::Z => z_mask |= 1 << index,
+                    SingleSpinOperator::Identity => (),
+                    _ => {
+                        return Err(StruqtureError::GenericError {
+                            msg: format!(
+                                "SpinOperator is not diagonal, term {} contains X or Y operators.",
+                                product
+                            ),
+                        })
+                    }
+                }
+            }
+            let real: f64 = value.re.clone().try_into()?;
+            let imaginary: f64 = value.im.clone().try_into()?;
+            let coefficient = Complex64::new(real, imaginary);
+            for (state, entry) in diagonal.iter_mut().enumerate() {
+                if (state & z_mask).count_ones() % 2 == 0 {
+                    *entry += coefficient;
+                } else {
+                    *entry -= coefficient;
+                }
+            }
+        }
+        Ok(diagonal)
+    }
+
+    /// Constructs a SpinOperator from a CSV input with the columns `key, real, imag`.
+    ///
+    /// Each line contains the PauliProduct in its string representation (e.g. `0X1Z`) followed by
+    /// the real and imaginary part of its coefficient. Coefficients of duplicate keys are added up.
+    /// Empty lines and an optional header line starting with `key` are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the CSV input.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator constructed from the CSV input.
+    /// * `Err(StruqtureError::ParsingError)` - A line of the input is malformed, the error message contains the line number.
+    /// * `Err(StruqtureError::GenericError)` - The input could not be read.
+    pub fn from_csv_reader<R: std::io::Read>(reader: R) -> Result<Self, StruqtureError> {
+        let parsing_error = |line_number: usize, msg: String| StruqtureError::ParsingError {
+            target_type: "SpinOperator".to_string(),
+            msg: format!("Line {}: {}", line_number, msg),
+        };
+        let mut operator = Self::new();
+        for (index, line) in std::io::BufRead::lines(std::io::BufReader::new(reader)).enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|err| StruqtureError::GenericError {
+                msg: format!("Could not read line {}: {}", line_number, err),
+            })?;
+            let fields: Vec<&str> = line.split(',').map(|field| field.trim()).collect();
+            if fields.iter().all(|field| field.is_empty()) || (index == 0 && fields[0] == "key") {
+                continue;
+            }
+            if fields.len() != 3 {
+                return Err(parsing_error(
+                    line_number,
+                    format!("Expected 3 columns, found {}.", fields.len()),
+                ));
+            }
+            let key = PauliProduct::from_str(fields[0])
+                .map_err(|err| parsing_error(line_number, format!("{}", err)))?;
+            let real: f64 = fields[1].parse().map_err(|_| {
+                parsing_error(line_number, format!("Invalid real part {}.", fields[1]))
+            })?;
+            let imag: f64 = fields[2].parse().map_err(|_| {
+                parsing_error(
+                    line_number,
+                    format!("Invalid imaginary part {}.", fields[2]),
+                )
+            })?;
+            operator.add_operator_product(key, CalculatorComplex::new(real, imag))?;
+        }
+        Ok(operator)
+    }
+
+    /// Exports the SpinOperator to CSV with the columns `key, real, imag`.
+    ///
+    /// The output starts with the header line `key,real,imag` and contains one line per term,
+    /// sorted by PauliProduct. The output can be read back with `from_csv_reader`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The SpinOperator in CSV format.
+    /// * `Err(StruqtureError)` - A coefficient is symbolic and can not be converted to f64.
+    pub fn to_csv(&self) -> Result<String, StruqtureError> {
+        let mut terms: Vec<(&PauliProduct, &CalculatorComplex)> = self.iter().collect();
+        terms.sort_by_key(|(a, _)| *a);
+        let mut output = String::from("key,real,imag\n");
+        for (key, value) in terms {
+            let real: f64 = value.re.clone().try_into()?;
+            let imag: f64 = value.im.clone().try_into()?;
+            writeln!(output, "{},{},{}", key, real, imag).map_err(|err| {
+                StruqtureError::GenericError {
+                    msg: format!("{}", err),
+                }
+            })?;
+        }
+        Ok(output)
+    }
+
+    /// Returns the commutator `[self, other] = self * other - other * self` of two SpinOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The commutator of the two SpinOperators.
+    pub fn commutator(&self, other: &Self) -> Self {
+        self.clone() * other.clone() - other.clone() * self.clone()
+    }
+
+    /// Returns the anticommutator `{self, other} = self * other + other * self` of two SpinOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to anticommute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anticommutator of the two SpinOperators.
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        self.clone() * other.clone() + other.clone() * self.clone()
+    }
+
+    /// Partitions the terms of the SpinOperator into groups of commuting PauliProducts.
+    ///
+    /// Depending on the strategy, the PauliProducts in each group commute qubit-wise or as operators.
+    /// The sum of all groups is the original SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The grouping strategy, see [GroupingStrategy].
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SpinOperator>` - The groups of commuting terms.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn group_commuting_terms(&self, strategy: GroupingStrategy) -> Vec<SpinOperator> {
+        let terms: Vec<(&PauliProduct, &CalculatorComplex)> = self.internal_map.iter().collect();
+        let products: Vec<&PauliProduct> = terms.iter().map(|(product, _)| *product).collect();
+        group_commuting_products(&products, strategy)
+            .into_iter()
+            .map(|group| {
+                let mut operator = SpinOperator::with_capacity(group.len());
+                for position in group {
+                    let (product, value) = terms[position];
+                    operator
+                        .add_operator_product(product.clone(), value.clone())
+                        .expect("Internal bug in add_operator_product");
+                }
+                operator
+            })
+            .collect()
+    }
+
+    /// Returns the sum `self ⊗ I + I ⊗ other` of two SpinOperators acting on disjoint sets of spins.
+    ///
+    /// The spin indices of `other` are shifted by `offset` and the shifted terms are added to the terms of `self`,
+    /// which composes the operator of a larger system from the operators of two subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator acting on the second subsystem.
+    /// * `offset` - The shift applied to the spin indices of `other`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator acting on the combined system.
+    /// * `Err(StruqtureError::GenericError)` - The shifted spin indices of `other` overlap with the spin indices of `self`.
+    pub fn tensor(&self, other: &Self, offset: usize) -> Result<Self, StruqtureError> {
+        if other.current_number_spins() > 0 && offset < self.current_number_spins() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The offset {} is smaller than the number of spins {} of the first SpinOperator, the spin indices overlap.",
+                    offset,
+                    self.current_number_spins()
+                ),
+            });
+        }
+        let shifted = other.clone().convert_keys(|product| {
+            product
+                .iter()
+                .map(|(index, pauli)| (index + offset, *pauli))
+                .collect()
+        })?;
+        let mut combined = self.clone();
+        for (key, value) in shifted {
+            combined.add_operator_product(key, value)?;
+        }
+        Ok(combined)
+    }
+
+    /// Returns the truncated Taylor series of the exponential of the SpinOperator.
+    ///
+    /// Computes `exp(self) = sum_{k=0}^{order} self^k / k!`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The highest power of the SpinOperator included in the series.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated exponential of the SpinOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    pub fn exp_approx(&self, order: usize) -> Self {
+        let mut term = Self::new();
+        term.add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+            .expect("Internal bug in add_operator_product.");
+        let mut exponential = term.clone();
+        for k in 1..=order {
+            term = term * self.clone() * (1.0 / k as f64);
+            exponential += term.clone();
+        }
+        exponential
+    }
+
+    /// Transforms the SpinOperator with the exponential of a generator using the truncated Baker-Campbell-Hausdorff expansion.
+    ///
+    /// Computes `exp(generator) * self * exp(-generator) = sum_{k=0}^{order} [generator, self]_k / k!`,
+    /// where `[generator, self]_k` denotes the k-fold nested commutator.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The generator A of the transformation e^{A} B e^{-A}.
+    /// * `order` - The highest number of nested commutators included in the expansion.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The transformed SpinOperator.
+    pub fn heisenberg_transform(&self, generator: &Self, order: usize) -> Self {
+        let mut term = self.clone();
+        let mut transformed = self.clone();
+        for k in 1..=order {
+            term = generator.commutator(&term) * (1.0 / k as f64);
+            if term.is_empty() {
+                break;
+            }
+            transformed += term.clone();
+        }
+        transformed
+    }
 }
 
 impl From<SpinHamiltonian> for SpinOperator {
@@ -477,6 +1323,73 @@ where
     }
 }
 
+/// Implements the in-place plus function of SpinOperator by SpinOperator.
+///
+impl<T, V> ops::AddAssign<T> for SpinOperator
+where
+    T: IntoIterator<Item = (PauliProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    /// Implements `+=` (add in place) for two SpinOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to be added.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add_assign(&mut self, other: T) {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place minus function of SpinOperator by SpinOperator.
+///
+impl<T, V> ops::SubAssign<T> for SpinOperator
+where
+    T: IntoIterator<Item = (PauliProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    /// Implements `-=` (subtract in place) for two SpinOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinOperator to be subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub_assign(&mut self, other: T) {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the in-place multiplication function of SpinOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::MulAssign<T> for SpinOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    /// Implement `*=` (multiply in place) for SpinOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    fn mul_assign(&mut self, other: T) {
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        for value in self.internal_map.values_mut() {
+            *value = value.clone() * other_cc.clone();
+        }
+    }
+}
+
 /// Implements the multiplication function of SpinOperator by SpinOperator.
 ///
 impl ops::Mul<SpinOperator> for SpinOperator {
@@ -687,7 +1600,7 @@ impl JordanWignerSpinToFermion for SpinOperator {
     fn jordan_wigner(&self) -> Self::Output {
         let mut out = FermionOperator::new();
         for pp in self.keys() {
-            out = out + pp.jordan_wigner() * self.get(pp);
+            out += pp.jordan_wigner() * self.get(pp);
         }
         out
     }
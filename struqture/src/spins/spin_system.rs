@@ -205,6 +205,11 @@ impl OperateOnSpins<'_> for SpinSystem {
 
 impl ToSparseMatrixOperator<'_> for SpinSystem {}
 impl<'a> ToSparseMatrixSuperOperator<'a> for SpinSystem {
+    // From trait
+    fn has_no_terms(&'a self) -> bool {
+        self.is_empty()
+    }
+
     // From trait
     fn sparse_matrix_superoperator_entries_on_row(
         &'a self,
@@ -220,7 +225,7 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinSystem {
 
     // From trait
     fn unitary_sparse_matrix_coo(&'a self) -> Result<CooSparseMatrix, StruqtureError> {
-        self.operator.sparse_matrix_coo(self.number_spins)
+        self.operator.sparse_matrix_coo(Some(self.number_spins()))
     }
 
     // From trait
@@ -270,6 +275,13 @@ impl SpinSystem {
         }
     }
 
+    /// Removes all terms from the SpinSystem while keeping the number of spins and the allocated memory.
+    ///
+    /// In contrast to `empty_clone` no new object is allocated.
+    pub fn clear(&mut self) {
+        self.operator.clear();
+    }
+
     /// Returns the SpinOperator of the SpinSystem.
     ///
     /// # Returns
@@ -336,6 +348,46 @@ impl SpinSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Writes the SpinSystem into an HDF5 group.
+    ///
+    /// The operator of the SpinSystem is written with [SpinOperator::to_hdf5], the `number_spins` attribute
+    /// is only written when the number spins of the SpinSystem is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to write the SpinSystem to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The SpinSystem has been written to the group.
+    /// * `Err(StruqtureError::GenericError)` - Writing to the group failed.
+    /// * `Err(CalculatorError)` - A coefficient is symbolic, only numeric coefficients can be written.
+    #[cfg(feature = "hdf5")]
+    pub fn to_hdf5(&self, group: &hdf5::Group) -> Result<(), StruqtureError> {
+        self.operator.to_hdf5(group)?;
+        crate::hdf5_io::write_optional_number(group, "number_spins", self.number_spins)
+    }
+
+    /// Reads a SpinSystem from an HDF5 group written with [SpinSystem::to_hdf5].
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The HDF5 group to read the SpinSystem from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinSystem stored in the group.
+    /// * `Err(StruqtureError::VersionMissmatch)` - The data was written by an incompatible version of struqture.
+    /// * `Err(StruqtureError::GenericError)` - Reading from the group failed.
+    /// * `Err(StruqtureError)` - The operator could not be read or does not fit into the number spins.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5(group: &hdf5::Group) -> Result<Self, StruqtureError> {
+        Self::from_operator(
+            SpinOperator::from_hdf5(group)?,
+            crate::hdf5_io::read_optional_number(group, "number_spins")?,
+        )
+    }
 }
 
 /// Implements the negative sign function of SpinSystem.
@@ -422,7 +474,7 @@ where
     ///
     /// * `Self` - The SpinSystem multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(mut self, other: T) -> Self {
-        self.operator = self.operator * other;
+        self.operator *= other;
         self
     }
 }
@@ -0,0 +1,164 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::spins::{PauliProduct, SpinHamiltonian};
+use crate::{OperateOnDensityMatrix, StruqtureError};
+
+/// Ordered sequence of Pauli rotations approximating the time evolution under a SpinHamiltonian.
+///
+/// Each entry `(P, angle)` represents the rotation `exp(-i * angle * P)`.
+/// The rotations are applied in the order of the sequence, the first entry acts first on the state.
+///
+/// # Example
+///
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonian, TrotterSchedule};
+///
+/// let mut hamiltonian = SpinHamiltonian::new();
+/// hamiltonian.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5)).unwrap();
+/// hamiltonian.add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.2)).unwrap();
+///
+/// let schedule = TrotterSchedule::from_hamiltonian(&hamiltonian, 1.0, 10, 1).unwrap();
+/// assert_eq!(schedule.len(), 20);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrotterSchedule {
+    // The ordered rotations (PauliProduct, rotation angle)
+    rotations: Vec<(PauliProduct, f64)>,
+}
+
+/// Functions for the TrotterSchedule
+///
+impl TrotterSchedule {
+    /// Creates the Trotter-Suzuki decomposition of `exp(-i * H * time)` for a SpinHamiltonian H.
+    ///
+    /// The time is divided into `steps` steps of length `dt = time / steps`.
+    /// For the first order decomposition each step applies `exp(-i * c_k * dt * P_k)` for all terms `c_k * P_k` of H.
+    /// For the second order decomposition each step uses the symmetric splitting, applying all terms
+    /// with half the step length in forward and then in backward order. The two half steps of the
+    /// last term are merged into a single rotation.
+    /// The terms are ordered by their PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The SpinHamiltonian H generating the time evolution.
+    /// * `time` - The total evolution time.
+    /// * `steps` - The number of Trotter steps.
+    /// * `order` - The order of the Trotter-Suzuki decomposition (1 or 2).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The TrotterSchedule of the time evolution.
+    /// * `Err(StruqtureError::GenericError)` - The order is not 1 or 2 or the number of steps is zero.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn from_hamiltonian(
+        hamiltonian: &SpinHamiltonian,
+        time: f64,
+        steps: usize,
+        order: usize,
+    ) -> Result<Self, StruqtureError> {
+        if steps == 0 {
+            return Err(StruqtureError::GenericError {
+                msg: "The number of Trotter steps needs to be at least one.".to_string(),
+            });
+        }
+        if order != 1 && order != 2 {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Only Trotter-Suzuki decompositions of order 1 and 2 are supported, got order {}.",
+                    order
+                ),
+            });
+        }
+        let dt = time / steps as f64;
+        let mut terms: Vec<(PauliProduct, f64)> = Vec::with_capacity(hamiltonian.len());
+        for (product, coefficient) in hamiltonian.iter() {
+            let coefficient: f64 = coefficient.clone().try_into()?;
+            terms.push((product.clone(), coefficient));
+        }
+        terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let rotations_per_step = if order == 1 || terms.is_empty() {
+            terms.len()
+        } else {
+            2 * terms.len() - 1
+        };
+        let mut rotations: Vec<(PauliProduct, f64)> =
+            Vec::with_capacity(steps * rotations_per_step);
+        for _ in 0..steps {
+            if order == 1 {
+                rotations.extend(
+                    terms
+                        .iter()
+                        .map(|(product, coefficient)| (product.clone(), coefficient * dt)),
+                );
+            } else if let Some(((last_product, last_coefficient), forward)) = terms.split_last() {
+                rotations.extend(
+                    forward
+                        .iter()
+                        .map(|(product, coefficient)| (product.clone(), coefficient * dt / 2.0)),
+                );
+                rotations.push((last_product.clone(), last_coefficient * dt));
+                rotations.extend(
+                    forward
+                        .iter()
+                        .rev()
+                        .map(|(product, coefficient)| (product.clone(), coefficient * dt / 2.0)),
+                );
+            }
+        }
+        Ok(Self { rotations })
+    }
+
+    /// Returns the ordered rotations of the TrotterSchedule.
+    ///
+    /// # Returns
+    ///
+    /// * `&[(PauliProduct, f64)]` - The rotations `(P, angle)` representing `exp(-i * angle * P)`.
+    pub fn rotations(&self) -> &[(PauliProduct, f64)] {
+        &self.rotations
+    }
+
+    /// Returns the number of rotations in the TrotterSchedule.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of rotations.
+    pub fn len(&self) -> usize {
+        self.rotations.len()
+    }
+
+    /// Returns true if the TrotterSchedule contains no rotations.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the TrotterSchedule is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.rotations.is_empty()
+    }
+}
+
+impl IntoIterator for TrotterSchedule {
+    type Item = (PauliProduct, f64);
+    type IntoIter = std::vec::IntoIter<(PauliProduct, f64)>;
+    /// Returns the ordered rotations of the TrotterSchedule in IntoIterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The ordered rotations in IntoIterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.rotations.into_iter()
+    }
+}
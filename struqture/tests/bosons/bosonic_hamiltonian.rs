@@ -13,13 +13,20 @@
 //! Integration test for public API of BosonHamiltonian
 
 use bincode::{deserialize, serialize};
+use nalgebra as na;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::iter::{FromIterator, IntoIterator};
-use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
+use struqture::bosons::{
+    BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct,
+    ToSparseMatrixBosonOperator,
+};
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+    SymmetricIndex,
 };
 use test_case::test_case;
 
@@ -65,6 +72,16 @@ fn internal_map_len() {
     so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the BosonHamiltonian
+#[test]
+fn number_of_terms() {
+    let pp_2: HermitianBosonProduct = HermitianBosonProduct::new([0], [2]).unwrap();
+    let mut so = BosonHamiltonian::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -400,6 +417,108 @@ fn from_operator_fail() {
     );
 }
 
+// Test the LaTeX representation of the BosonHamiltonian including the hermitian conjugate terms
+#[test]
+fn to_latex() {
+    let mut bh = BosonHamiltonian::new();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([], []).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    assert_eq!(
+        bh.to_latex(),
+        r"0.5 \mathbb{1} + \hat{a}^\dagger_{0} \hat{a}_{1} + \hat{a}^\dagger_{1} \hat{a}_{0}"
+    );
+}
+
+// Test the sparse matrix functions of the ToSparseMatrixBosonOperator trait for the BosonHamiltonian
+#[test]
+fn sparse_matrix_fock_space() {
+    let mut bh = BosonHamiltonian::new();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+
+    let cutoffs = [2, 2];
+    let mut expected: HashMap<(usize, usize), Complex64> = HashMap::new();
+    // number operator on mode 0 (basis index n_0 + 2 * n_1)
+    expected.insert((1, 1), Complex64::new(1.0, 0.0));
+    expected.insert((3, 3), Complex64::new(1.0, 0.0));
+    // hopping between the modes
+    expected.insert((1, 2), Complex64::new(0.5, 0.0));
+    expected.insert((2, 1), Complex64::new(0.5, 0.0));
+    assert_eq!(bh.sparse_matrix(&cutoffs).unwrap(), expected);
+    assert_eq!(
+        ToSparseMatrixBosonOperator::sparse_matrix_coo(&bh, &cutoffs).unwrap(),
+        BosonOperator::from(bh.clone())
+            .sparse_matrix_coo(&cutoffs)
+            .unwrap()
+    );
+
+    assert_eq!(
+        bh.sparse_matrix_coo(&[2]),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+    assert!(bh.sparse_matrix_coo(&[2, 0]).is_err());
+}
+
+// Test the sparse_matrix_superoperator_coo function of the BosonHamiltonian
+#[test]
+fn sparse_matrix_superoperator_fock_space() {
+    let mut bh = BosonHamiltonian::new();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    // flatten(-i [H, p]) for H = 2 n, the entry (a, b) of p has index 2 * a + b
+    let expected = (
+        vec![Complex64::new(0.0, 2.0), Complex64::new(0.0, -2.0)],
+        (vec![1, 2], vec![1, 2]),
+    );
+    assert_eq!(bh.sparse_matrix_superoperator_coo(&[2]).unwrap(), expected);
+}
+
+// Test that the sparse matrices of the BosonHamiltonian return an error if the Fock space dimension overflows
+#[test]
+fn sparse_matrix_fock_space_dimension_overflow() {
+    let mut bh = BosonHamiltonian::new();
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        bh.sparse_matrix_coo(&[usize::MAX, 2]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        bh.sparse_matrix_superoperator_coo(&[usize::MAX, 2]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    // The dimension of the Fock space fits into usize, the dimension of the space of density matrices does not
+    let cutoff = 1usize << (usize::BITS / 2);
+    assert!(matches!(
+        bh.sparse_matrix_superoperator_coo(&[cutoff, 1]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
 // Test the Iter traits of BosonHamiltonian: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -601,6 +720,47 @@ fn serde_compact() {
     );
 }
 
+// Test the driven_oscillator constructor against the analytic Fock space matrix
+#[test_case(1.0, 0.5, 4; "cutoff_4")]
+#[test_case(2.0, -0.3, 6; "cutoff_6")]
+fn driven_oscillator(frequency: f64, drive: f64, cutoff: usize) {
+    let hamiltonian =
+        BosonHamiltonian::driven_oscillator(0, frequency.into(), drive.into()).unwrap();
+    assert_eq!(hamiltonian.len(), 2);
+
+    let mut annihilator = na::DMatrix::<f64>::zeros(cutoff, cutoff);
+    for n in 1..cutoff {
+        annihilator[(n - 1, n)] = (n as f64).sqrt();
+    }
+    let creator = annihilator.transpose();
+    let mut matrix = na::DMatrix::<f64>::zeros(cutoff, cutoff);
+    for (key, value) in hamiltonian.iter() {
+        let mut term = na::DMatrix::<f64>::identity(cutoff, cutoff);
+        for _ in key.creators() {
+            term *= &creator;
+        }
+        for _ in key.annihilators() {
+            term *= &annihilator;
+        }
+        let prefactor = *value.re.float().unwrap();
+        if key.is_natural_hermitian() {
+            matrix += term * prefactor;
+        } else {
+            matrix += (term.clone() + term.transpose()) * prefactor;
+        }
+    }
+
+    let mut expected = na::DMatrix::<f64>::zeros(cutoff, cutoff);
+    for n in 0..cutoff {
+        expected[(n, n)] = frequency * n as f64;
+        if n + 1 < cutoff {
+            expected[(n, n + 1)] = drive * ((n + 1) as f64).sqrt();
+            expected[(n + 1, n)] = drive * ((n + 1) as f64).sqrt();
+        }
+    }
+    assert!((matrix - expected).abs().max() < 1e-12);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_hamiltonian_schema() {
@@ -77,6 +77,17 @@ fn internal_map_len() {
         .unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the BosonLindbladNoiseOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: BosonProduct = BosonProduct::new([0], [2]).unwrap();
+    let mut so = BosonLindbladNoiseOperator::new();
+    so.set((pp_2.clone(), pp_2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -430,6 +441,7 @@ fn serde_json() {
 
 /// Test SpinOperator Serialization and Deserialization traits (readable)
 #[test]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
     let major_version = MINIMUM_STRUQTURE_VERSION.0;
@@ -478,6 +478,7 @@ fn serde_json() {
 
 /// Test SpinOperator Serialization and Deserialization traits (readable)
 #[test]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
     let major_version = MINIMUM_STRUQTURE_VERSION.0;
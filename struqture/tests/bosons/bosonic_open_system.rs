@@ -12,6 +12,7 @@
 
 //! Integration test for public API of BosonLindbladOpenSystem
 
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -46,6 +47,45 @@ fn new_system_none() {
     assert_eq!(system.current_number_modes(), 0_usize);
 }
 
+// Test the sparse_matrix_superoperator_coo function of the BosonLindbladOpenSystem
+#[test]
+fn sparse_matrix_superoperator_coo() {
+    let mut system = BosonLindbladOpenSystem::new(None);
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                BosonProduct::new([], [0]).unwrap(),
+                BosonProduct::new([], [0]).unwrap(),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    // Damped oscillator truncated to one boson, the entry (a, b) of p has index 2 * a + b
+    let expected = (
+        vec![
+            Complex64::new(0.5, 0.0),
+            Complex64::new(-0.25, 1.0),
+            Complex64::new(-0.25, -1.0),
+            Complex64::new(-0.5, 0.0),
+        ],
+        (vec![0, 1, 2, 3], vec![3, 1, 2, 3]),
+    );
+    assert_eq!(
+        system.sparse_matrix_superoperator_coo(&[2]).unwrap(),
+        expected
+    );
+    assert!(system.sparse_matrix_superoperator_coo(&[]).is_err());
+}
+
 // Test the group function of the BosonLindbladOpenSystem
 #[test]
 fn group() {
@@ -472,6 +512,36 @@ fn clone_partial_eq() {
     assert!(slos != slos_2);
 }
 
+// Test the to_hdf5 and from_hdf5 functions of the BosonLindbladOpenSystem
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut system = BosonLindbladOpenSystem::new(None);
+    system
+        .system_mut()
+        .set(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.4, 0.1),
+        )
+        .unwrap();
+    let dp: BosonProduct = BosonProduct::new([0], [0]).unwrap();
+    system
+        .noise_mut()
+        .set((dp.clone(), dp), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("boson_open_system_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("boson_open_system").unwrap();
+        system.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("boson_open_system").unwrap();
+    let read = BosonLindbladOpenSystem::from_hdf5(&group).unwrap();
+    assert_eq!(read, system);
+}
+
 /// Test BosonLindbladOpenSystem Serialization and Deserialization traits (readable)
 #[test]
 fn serde_json() {
@@ -491,6 +561,7 @@ fn serde_json() {
 }
 
 #[test]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
     let major_version = MINIMUM_STRUQTURE_VERSION.0;
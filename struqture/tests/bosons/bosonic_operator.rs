@@ -13,12 +13,16 @@
 //! Integration test for public API of BosonOperator
 
 use bincode::{deserialize, serialize};
+use nalgebra as na;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
-use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState};
+use struqture::{
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+};
 use test_case::test_case;
 
 // Test the new function of the BosonOperator
@@ -30,6 +34,18 @@ fn new() {
     assert_eq!(BosonOperator::with_capacity(2), BosonOperator::new());
 }
 
+// Test that the BosonOperator accepts BosonProducts acting on arbitrarily high modes
+#[test]
+fn unbounded_number_modes() {
+    let mut unbounded = BosonOperator::new();
+    let bp_high = BosonProduct::new([100], [1000]).unwrap();
+    unbounded
+        .set(bp_high.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(unbounded.get(&bp_high), &CalculatorComplex::from(0.5));
+    assert_eq!(unbounded.number_modes(), 1001);
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_2: BosonProduct = BosonProduct::new([0], [2]).unwrap();
@@ -63,6 +79,16 @@ fn internal_map_len() {
     so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the BosonOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: BosonProduct = BosonProduct::new([0], [2]).unwrap();
+    let mut so = BosonOperator::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -247,6 +273,89 @@ fn mul_so_so_same_index() {
     assert_eq!(so_0 * so_1, so_0_1);
 }
 
+// Test the commutator and anticommutator functions of the BosonOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut annihilator = BosonOperator::new();
+    annihilator
+        .add_operator_product(
+            BosonProduct::new([], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut creator = BosonOperator::new();
+    creator
+        .add_operator_product(
+            BosonProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    let mut expected_commutator = BosonOperator::new();
+    expected_commutator
+        .add_operator_product(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(annihilator.commutator(&creator), expected_commutator);
+
+    let mut expected_anticommutator = BosonOperator::new();
+    expected_anticommutator
+        .add_operator_product(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected_anticommutator
+        .add_operator_product(
+            BosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert_eq!(
+        annihilator.anticommutator(&creator),
+        expected_anticommutator
+    );
+}
+
+// Test the tensor function of the BosonOperator
+#[test]
+fn tensor() {
+    let mut number = BosonOperator::new();
+    number
+        .add_operator_product(
+            BosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    let mut annihilator = BosonOperator::new();
+    annihilator
+        .add_operator_product(
+            BosonProduct::new([], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+
+    let mut expected = BosonOperator::new();
+    expected
+        .add_operator_product(
+            BosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            BosonProduct::new([], [2]).unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+    assert_eq!(number.tensor(&annihilator, 1).unwrap(), expected);
+
+    let error = number.tensor(&annihilator, 0);
+    assert!(matches!(error, Err(StruqtureError::GenericError { .. })));
+}
+
 // Test the multiplication: BosonOperator * Calculatorcomplex
 #[test]
 fn mul_so_cc() {
@@ -292,6 +401,35 @@ fn from_operator_pass(creators: &[usize], annihilators: &[usize]) {
     assert_eq!(BosonOperator::from(so_0), so_0_1);
 }
 
+// Test the max_mode_locality function of the BosonOperator
+#[test]
+fn max_mode_locality() {
+    let mut so = BosonOperator::new();
+    assert_eq!(so.max_mode_locality(), 0);
+    so.add_operator_product(
+        BosonProduct::new([], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(so.max_mode_locality(), 0);
+    so.add_operator_product(
+        BosonProduct::new([0], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        BosonProduct::new([0, 2], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(so.max_mode_locality(), 3);
+}
+
 // Test the separation of terms
 #[test_case((1, 1))]
 #[test_case((1, 2))]
@@ -371,6 +509,139 @@ fn separate_out_terms(number_spins: (usize, usize)) {
     assert_eq!(result.1, remainder);
 }
 
+// Test the sparse_matrix_coo_uniform_cutoff function of the BosonOperator on a hopping term
+#[test]
+fn sparse_matrix_coo_uniform_cutoff() {
+    let mut bo = BosonOperator::new();
+    bo.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([1], [0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+
+    // Truncated annihilation operator for cutoff 3, mode 1 is the leftmost Kronecker factor
+    let c = |re: f64| Complex64::new(re, 0.0);
+    let annihilator = na::DMatrix::from_row_slice(
+        3,
+        3,
+        &[
+            c(0.0),
+            c(1.0),
+            c(0.0),
+            c(0.0),
+            c(0.0),
+            c(2.0_f64.sqrt()),
+            c(0.0),
+            c(0.0),
+            c(0.0),
+        ],
+    );
+    let creator = annihilator.adjoint();
+    let expected = annihilator.kronecker(&creator) + creator.kronecker(&annihilator);
+
+    let (values, (rows, columns)) = bo.sparse_matrix_coo_uniform_cutoff(3).unwrap();
+    let mut matrix = na::DMatrix::<Complex64>::zeros(9, 9);
+    for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+        matrix[(row, column)] += value;
+    }
+    assert!((matrix - expected).norm() < 1e-12);
+    assert_eq!(
+        bo.sparse_matrix_coo_uniform_cutoff(3),
+        bo.sparse_matrix_coo(&[3, 3])
+    );
+
+    assert_eq!(
+        bo.sparse_matrix_coo(&[3]),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+    assert!(matches!(
+        bo.sparse_matrix_coo_uniform_cutoff(0),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the LaTeX representation of the BosonOperator
+#[test]
+fn to_latex() {
+    let mut bo = BosonOperator::new();
+    assert_eq!(bo.to_latex(), "0");
+    bo.add_operator_product(
+        BosonProduct::new([], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(bo.to_latex(), r"\mathbb{1}");
+    bo.add_operator_product(
+        BosonProduct::new([0, 0], [2]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(-0.5),
+    )
+    .unwrap();
+    assert_eq!(
+        bo.to_latex(),
+        r"\mathbb{1} - 0.5 \hat{a}^\dagger_{0} \hat{a}_{1} + 2 \hat{a}^\dagger_{0} \hat{a}^\dagger_{0} \hat{a}_{2}"
+    );
+
+    let mut negative_identity = BosonOperator::new();
+    negative_identity
+        .add_operator_product(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(-1.0),
+        )
+        .unwrap();
+    assert_eq!(negative_identity.to_latex(), r"-\mathbb{1}");
+}
+
+// Test the in-place arithmetic operators of BosonOperator: +=, -= and *=
+#[test]
+fn assign_operators() {
+    let mut a = BosonOperator::new();
+    a.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut b = BosonOperator::new();
+    b.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    b.add_operator_product(
+        BosonProduct::new([2], []).unwrap(),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+
+    let mut added = a.clone();
+    added += b.clone();
+    assert_eq!(added, a.clone() + b.clone());
+
+    let mut subtracted = a.clone();
+    subtracted -= b.clone();
+    assert_eq!(subtracted, a.clone() - b.clone());
+
+    let mut scaled = a.clone();
+    scaled *= CalculatorComplex::new(0.0, 2.0);
+    assert_eq!(scaled, a.clone() * CalculatorComplex::new(0.0, 2.0));
+    let mut scaled = a.clone();
+    scaled *= CalculatorFloat::from(3.0);
+    assert_eq!(scaled, a.clone() * CalculatorFloat::from(3.0));
+
+    let mut cancelled = a.clone();
+    cancelled -= a.clone();
+    assert!(cancelled.is_empty());
+}
+
 // Test the Iter traits of BosonOperator: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -461,6 +732,7 @@ fn serde_json() {
 
 /// Test SpinOperator Serialization and Deserialization traits (readable)
 #[test]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
     let major_version = MINIMUM_STRUQTURE_VERSION.0;
@@ -585,3 +857,90 @@ fn test_boson_operator_schema() {
 
     assert!(validation.is_ok());
 }
+
+// Test the normal_ordered function of the BosonOperator
+#[test]
+fn normal_ordered() {
+    // b_0 b_0^dagger = 1 + b_0^dagger b_0
+    let mut expected = BosonOperator::new();
+    expected
+        .add_operator_product(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            BosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        BosonOperator::normal_ordered(&[(0, false), (0, true)]),
+        expected
+    );
+
+    // b_1 b_0^dagger = b_0^dagger b_1
+    let mut expected = BosonOperator::new();
+    expected
+        .add_operator_product(
+            BosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        BosonOperator::normal_ordered(&[(1, false), (0, true)]),
+        expected
+    );
+}
+
+// Test the wick_expectation function of the BosonOperator
+#[cfg(feature = "linalg")]
+#[test]
+fn wick_expectation() {
+    let mut bo = BosonOperator::new();
+    bo.add_operator_product(
+        BosonProduct::new([0, 0], [0, 0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([0, 1], [0, 1]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([1], []).unwrap(),
+        CalculatorComplex::from(3.0),
+    )
+    .unwrap();
+    let one_body_dm = ndarray::array![
+        [Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(2.0, 0.0)]
+    ];
+    // <b_0^dagger b_0^dagger b_0 b_0> = 2 n_0^2 and <b_0^dagger b_1^dagger b_0 b_1> = n_0 n_1
+    let expected = Complex64::new(2.0 * 0.25 + 2.0 * 1.0, 0.0);
+    assert!((bo.wick_expectation(&one_body_dm).unwrap() - expected).norm() < 1e-10);
+
+    let not_square = ndarray::Array2::<Complex64>::zeros((2, 3));
+    assert!(matches!(
+        bo.wick_expectation(&not_square),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the min_supported_version_for_content function of the BosonOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut bo = BosonOperator::new();
+    assert_eq!(bo.min_supported_version_for_content(), (1, 0, 0));
+    bo.set(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    #[cfg(feature = "compact_keys")]
+    assert_eq!(bo.min_supported_version_for_content(), (1, 10, 0));
+    #[cfg(not(feature = "compact_keys"))]
+    assert_eq!(bo.min_supported_version_for_content(), (1, 0, 0));
+}
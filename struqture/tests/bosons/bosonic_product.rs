@@ -21,7 +21,7 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use struqture::bosons::*;
 use struqture::prelude::*;
-use struqture::{CorrespondsTo, GetValue, StruqtureError};
+use struqture::{CorrespondsTo, GetValue, MinSupportedVersion, StruqtureError};
 use test_case::test_case;
 use tinyvec::tiny_vec;
 use tinyvec::TinyVec;
@@ -218,6 +218,7 @@ fn test_serde_json(creators: &[usize], annihilators: &[usize]) {
 #[test_case(&[0,1,1], &[3,3,5], Token::Str("c0c1c1a3a3a5"); "0,1,1 - 3,3,5")]
 #[test_case( &[1,2], &[1,2], Token::Str("c1c2a1a2"); "2,1 - 1,2")]
 #[test_case( &[0], &[0, 30], Token::Str("c0a0a30"); "0 - 0,30")]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable(creators: &[usize], annihilators: &[usize], result: Token) {
     let creators = creators.to_vec();
     let annihilators = annihilators.to_vec();
@@ -226,6 +227,64 @@ fn serde_readable(creators: &[usize], annihilators: &[usize], result: Token) {
     assert_tokens(&test.readable(), &[result]);
 }
 
+// Test that the string and the compact integer representation can both be deserialized
+#[test_case( &[], &[], "\"I\"", "{\"c\":[],\"a\":[]}"; "empty")]
+#[test_case(&[0], &[1], "\"c0a1\"", "{\"c\":[0],\"a\":[1]}"; "0 - 1")]
+#[test_case( &[], &[2000], "\"a2000\"", "{\"c\":[],\"a\":[2000]}"; "empty - 2000")]
+#[test_case(&[0,1,1], &[3,3,5], "\"c0c1c1a3a3a5\"", "{\"c\":[0,1,1],\"a\":[3,3,5]}"; "0,1,1 - 3,3,5")]
+fn serde_json_string_and_compact(
+    creators: &[usize],
+    annihilators: &[usize],
+    string_form: &str,
+    compact_form: &str,
+) {
+    let test = BosonProduct::new(creators.to_vec(), annihilators.to_vec()).unwrap();
+    let from_string: BosonProduct = serde_json::from_str(string_form).unwrap();
+    assert_eq!(from_string, test);
+    let from_compact: BosonProduct = serde_json::from_str(compact_form).unwrap();
+    assert_eq!(from_compact, test);
+    // Keys in the compact form do not need to be in a fixed order
+    let reordered = format!(
+        "{{\"a\":{:?},\"c\":{:?}}}",
+        annihilators.to_vec(),
+        creators.to_vec()
+    );
+    let from_reordered: BosonProduct = serde_json::from_str(&reordered).unwrap();
+    assert_eq!(from_reordered, test);
+
+    let serialized = serde_json::to_string(&test).unwrap();
+    #[cfg(feature = "compact_keys")]
+    assert_eq!(serialized, compact_form.to_string());
+    #[cfg(not(feature = "compact_keys"))]
+    assert_eq!(serialized, string_form.to_string());
+
+    assert!(serde_json::from_str::<BosonProduct>("{\"c\":[0]}").is_err());
+}
+
+#[cfg(feature = "compact_keys")]
+#[test]
+fn serde_readable_compact_keys() {
+    let test = BosonProduct::new([0], [1]).unwrap();
+    assert_tokens(
+        &test.readable(),
+        &[
+            Token::Struct {
+                name: "BosonProductCompact",
+                len: 2,
+            },
+            Token::Str("c"),
+            Token::Seq { len: Some(1) },
+            Token::U64(0),
+            Token::SeqEnd,
+            Token::Str("a"),
+            Token::Seq { len: Some(1) },
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
 #[test_case( &[], &[]; "empty")]
 #[test_case(&[0], &[1]; "0 - 1")]
 #[test_case(&[1], &[]; "1 - empty")]
@@ -247,6 +306,43 @@ fn test_bincode(creators: &[usize], annihilators: &[usize]) {
     assert_eq!(test, deserialized);
 }
 
+// Test the commutes_with function of the BosonProduct
+#[test_case(&[0], &[1], &[1], &[0], false; "hopping_reversed")]
+#[test_case(&[0], &[0], &[1], &[1], true; "disjoint_modes")]
+#[test_case(&[0], &[], &[1], &[], true; "creators_only")]
+#[test_case(&[0], &[], &[], &[0], false; "creator_annihilator")]
+#[test_case(&[0, 1], &[2], &[0, 1], &[2], true; "identical")]
+fn commutes_with(
+    left_creators: &[usize],
+    left_annihilators: &[usize],
+    right_creators: &[usize],
+    right_annihilators: &[usize],
+    commutes: bool,
+) {
+    let left = BosonProduct::new(left_creators.to_vec(), left_annihilators.to_vec()).unwrap();
+    let right = BosonProduct::new(right_creators.to_vec(), right_annihilators.to_vec()).unwrap();
+    assert_eq!(left.commutes_with(&right), commutes);
+    assert_eq!(right.commutes_with(&left), commutes);
+}
+
+// Test the is_normal_ordered function of the BosonProduct
+#[test]
+fn is_normal_ordered() {
+    assert!(BosonProduct::new([], []).unwrap().is_normal_ordered());
+    assert!(BosonProduct::new([0, 2], [1, 3])
+        .unwrap()
+        .is_normal_ordered());
+    assert!(<BosonProduct as std::str::FromStr>::from_str("c0c3a1")
+        .unwrap()
+        .is_normal_ordered());
+
+    // Deserializing an incorrectly ordered product results in a normal ordered product
+    let serialized = bincode::serialize(&(vec![2_usize, 1, 1], vec![3_usize, 0])).unwrap();
+    let deserialized: BosonProduct = bincode::deserialize(&serialized).unwrap();
+    assert!(deserialized.is_normal_ordered());
+    assert_eq!(deserialized, BosonProduct::new([1, 1, 2], [0, 3]).unwrap());
+}
+
 #[test_case(&[1], &[]; "1 - empty")]
 fn serde_compact_creators(creators: &[usize], annihilators: &[usize]) {
     let creators = creators.to_vec();
@@ -549,3 +645,13 @@ fn test_boson_product_schema() {
     let validation = schema_checker.validate(&value);
     assert!(validation.is_ok());
 }
+
+// Test the min_supported_version_of function of the BosonProduct
+#[test]
+fn min_supported_version_of() {
+    let bp = BosonProduct::new([0], [1]).unwrap();
+    #[cfg(feature = "compact_keys")]
+    assert_eq!(bp.min_supported_version_of(), (1, 10, 0));
+    #[cfg(not(feature = "compact_keys"))]
+    assert_eq!(bp.min_supported_version_of(), (1, 0, 0));
+}
@@ -121,6 +121,34 @@ fn from_boson_operator_some() {
     );
 }
 
+// Test that the bound on the number of modes of a BosonSystem is enforced, serialized and compared
+#[test]
+fn number_modes_bound() {
+    let mut bounded = BosonSystem::new(Some(3));
+    let bp_valid = BosonProduct::new([0], [2]).unwrap();
+    let bp_invalid = BosonProduct::new([0], [3]).unwrap();
+    bounded
+        .set(bp_valid.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(
+        bounded.add_operator_product(bp_invalid.clone(), CalculatorComplex::from(0.5)),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+
+    let mut unbounded = BosonSystem::new(None);
+    unbounded
+        .set(bp_valid, CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_ne!(bounded, unbounded);
+
+    let mut deserialized: BosonSystem = deserialize(&serialize(&bounded).unwrap()).unwrap();
+    assert_eq!(deserialized, bounded);
+    assert_eq!(
+        deserialized.set(bp_invalid, CalculatorComplex::from(1.0)),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+}
+
 // Test the current_number_modes function of the BosonSystem
 #[test]
 fn internal_map_current_number_modes() {
@@ -545,6 +573,7 @@ fn serde_json() {
 
 /// Test BosonOperator Serialization and Deserialization traits (readable)
 #[test]
+#[cfg(not(feature = "compact_keys"))]
 fn serde_readable() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
     let major_version = MINIMUM_STRUQTURE_VERSION.0;
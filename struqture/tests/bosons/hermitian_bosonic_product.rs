@@ -82,6 +82,36 @@ fn new_error() {
     );
 }
 
+#[test]
+fn new_error_message() {
+    let error = HermitianBosonProduct::new(vec![43], vec![9]).unwrap_err();
+    assert!(format!("{}", error).contains(
+        "creators' minimum index must not exceed annihilators' minimum index; swap creators and annihilators"
+    ));
+}
+
+#[test_case(&[0, 2], &[1, 3], &[0, 2], &[1, 3], false; "valid")]
+#[test_case(&[1], &[1], &[1], &[1], false; "diagonal")]
+#[test_case(&[], &[4], &[], &[4], false; "only annihilators")]
+#[test_case(&[43], &[9], &[9], &[43], true; "swapped")]
+#[test_case(&[4], &[], &[], &[4], true; "only creators")]
+#[test_case(&[1, 3], &[1, 2], &[1, 2], &[1, 3], true; "swapped equal minimum")]
+fn new_or_conjugate(
+    creators: &[usize],
+    annihilators: &[usize],
+    creators_result: &[usize],
+    annihilators_result: &[usize],
+    conjugated: bool,
+) {
+    let (hbp, swapped) =
+        HermitianBosonProduct::new_or_conjugate(creators.to_vec(), annihilators.to_vec()).unwrap();
+    assert_eq!(swapped, conjugated);
+    assert_eq!(
+        hbp,
+        HermitianBosonProduct::new(creators_result.to_vec(), annihilators_result.to_vec()).unwrap()
+    );
+}
+
 #[test_case(&[], &[], 0, 0, 0; "empty")]
 #[test_case(&[0], &[1], 1, 1, 2; "0 - 1")]
 #[test_case(&[], &[2000], 0, 1, 2001; "empty - 2000")]
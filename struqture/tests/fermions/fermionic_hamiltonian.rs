@@ -13,6 +13,7 @@
 //! Integration test for public API of FermionHamiltonian
 
 use bincode::{deserialize, serialize};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -73,6 +74,16 @@ fn internal_map_len() {
     so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the FermionHamiltonian
+#[test]
+fn number_of_terms() {
+    let pp_2: HermitianFermionProduct = HermitianFermionProduct::new([0], [2]).unwrap();
+    let mut so = FermionHamiltonian::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -314,6 +325,52 @@ fn sub_so_so() {
     assert_eq!(so_0 - so_1, Ok(so_0_1));
 }
 
+// Test the commutator and anticommutator functions of the FermionHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut number = FermionHamiltonian::new();
+    number
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut hopping = FermionHamiltonian::new();
+    hopping
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    let mut expected_commutator = FermionOperator::new();
+    expected_commutator
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected_commutator
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(-1.0),
+        )
+        .unwrap();
+    assert_eq!(number.commutator(&hopping).unwrap(), expected_commutator);
+
+    let mut expected_anticommutator = FermionOperator::new();
+    expected_anticommutator
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert_eq!(
+        number.anticommutator(&number).unwrap(),
+        expected_anticommutator
+    );
+}
+
 // Test the multiplication: FermionHamiltonian * FermionHamiltonian
 #[test]
 fn mul_so_so() {
@@ -638,3 +695,48 @@ fn test_fermion_hamiltonian_schema() {
 
     assert!(validation.is_ok());
 }
+
+// Test the basis_transform function of the FermionHamiltonian
+#[test]
+fn basis_transform() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    // Swapping the modes 0 and 1 maps c_0^dagger c_1 to c_1^dagger c_0, the conjugate of the stored key
+    let swap = ndarray::array![
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]
+    ];
+    let mut expected = FermionHamiltonian::new();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, -0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([1], [1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert_eq!(fh.basis_transform(&swap).unwrap().truncate(1e-10), expected);
+
+    let not_unitary = ndarray::array![
+        [Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]
+    ];
+    assert!(matches!(
+        fh.basis_transform(&not_unitary),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
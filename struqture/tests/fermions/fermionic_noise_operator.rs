@@ -80,6 +80,17 @@ fn internal_map_len() {
         .unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the FermionLindbladNoiseOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: FermionProduct = FermionProduct::new([0], [2]).unwrap();
+    let mut so = FermionLindbladNoiseOperator::new();
+    so.set((pp_2.clone(), pp_2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
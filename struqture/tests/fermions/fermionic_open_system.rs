@@ -12,6 +12,7 @@
 
 //! Integration test for public API of FermionLindbladOpenSystem
 
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
@@ -20,7 +21,7 @@ use struqture::fermions::{
     FermionProduct, HermitianFermionProduct,
 };
 use struqture::prelude::*;
-use struqture::ModeIndex;
+use struqture::{ModeIndex, StruqtureError};
 #[cfg(feature = "json_schema")]
 use test_case::test_case;
 
@@ -51,6 +52,47 @@ fn new_system_none() {
     assert_eq!(system.current_number_modes(), 0_usize);
 }
 
+// Test the sparse_matrix_superoperator_coo function of the FermionLindbladOpenSystem
+#[test]
+fn sparse_matrix_superoperator_coo() {
+    let mut system = FermionLindbladOpenSystem::new(None);
+    system
+        .system_mut()
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                FermionProduct::new([], [0]).unwrap(),
+                FermionProduct::new([], [0]).unwrap(),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    // Damped single mode, the entry (a, b) of p has index 2 * a + b
+    let expected = (
+        vec![
+            Complex64::new(0.5, 0.0),
+            Complex64::new(-0.25, 1.0),
+            Complex64::new(-0.25, -1.0),
+            Complex64::new(-0.5, 0.0),
+        ],
+        (vec![0, 1, 2, 3], vec![3, 1, 2, 3]),
+    );
+    assert_eq!(system.sparse_matrix_superoperator_coo(1).unwrap(), expected);
+    assert!(system.sparse_matrix_superoperator_coo(0).is_err());
+    // The space of density matrices of 32 modes does not fit into usize
+    assert!(matches!(
+        system.sparse_matrix_superoperator_coo(32),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
 // Test the group function of the FermionLindbladOpenSystem
 #[test]
 fn group() {
@@ -478,6 +520,36 @@ fn clone_partial_eq() {
     assert!(slos != slos_2);
 }
 
+// Test the to_hdf5 and from_hdf5 functions of the FermionLindbladOpenSystem
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut system = FermionLindbladOpenSystem::new(Some(2));
+    system
+        .system_mut()
+        .set(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.4, 0.1),
+        )
+        .unwrap();
+    let dp: FermionProduct = FermionProduct::new([0], [0]).unwrap();
+    system
+        .noise_mut()
+        .set((dp.clone(), dp), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("fermion_open_system_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("fermion_open_system").unwrap();
+        system.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("fermion_open_system").unwrap();
+    let read = FermionLindbladOpenSystem::from_hdf5(&group).unwrap();
+    assert_eq!(read, system);
+}
+
 /// Test FermionLindbladOpenSystem Serialization and Deserialization traits (readable)
 #[test]
 fn serde_json() {
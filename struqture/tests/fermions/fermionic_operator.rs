@@ -13,14 +13,21 @@
 //! Integration test for public API of FermionOperator
 
 use bincode::{deserialize, serialize};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::iter::{FromIterator, IntoIterator};
 use struqture::fermions::{
     FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
 };
-use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState};
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::spins::ToSparseMatrixOperator;
+use struqture::{
+    CooSparseMatrix, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState,
+    StruqtureError,
+};
 use test_case::test_case;
 
 // Test the new function of the FermionOperator
@@ -65,6 +72,16 @@ fn internal_map_len() {
     so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the FermionOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: FermionProduct = FermionProduct::new([0], [2]).unwrap();
+    let mut so = FermionOperator::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -178,6 +195,127 @@ fn hermitian_test() {
     assert_eq!(system.hermitian_conjugate(), system.clone());
 }
 
+// Test the iter_hermitian_pairs function of the FermionOperator
+#[test]
+fn iter_hermitian_pairs() {
+    let fp_0: FermionProduct = FermionProduct::new([0, 1], [2]).unwrap();
+    let fp_1: FermionProduct = FermionProduct::new([1], [1]).unwrap();
+    let mut system = FermionOperator::new();
+    system
+        .add_operator_product(fp_0.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    system
+        .add_operator_product(fp_1.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let pairs: BTreeMap<FermionProduct, (CalculatorComplex, FermionProduct)> = system
+        .iter_hermitian_pairs()
+        .map(|(key, value, partner)| (key.clone(), (value.clone(), partner)))
+        .collect();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(
+        pairs.get(&fp_0),
+        Some(&(
+            CalculatorComplex::new(1.0, 2.0),
+            FermionProduct::new([2], [0, 1]).unwrap()
+        ))
+    );
+    assert_eq!(
+        pairs.get(&fp_1),
+        Some(&(CalculatorComplex::from(0.5), fp_1.clone()))
+    );
+}
+
+// Test the to_canonical_hermitian_form function of the FermionOperator on a hermitian operator
+#[test]
+fn to_canonical_hermitian_form() {
+    let hopping = FermionProduct::new([0], [1]).unwrap();
+    let hopping_conjugate = FermionProduct::new([1], [0]).unwrap();
+    let number = FermionProduct::new([1], [1]).unwrap();
+    let mut system = FermionOperator::new();
+    system
+        .add_operator_product(hopping.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    system
+        .add_operator_product(hopping_conjugate, CalculatorComplex::new(1.0, -2.0))
+        .unwrap();
+    system
+        .add_operator_product(number.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(system.hermitian_conjugate(), system);
+
+    let canonical = system.to_canonical_hermitian_form();
+    assert_eq!(canonical.len(), 2);
+    assert_eq!(canonical.get(&hopping), &CalculatorComplex::new(2.0, 4.0));
+    assert_eq!(canonical.get(&number), &CalculatorComplex::from(0.5));
+}
+
+// Test the hermitian_orbits function of the FermionOperator
+#[test]
+fn hermitian_orbits() {
+    let hopping = FermionProduct::new([0], [1]).unwrap();
+    let hopping_conjugate = FermionProduct::new([1], [0]).unwrap();
+    let number = FermionProduct::new([1], [1]).unwrap();
+    let unpaired = FermionProduct::new([0, 2], []).unwrap();
+    let mut system = FermionOperator::new();
+    system
+        .add_operator_product(hopping.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    system
+        .add_operator_product(hopping_conjugate.clone(), CalculatorComplex::new(1.0, -2.0))
+        .unwrap();
+    system
+        .add_operator_product(number.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(unpaired.clone(), CalculatorComplex::from(3.0))
+        .unwrap();
+
+    let orbits = system.hermitian_orbits();
+    assert_eq!(orbits.len(), 3);
+    assert_eq!(orbits.iter().map(|orbit| orbit.len()).sum::<usize>(), 4);
+    let hopping_orbit = orbits
+        .iter()
+        .find(|orbit| orbit.iter().any(|(key, _)| **key == hopping))
+        .unwrap();
+    assert_eq!(hopping_orbit.len(), 2);
+    assert!(hopping_orbit
+        .iter()
+        .any(|(key, value)| **key == hopping_conjugate
+            && **value == CalculatorComplex::new(1.0, -2.0)));
+    assert!(orbits.contains(&vec![(&number, &CalculatorComplex::from(0.5))]));
+    assert!(orbits.contains(&vec![(&unpaired, &CalculatorComplex::from(3.0))]));
+}
+
+// Test the max_mode_locality function of the FermionOperator
+#[test]
+fn max_mode_locality() {
+    let mut so = FermionOperator::new();
+    assert_eq!(so.max_mode_locality(), 0);
+    so.add_operator_product(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(so.max_mode_locality(), 0);
+    so.add_operator_product(
+        FermionProduct::new([0], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        FermionProduct::new([0, 2], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(so.max_mode_locality(), 3);
+}
+
 // Test the separation of terms
 #[test_case((1, 1))]
 #[test_case((1, 2))]
@@ -332,6 +470,229 @@ fn mul_so_so_same_index() {
     assert_eq!(so_0 * so_1, so_0_1);
 }
 
+// Test the commutator and anticommutator functions of the FermionOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut annihilator = FermionOperator::new();
+    annihilator
+        .add_operator_product(
+            FermionProduct::new([], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut creator = FermionOperator::new();
+    creator
+        .add_operator_product(
+            FermionProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    let mut expected_anticommutator = FermionOperator::new();
+    expected_anticommutator
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        annihilator.anticommutator(&creator),
+        expected_anticommutator
+    );
+
+    let mut expected_commutator = FermionOperator::new();
+    expected_commutator
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected_commutator
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(-2.0),
+        )
+        .unwrap();
+    assert_eq!(annihilator.commutator(&creator), expected_commutator);
+}
+
+// Test the tensor function of the FermionOperator
+#[test]
+fn tensor() {
+    let mut hopping = FermionOperator::new();
+    hopping
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    let mut creator = FermionOperator::new();
+    creator
+        .add_operator_product(
+            FermionProduct::new([0], []).unwrap(),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            FermionProduct::new([2], []).unwrap(),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+    assert_eq!(hopping.tensor(&creator, 2).unwrap(), expected);
+
+    let error = hopping.tensor(&creator, 1);
+    assert!(matches!(error, Err(StruqtureError::GenericError { .. })));
+}
+
+// Test the exp_approx and heisenberg_transform functions of the FermionOperator
+#[test]
+fn exp_approx_heisenberg_transform() {
+    let theta: f64 = 0.4;
+    let mut generator = FermionOperator::new();
+    generator
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(theta),
+        )
+        .unwrap();
+
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(theta.exp() - 1.0),
+        )
+        .unwrap();
+    assert!((generator.exp_approx(25) - expected)
+        .truncate(1e-12)
+        .is_empty());
+
+    let mut creator = FermionOperator::new();
+    creator
+        .add_operator_product(
+            FermionProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0], []).unwrap(),
+            CalculatorComplex::from(theta.exp()),
+        )
+        .unwrap();
+    assert!((creator.heisenberg_transform(&generator, 25) - expected)
+        .truncate(1e-12)
+        .is_empty());
+}
+
+// Test the sparse_matrix_coo function of the FermionOperator against the Jordan-Wigner transformation
+#[test]
+fn sparse_matrix_coo_jordan_wigner() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1], [2]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(-0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(0.25),
+    )
+    .unwrap();
+
+    // The sparse matrix of the SpinOperator can contain explicit zeros, these are ignored in the comparison
+    let to_map =
+        |(values, (rows, columns)): CooSparseMatrix| -> HashMap<(usize, usize), Complex64> {
+            rows.into_iter()
+                .zip(columns)
+                .zip(values)
+                .filter(|(_, value)| value.norm() > 1e-12)
+                .collect()
+        };
+    let direct = fo.sparse_matrix_coo(3).unwrap();
+    let (_, (rows, columns)) = direct.clone();
+    let keys: Vec<(usize, usize)> = rows.into_iter().zip(columns).collect();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort_unstable();
+    assert_eq!(keys, sorted_keys);
+    assert_eq!(
+        to_map(direct),
+        to_map(fo.jordan_wigner().sparse_matrix_coo(Some(3)).unwrap())
+    );
+
+    let mut single = FermionOperator::new();
+    single
+        .add_operator_product(
+            FermionProduct::new([1], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    // c_1^dagger picks up a sign if mode 0 is occupied
+    let expected: CooSparseMatrix = (
+        vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)],
+        (vec![2, 3], vec![0, 1]),
+    );
+    assert_eq!(single.sparse_matrix_coo(2).unwrap(), expected);
+    assert_eq!(
+        single.sparse_matrix_coo(1),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+}
+
+// Test the sparse_matrix_coo function of the FermionOperator for a product on part of the modes and for too many modes
+#[test]
+fn sparse_matrix_coo_many_modes() {
+    let mut small = FermionOperator::new();
+    small
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let (values, (rows, columns)) = small.sparse_matrix_coo(3).unwrap();
+    assert_eq!(rows, vec![2, 6]);
+    assert_eq!(columns, vec![1, 5]);
+    assert_eq!(
+        values,
+        vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)]
+    );
+
+    assert!(matches!(
+        small.sparse_matrix_coo(64),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        small.sparse_matrix_coo(usize::MAX),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
 // Test the multiplication: FermionOperator * Calculatorcomplex
 #[test]
 fn mul_so_cc() {
@@ -378,6 +739,72 @@ fn from_operator_pass(creators: &[usize], annihilators: &[usize]) {
     assert_eq!(FermionOperator::from(so_0), so_0_1);
 }
 
+// Test the LaTeX representation of the FermionOperator
+#[test]
+fn to_latex() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([1], []).unwrap(),
+        CalculatorComplex::new(1.0, -2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(-3.0),
+    )
+    .unwrap();
+    assert_eq!(
+        fo.to_latex(),
+        r"-3 \mathbb{1} + i \hat{c}^\dagger_{0} \hat{c}_{1} + (1 - 2i) \hat{c}^\dagger_{1}"
+    );
+
+    let mut symbolic = FermionOperator::new();
+    symbolic
+        .add_operator_product(FermionProduct::new([0], [0]).unwrap(), "theta".into())
+        .unwrap();
+    assert_eq!(
+        symbolic.to_latex(),
+        r"(theta) \hat{c}^\dagger_{0} \hat{c}_{0}"
+    );
+}
+
+// Test the in-place arithmetic operators of FermionOperator: +=, -= and *=
+#[test]
+fn assign_operators() {
+    let mut a = FermionOperator::new();
+    a.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut b = FermionOperator::new();
+    b.add_operator_product(
+        FermionProduct::new([1], [2]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+
+    let mut added = a.clone();
+    added += b.clone();
+    assert_eq!(added, a.clone() + b.clone());
+
+    let mut subtracted = a.clone();
+    subtracted -= b.clone();
+    assert_eq!(subtracted, a.clone() - b.clone());
+
+    let mut scaled = a.clone();
+    scaled *= CalculatorComplex::new(0.0, 2.0);
+    assert_eq!(scaled, a.clone() * CalculatorComplex::new(0.0, 2.0));
+    let mut scaled = a.clone();
+    scaled *= CalculatorFloat::from(3.0);
+    assert_eq!(scaled, a * CalculatorFloat::from(3.0));
+}
+
 // Test the Iter traits of FermionOperator: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -576,6 +1003,118 @@ fn serde_compact() {
     );
 }
 
+#[test]
+fn openfermion_string_export() {
+    let mut so = FermionOperator::new();
+    so.set(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    so.set(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::new(1.0, -2.0),
+    )
+    .unwrap();
+
+    assert_eq!(
+        so.to_openfermion_fermion_string(),
+        "(1.0-2.0j) [] +\n0.5 [0^ 1]".to_string()
+    );
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string(&so.to_openfermion_fermion_string())
+            .unwrap(),
+        so
+    );
+}
+
+#[test]
+fn openfermion_string_roundtrip() {
+    let mut so = FermionOperator::new();
+    so.set(
+        FermionProduct::new([0, 2], [1, 3]).unwrap(),
+        CalculatorComplex::new(0.0, 1.5),
+    )
+    .unwrap();
+    so.set(
+        FermionProduct::new([], [4]).unwrap(),
+        CalculatorComplex::from(-0.25),
+    )
+    .unwrap();
+    so.set(
+        FermionProduct::new([3], []).unwrap(),
+        CalculatorComplex::new(2.0, 3.0),
+    )
+    .unwrap();
+
+    let string = so.to_openfermion_fermion_string();
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string(&string).unwrap(),
+        so
+    );
+
+    let mut sh = FermionHamiltonian::new();
+    sh.set(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string(&sh.to_openfermion_fermion_string())
+            .unwrap(),
+        FermionOperator::from(sh)
+    );
+}
+
+#[test]
+fn openfermion_string_normal_ordering() {
+    // a_1 c_0 = - c_0 a_1
+    let mut so = FermionOperator::new();
+    so.set(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(-2.0),
+    )
+    .unwrap();
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string("2.0 [1 0^]").unwrap(),
+        so
+    );
+
+    // a_0 c_0 = 1 - c_0 a_0
+    let mut so = FermionOperator::new();
+    so.set(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    so.set(
+        FermionProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::from(-1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string("[0 0^]").unwrap(),
+        so
+    );
+
+    // c_2 c_0 + c_0 c_2 = 0 and c_0 c_0 = 0
+    assert_eq!(
+        FermionOperator::from_openfermion_fermion_string(
+            "1.0 [2^ 0^] +\n1.0 [0^ 2^] + 3.0 [0^ 0^]"
+        )
+        .unwrap(),
+        FermionOperator::new()
+    );
+}
+
+#[test_case("1.0 [0^ x]"; "bad index")]
+#[test_case("abc [0^ 1]"; "bad coefficient")]
+#[test_case("1.0 [0^ 1"; "missing bracket")]
+#[test_case("1.0 [0^ 1] + 2.0"; "dangling coefficient")]
+fn openfermion_string_errors(input: &str) {
+    assert!(FermionOperator::from_openfermion_fermion_string(input).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_operator_schema() {
@@ -592,3 +1131,157 @@ fn test_fermion_operator_schema() {
 
     assert!(validation.is_ok());
 }
+
+// Test the basis_transform function of the FermionOperator
+#[test]
+fn basis_transform() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1], [0, 1]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    // Swapping the modes 0 and 1
+    let swap = ndarray::array![
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]
+    ];
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0, 1], [0, 1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert_eq!(fo.basis_transform(&swap).unwrap().truncate(1e-10), expected);
+
+    // The total number of particles is invariant under a rotation of the modes
+    let (c, s) = (0.3_f64.cos(), 0.3_f64.sin());
+    let rotation = ndarray::array![
+        [Complex64::new(c, 0.0), Complex64::new(-s, 0.0)],
+        [Complex64::new(s, 0.0), Complex64::new(c, 0.0)]
+    ];
+    let mut number = FermionOperator::new();
+    for mode in 0..2 {
+        number
+            .add_operator_product(
+                FermionProduct::new([mode], [mode]).unwrap(),
+                CalculatorComplex::from(1.0),
+            )
+            .unwrap();
+    }
+    let transformed = number.basis_transform(&rotation).unwrap().truncate(1e-10);
+    assert_eq!(transformed.len(), 2);
+    for mode in 0..2 {
+        let value = transformed.get(&FermionProduct::new([mode], [mode]).unwrap());
+        assert!((value.re.float().unwrap() - 1.0).abs() < 1e-10);
+        assert!(value.im.float().unwrap().abs() < 1e-10);
+    }
+
+    let not_unitary = ndarray::array![
+        [Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]
+    ];
+    assert!(matches!(
+        fo.basis_transform(&not_unitary),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    let too_small = ndarray::array![[Complex64::new(1.0, 0.0)]];
+    assert_eq!(
+        fo.basis_transform(&too_small),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+}
+
+// Test the normal_ordered function of the FermionOperator
+#[test]
+fn normal_ordered() {
+    // c_0 c_0^dagger = 1 - c_0^dagger c_0
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(-1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        FermionOperator::normal_ordered(&[(0, false), (0, true)]).truncate(1e-10),
+        expected
+    );
+
+    // c_1 c_0^dagger = -c_0^dagger c_1
+    let mut expected = FermionOperator::new();
+    expected
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(-1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        FermionOperator::normal_ordered(&[(1, false), (0, true)]).truncate(1e-10),
+        expected
+    );
+
+    // c_0^dagger c_0^dagger = 0
+    assert!(FermionOperator::normal_ordered(&[(0, true), (0, true)])
+        .truncate(1e-10)
+        .is_empty());
+}
+
+// Test the wick_expectation function of the FermionOperator
+#[cfg(feature = "linalg")]
+#[test]
+fn wick_expectation() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1], [0, 1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0], []).unwrap(),
+        CalculatorComplex::from(3.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let one_body_dm = ndarray::array![
+        [Complex64::new(0.5, 0.0), Complex64::new(0.25, 0.0)],
+        [Complex64::new(0.25, 0.0), Complex64::new(0.5, 0.0)]
+    ];
+    // <c_0^dagger c_1^dagger c_0 c_1> = -(D_00 D_11 - D_01 D_10)
+    let expected = Complex64::new(-0.1875 + 2.0 * 0.25 + 1.0, 0.0);
+    assert!((fo.wick_expectation(&one_body_dm).unwrap() - expected).norm() < 1e-10);
+
+    let too_small = ndarray::array![[Complex64::new(1.0, 0.0)]];
+    assert_eq!(
+        fo.wick_expectation(&too_small),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+}
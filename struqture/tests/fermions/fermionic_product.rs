@@ -252,6 +252,43 @@ fn test_bincode(creators: &[usize], annihilators: &[usize]) {
     assert_eq!(test, deserialized);
 }
 
+// Test the commutes_with function of the FermionProduct
+#[test_case(&[0], &[0], &[1], &[1], true; "number_operators")]
+#[test_case(&[0], &[], &[1], &[], false; "single_creators")]
+#[test_case(&[0], &[1], &[2], &[], true; "even_and_odd")]
+#[test_case(&[0], &[], &[], &[0], false; "shared_mode")]
+#[test_case(&[0, 1], &[2], &[0, 1], &[2], true; "identical")]
+fn commutes_with(
+    left_creators: &[usize],
+    left_annihilators: &[usize],
+    right_creators: &[usize],
+    right_annihilators: &[usize],
+    commutes: bool,
+) {
+    let left = FermionProduct::new(left_creators.to_vec(), left_annihilators.to_vec()).unwrap();
+    let right = FermionProduct::new(right_creators.to_vec(), right_annihilators.to_vec()).unwrap();
+    assert_eq!(left.commutes_with(&right), commutes);
+    assert_eq!(right.commutes_with(&left), commutes);
+}
+
+// Test the is_normal_ordered function of the FermionProduct
+#[test]
+fn is_normal_ordered() {
+    assert!(FermionProduct::new([], []).unwrap().is_normal_ordered());
+    assert!(FermionProduct::new([0, 2], [1, 3])
+        .unwrap()
+        .is_normal_ordered());
+    assert!(<FermionProduct as std::str::FromStr>::from_str("c0c3a1")
+        .unwrap()
+        .is_normal_ordered());
+
+    // Deserializing an incorrectly ordered product is rejected, so no invalid product can be created
+    let serialized = bincode::serialize(&(vec![2_usize, 1], vec![0_usize])).unwrap();
+    assert!(bincode::deserialize::<FermionProduct>(&serialized).is_err());
+    let serialized = bincode::serialize(&(vec![1_usize, 1], vec![0_usize])).unwrap();
+    assert!(bincode::deserialize::<FermionProduct>(&serialized).is_err());
+}
+
 #[test_case(&[1], &[]; "1 - empty")]
 fn serde_compact_creators(creators: &[usize], annihilators: &[usize]) {
     let creators = creators.to_vec();
@@ -381,6 +418,30 @@ fn get_value_get_transform_hbp() {
     );
 }
 
+// Test the verify_product function of the FermionProduct on known products
+#[cfg(feature = "linalg")]
+#[test_case(&[], &[0], &[0], &[]; "a0 - c0")]
+#[test_case(&[0], &[1], &[1], &[2]; "c0a1 - c1a2")]
+#[test_case(&[0, 1], &[1, 2], &[2], &[0]; "c0c1a1a2 - c2a0")]
+#[test_case(&[1], &[0], &[0, 2], &[1]; "c1a0 - c0c2a1")]
+fn verify_product(
+    creators_left: &[usize],
+    annihilators_left: &[usize],
+    creators_right: &[usize],
+    annihilators_right: &[usize],
+) {
+    let left = FermionProduct::new(creators_left.to_vec(), annihilators_left.to_vec()).unwrap();
+    let right = FermionProduct::new(creators_right.to_vec(), annihilators_right.to_vec()).unwrap();
+    let result = left.clone() * right.clone();
+    assert!(FermionProduct::verify_product(&left, &right, &result));
+
+    let wrong: Vec<(FermionProduct, f64)> = result
+        .iter()
+        .map(|(product, coefficient)| (product.clone(), -coefficient))
+        .collect();
+    assert!(!FermionProduct::verify_product(&left, &right, &wrong));
+}
+
 type MulVec = Vec<(TinyVec<[usize; 2]>, TinyVec<[usize; 2]>, f64)>;
 #[test_case(tiny_vec!([usize; 2] => 0, 2, 4), tiny_vec!([usize; 2] => 1, 3, 5),
      vec![(tiny_vec!([usize; 2] => 1, 3, 5), tiny_vec!([usize; 2] => 0, 2, 4), -1.0)]; "0,2,4 - 1,3,5")]
@@ -0,0 +1,197 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of SpinSOperator
+
+use bincode::{deserialize, serialize};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+use struqture::higher_spins::{SpinSOperator, SpinSProduct};
+use struqture::{CooSparseMatrix, OperateOnDensityMatrix, OperateOnState, StruqtureError};
+
+fn to_map((values, (rows, columns)): CooSparseMatrix) -> HashMap<(usize, usize), Complex64> {
+    rows.into_iter().zip(columns).zip(values).collect()
+}
+
+// Test the sparse_matrix_coo function of the SpinSOperator for a single spin-1
+#[test]
+fn sparse_matrix_coo_spin_one() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(SpinSProduct::new().plus(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(SpinSProduct::new().z(0), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    let sqrt_two = 2.0_f64.sqrt();
+    let expected: CooSparseMatrix = (
+        vec![
+            Complex64::new(0.0, 2.0),
+            Complex64::new(sqrt_two, 0.0),
+            Complex64::new(sqrt_two, 0.0),
+            Complex64::new(0.0, -2.0),
+        ],
+        (vec![0, 0, 1, 2], vec![0, 1, 2, 2]),
+    );
+    assert_eq!(so.sparse_matrix_coo(&[1.0]).unwrap(), expected);
+}
+
+// Test that the spin operators fulfil the commutation relations and the Casimir identity for mixed spins
+#[test]
+fn sparse_matrix_coo_algebra() {
+    let spins = [1.5, 0.5, 1.0];
+    let dimension = 4 * 2 * 3;
+    for site in 0..3 {
+        // S^+ S^- - S^- S^+ = 2 S^z
+        let mut commutator = SpinSOperator::new();
+        commutator
+            .add_operator_product(
+                SpinSProduct::new().plus(site).minus(site),
+                CalculatorComplex::from(1.0),
+            )
+            .unwrap();
+        commutator
+            .add_operator_product(
+                SpinSProduct::new().minus(site).plus(site),
+                CalculatorComplex::from(-1.0),
+            )
+            .unwrap();
+        let mut expected = SpinSOperator::new();
+        expected
+            .add_operator_product(SpinSProduct::new().z(site), CalculatorComplex::from(2.0))
+            .unwrap();
+        let commutator_map = to_map(commutator.sparse_matrix_coo(&spins).unwrap());
+        let expected_map = to_map(expected.sparse_matrix_coo(&spins).unwrap());
+        for key in commutator_map.keys().chain(expected_map.keys()) {
+            let difference = commutator_map.get(key).copied().unwrap_or_default()
+                - expected_map.get(key).copied().unwrap_or_default();
+            assert!(difference.norm() < 1e-10);
+        }
+
+        // 1/2 (S^+ S^- + S^- S^+) + S^z S^z = S (S + 1)
+        let mut casimir = SpinSOperator::new();
+        casimir
+            .add_operator_product(
+                SpinSProduct::new().plus(site).minus(site),
+                CalculatorComplex::from(0.5),
+            )
+            .unwrap();
+        casimir
+            .add_operator_product(
+                SpinSProduct::new().minus(site).plus(site),
+                CalculatorComplex::from(0.5),
+            )
+            .unwrap();
+        casimir
+            .add_operator_product(
+                SpinSProduct::new().z(site).z(site),
+                CalculatorComplex::from(1.0),
+            )
+            .unwrap();
+        let (values, (rows, columns)) = casimir.sparse_matrix_coo(&spins).unwrap();
+        assert_eq!(values.len(), dimension);
+        assert_eq!(rows, columns);
+        for value in values {
+            assert!((value.re - spins[site] * (spins[site] + 1.0)).abs() < 1e-10);
+            assert!(value.im.abs() < 1e-10);
+        }
+    }
+}
+
+// Test the errors of the sparse_matrix_coo function of the SpinSOperator
+#[test]
+fn sparse_matrix_coo_errors() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(SpinSProduct::new().plus(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        so.sparse_matrix_coo(&[1.0]),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0, 0.7]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0, 0.0]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0, f64::NAN]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0, f64::INFINITY]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0, 1e300]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    // Every local dimension fits into usize, the dimension of all sites does not
+    assert!(matches!(
+        so.sparse_matrix_coo(&[1.0; 64]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the hermitian_conjugate function of the SpinSOperator
+#[test]
+fn hermitian_conjugate() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(
+        SpinSProduct::new().plus(0).z(0),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    let mut expected = SpinSOperator::new();
+    expected
+        .add_operator_product(
+            SpinSProduct::new().z(0).minus(0),
+            CalculatorComplex::new(1.0, -2.0),
+        )
+        .unwrap();
+    assert_eq!(so.hermitian_conjugate(), expected);
+}
+
+// Test the Add, Sub, Mul and Neg traits of the SpinSOperator
+#[test]
+fn arithmetic() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(SpinSProduct::new().z(0).z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut doubled = SpinSOperator::new();
+    doubled
+        .add_operator_product(SpinSProduct::new().z(0).z(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(so.clone() + so.clone(), doubled);
+    assert_eq!(so.clone() * CalculatorComplex::from(2.0), doubled);
+    assert!((so.clone() - so.clone()).is_empty());
+    assert_eq!(-so.clone() + doubled, so);
+}
+
+// Test the Serialize and Deserialize traits of the SpinSOperator
+#[test]
+fn bincode() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(
+        SpinSProduct::new().plus(0).minus(1),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    let serialized = serialize(&so).unwrap();
+    let deserialized: SpinSOperator = deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, so);
+    assert_eq!(
+        format!("{}", so),
+        "SpinSOperator{\n0+1-: (1e0 + i * 5e-1),\n}"
+    );
+}
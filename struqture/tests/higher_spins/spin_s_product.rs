@@ -0,0 +1,99 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of SpinSProduct
+
+use bincode::{deserialize, serialize};
+use serde_test::{assert_tokens, Configure, Token};
+use std::str::FromStr;
+use struqture::higher_spins::{SingleSpinSOperator, SpinSProduct};
+use struqture::{StruqtureError, SymmetricIndex};
+
+// Test the new function and the setters of the SpinSProduct
+#[test]
+fn new_and_add_operator() {
+    let sp = SpinSProduct::new().z(1).plus(0).z(1).minus(1);
+    assert_eq!(sp.get(&0), vec![SingleSpinSOperator::Plus]);
+    assert_eq!(
+        sp.get(&1),
+        vec![
+            SingleSpinSOperator::Z,
+            SingleSpinSOperator::Z,
+            SingleSpinSOperator::Minus
+        ]
+    );
+    assert!(sp.get(&2).is_empty());
+    assert_eq!(sp.len(), 4);
+    assert_eq!(sp.current_number_spins(), 2);
+    assert_eq!(
+        sp.clone().add_operator(3, SingleSpinSOperator::Identity),
+        sp
+    );
+    assert!(SpinSProduct::new().is_empty());
+    assert_eq!(SpinSProduct::default(), SpinSProduct::new());
+}
+
+// Test the Display and FromStr traits of the SpinSProduct
+#[test]
+fn display_from_str() {
+    let sp = SpinSProduct::new().plus(0).z(2).z(2).minus(10);
+    assert_eq!(sp.to_string(), "0+2Z2Z10-");
+    assert_eq!(SpinSProduct::from_str("0+2Z2Z10-").unwrap(), sp);
+    // Operators on the same site keep their order, sites are sorted
+    assert_eq!(
+        SpinSProduct::from_str("2Z0+2-").unwrap(),
+        SpinSProduct::new().plus(0).z(2).minus(2)
+    );
+    assert_eq!(SpinSProduct::from_str("I").unwrap(), SpinSProduct::new());
+    assert_eq!(SpinSProduct::new().to_string(), "I");
+    assert_eq!(
+        SpinSProduct::from_str("0X"),
+        Err(StruqtureError::IncorrectPauliEntry {
+            pauli: "X".to_string()
+        })
+    );
+    assert!(matches!(
+        SpinSProduct::from_str("Z0"),
+        Err(StruqtureError::FromStringFailed { .. })
+    ));
+}
+
+// Test the hermitian_conjugate and is_natural_hermitian functions of the SpinSProduct
+#[test]
+fn hermitian_conjugate() {
+    let sp = SpinSProduct::new().plus(0).z(0).minus(1);
+    let expected = SpinSProduct::new().z(0).minus(0).plus(1);
+    assert_eq!(sp.hermitian_conjugate(), (expected, 1.0));
+    assert!(!sp.is_natural_hermitian());
+    assert!(SpinSProduct::new().z(0).z(0).is_natural_hermitian());
+    assert!(SpinSProduct::new()
+        .plus(0)
+        .z(0)
+        .minus(0)
+        .is_natural_hermitian());
+}
+
+// Test the Serialize and Deserialize traits of the SpinSProduct (readable)
+#[test]
+fn serde_readable() {
+    let sp = SpinSProduct::new().plus(0).z(0);
+    assert_tokens(&sp.readable(), &[Token::Str("0+0Z")]);
+}
+
+// Test the Serialize and Deserialize traits of the SpinSProduct (compact)
+#[test]
+fn bincode() {
+    let sp = SpinSProduct::new().plus(0).z(0).minus(3);
+    let serialized = serialize(&sp).unwrap();
+    let deserialized: SpinSProduct = deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, sp);
+}
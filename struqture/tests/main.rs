@@ -14,6 +14,39 @@ use tinyvec::TinyVec;
 
 type BosonTinyVec = TinyVec<[usize; 2]>;
 
+/// HDF5 file in the temporary directory that is removed when dropped, also when a test fails.
+#[cfg(feature = "hdf5")]
+struct TemporaryHdf5File {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "hdf5")]
+impl TemporaryHdf5File {
+    /// Creates the path of a temporary HDF5 file that is unique for the test `name` and the test process.
+    fn new(name: &str) -> Self {
+        Self {
+            path: std::env::temp_dir().join(format!(
+                "struqture_{}_{}.h5",
+                name,
+                std::process::id()
+            )),
+        }
+    }
+
+    /// Returns the path of the temporary HDF5 file.
+    fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "hdf5")]
+impl Drop for TemporaryHdf5File {
+    fn drop(&mut self) {
+        // The file does not exist if the test failed before creating it
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
 #[cfg(test)]
 mod bosons;
 
@@ -23,6 +56,9 @@ mod fermions;
 #[cfg(test)]
 mod spins;
 
+#[cfg(test)]
+mod higher_spins;
+
 #[cfg(test)]
 mod mixed_systems;
 
@@ -0,0 +1,199 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionLindbladNoiseOperator,
+    FermionLindbladNoiseSystem, FermionLindbladOpenSystem, FermionOperator, FermionProduct,
+    HermitianFermionProduct,
+};
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
+use struqture::prelude::*;
+use struqture::spins::{
+    DecoherenceProduct, PauliProduct, SpinHamiltonian, SpinHamiltonianSystem,
+    SpinLindbladNoiseOperator, SpinLindbladNoiseSystem, SpinLindbladOpenSystem, SpinOperator,
+};
+use struqture::{CooSparseMatrix, StruqtureError};
+
+fn dense_eigenvalues(coo: CooSparseMatrix, number_spins: usize) -> Vec<f64> {
+    let dimension = 2usize.pow(number_spins as u32);
+    let mut matrix = nalgebra::DMatrix::<Complex64>::zeros(dimension, dimension);
+    let (values, (rows, columns)) = coo;
+    for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+        matrix[(row, column)] += value;
+    }
+    let mut eigenvalues: Vec<f64> = matrix
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .copied()
+        .collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    eigenvalues
+}
+
+// Test the Bravyi-Kitaev transformation of the number operators on four modes
+#[test]
+fn test_bk_number_operators() {
+    let expected_parities = [
+        PauliProduct::new().z(0),
+        PauliProduct::new().z(0).z(1),
+        PauliProduct::new().z(2),
+        PauliProduct::new().z(1).z(2).z(3),
+    ];
+    for (mode, parity) in expected_parities.into_iter().enumerate() {
+        let mut so = SpinOperator::new();
+        so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 0.0))
+            .unwrap();
+        so.add_operator_product(parity.clone(), CalculatorComplex::new(-0.5, 0.0))
+            .unwrap();
+        let fp = FermionProduct::new([mode], [mode]).unwrap();
+        assert_eq!(fp.bravyi_kitaev(4).unwrap().truncate(1e-12), so);
+
+        let mut sh = SpinHamiltonian::new();
+        sh.add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.5))
+            .unwrap();
+        sh.add_operator_product(parity, CalculatorFloat::from(-0.5))
+            .unwrap();
+        let hfp = HermitianFermionProduct::new([mode], [mode]).unwrap();
+        assert_eq!(hfp.bravyi_kitaev(4).unwrap().truncate(1e-12), sh);
+    }
+
+    let fp = FermionProduct::new([], []).unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(fp.bravyi_kitaev(4).unwrap(), so);
+}
+
+// Test that the Bravyi-Kitaev and Jordan-Wigner transformations of a Hamiltonian have the same spectrum
+#[test]
+fn test_bk_spectrum_matches_jw() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [4]).unwrap(),
+        CalculatorComplex::new(-0.3, 0.0),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 3], [1, 4]).unwrap(),
+        CalculatorComplex::new(0.7, -0.2),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([2], [2]).unwrap(),
+        CalculatorComplex::new(0.4, 0.0),
+    )
+    .unwrap();
+
+    let bk_spectrum = dense_eigenvalues(
+        fh.bravyi_kitaev(5)
+            .unwrap()
+            .sparse_matrix_coo(Some(5))
+            .unwrap(),
+        5,
+    );
+    let jw_spectrum = dense_eigenvalues(fh.jordan_wigner().sparse_matrix_coo(Some(5)).unwrap(), 5);
+    for (bk, jw) in bk_spectrum.iter().zip(jw_spectrum.iter()) {
+        assert!((bk - jw).abs() < 1e-10);
+    }
+
+    let mut fo = FermionOperator::new();
+    for (hfp, coeff) in fh.iter() {
+        let fp = FermionProduct::new(hfp.creators().copied(), hfp.annihilators().copied()).unwrap();
+        let (fp_conj, sign) = fp.hermitian_conjugate();
+        fo.add_operator_product(fp, coeff.clone()).unwrap();
+        if !hfp.is_natural_hermitian() {
+            fo.add_operator_product(fp_conj, coeff.conj() * sign)
+                .unwrap();
+        }
+    }
+    let operator_spectrum = dense_eigenvalues(
+        fo.bravyi_kitaev(5)
+            .unwrap()
+            .sparse_matrix_coo(Some(5))
+            .unwrap(),
+        5,
+    );
+    for (op, bk) in operator_spectrum.iter().zip(bk_spectrum.iter()) {
+        assert!((op - bk).abs() < 1e-10);
+    }
+}
+
+// Test that the Bravyi-Kitaev transformation fails for too few modes
+#[test]
+fn test_bk_number_modes_exceeded() {
+    let fp = FermionProduct::new([0], [3]).unwrap();
+    assert_eq!(
+        fp.bravyi_kitaev(3),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+
+    let hfp = HermitianFermionProduct::new([0], [3]).unwrap();
+    assert_eq!(
+        hfp.bravyi_kitaev(2),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(fp, CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(
+        fo.bravyi_kitaev(3),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+
+    let fhs = FermionHamiltonianSystem::new(Some(5));
+    assert_eq!(
+        fhs.bravyi_kitaev(4),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+}
+
+// Test the Bravyi-Kitaev transformation of the Lindblad noise and open systems
+#[test]
+fn test_bk_fermion_systems_to_spin() {
+    let mut fno = FermionLindbladNoiseOperator::new();
+    let fp = FermionProduct::new([0], [0]).unwrap();
+    fno.add_operator_product((fp.clone(), fp), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    let mut sno = SpinLindbladNoiseOperator::new();
+    let dp = DecoherenceProduct::new().z(0);
+    sno.add_operator_product((dp.clone(), dp), CalculatorComplex::new(0.25, 0.0))
+        .unwrap();
+    assert_eq!(fno.bravyi_kitaev(2).unwrap(), sno);
+
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    let fhs = FermionHamiltonianSystem::from_hamiltonian(fh.clone(), Some(3)).unwrap();
+    let shs =
+        SpinHamiltonianSystem::from_hamiltonian(fh.bravyi_kitaev(4).unwrap(), Some(4)).unwrap();
+    assert_eq!(fhs.bravyi_kitaev(4).unwrap(), shs);
+
+    let fns = FermionLindbladNoiseSystem::from_operator(fno.clone(), Some(3)).unwrap();
+    let sns = SpinLindbladNoiseSystem::from_operator(sno, Some(4)).unwrap();
+    assert_eq!(fns.bravyi_kitaev(4).unwrap(), sns);
+
+    let fos = FermionLindbladOpenSystem::group(fhs, fns).unwrap();
+    let sos = SpinLindbladOpenSystem::group(shs, sns).unwrap();
+    assert_eq!(fos.bravyi_kitaev(4).unwrap(), sos);
+}
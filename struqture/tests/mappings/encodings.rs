@@ -0,0 +1,221 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::fermions::{
+    FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
+};
+use struqture::mappings::{
+    BravyiKitaevEncoding, FermionToSpinEncoding, JordanWignerEncoding, ParityEncoding,
+    TernaryTreeEncoding,
+};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian, SpinOperator};
+use struqture::{CooSparseMatrix, StruqtureError};
+
+fn dense_eigenvalues(coo: CooSparseMatrix, number_spins: usize) -> Vec<f64> {
+    let dimension = 2usize.pow(number_spins as u32);
+    let mut matrix = nalgebra::DMatrix::<Complex64>::zeros(dimension, dimension);
+    let (values, (rows, columns)) = coo;
+    for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+        matrix[(row, column)] += value;
+    }
+    let mut eigenvalues: Vec<f64> = matrix
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .copied()
+        .collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    eigenvalues
+}
+
+fn encodings(number_modes: usize) -> Vec<Box<dyn FermionToSpinEncoding>> {
+    vec![
+        Box::new(JordanWignerEncoding::new(number_modes)),
+        Box::new(ParityEncoding::new(number_modes)),
+        Box::new(BravyiKitaevEncoding::new(number_modes)),
+        Box::new(TernaryTreeEncoding::new(number_modes)),
+    ]
+}
+
+// Test that the Majorana operators of all encodings are mutually anticommuting
+#[test]
+fn test_majorana_operators_anticommute() {
+    for number_modes in [1, 2, 5, 7] {
+        for encoding in encodings(number_modes) {
+            assert_eq!(encoding.number_modes(), number_modes);
+            assert_eq!(encoding.number_spins(), number_modes);
+            assert_eq!(encoding.spin_overhead(), 0);
+            let majorana_operators = encoding.majorana_operators();
+            assert_eq!(majorana_operators.len(), 2 * number_modes);
+            for (index, left) in majorana_operators.iter().enumerate() {
+                assert!(left.current_number_spins() <= number_modes);
+                for right in majorana_operators.iter().skip(index + 1) {
+                    assert!(!left.commutes_with(right));
+                }
+            }
+        }
+    }
+}
+
+// Test the Majorana operators of the ternary tree encoding
+#[test]
+fn test_ternary_tree_majorana_operators() {
+    assert_eq!(
+        TernaryTreeEncoding::new(1).majorana_operators(),
+        vec![PauliProduct::new().x(0), PauliProduct::new().y(0)]
+    );
+    assert_eq!(
+        TernaryTreeEncoding::new(2).majorana_operators(),
+        vec![
+            PauliProduct::new().x(0).x(1),
+            PauliProduct::new().x(0).y(1),
+            PauliProduct::new().x(0).z(1),
+            PauliProduct::new().y(0),
+        ]
+    );
+    // The Pauli weight grows logarithmically with the number of modes
+    let majorana_operators = TernaryTreeEncoding::new(13).majorana_operators();
+    assert!(majorana_operators.iter().all(|pp| pp.len() <= 3));
+}
+
+// Test the number operators in the parity encoding
+#[test]
+fn test_parity_number_operators() {
+    let encoding = ParityEncoding::new(3);
+    let expected_parities = [
+        PauliProduct::new().z(0),
+        PauliProduct::new().z(0).z(1),
+        PauliProduct::new().z(1).z(2),
+    ];
+    for (mode, parity) in expected_parities.into_iter().enumerate() {
+        let mut fh = FermionHamiltonian::new();
+        fh.add_operator_product(
+            HermitianFermionProduct::new([mode], [mode]).unwrap(),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+        .unwrap();
+        let mut sh = SpinHamiltonian::new();
+        sh.add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.5))
+            .unwrap();
+        sh.add_operator_product(parity, CalculatorFloat::from(-0.5))
+            .unwrap();
+        assert_eq!(encoding.encode(&fh).unwrap().truncate(1e-12), sh);
+    }
+}
+
+// Test that all encodings of a Hamiltonian have the same spectrum
+#[test]
+fn test_encodings_spectrum() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [3]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [4]).unwrap(),
+        CalculatorComplex::new(-0.3, 0.0),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0, 2], [1, 4]).unwrap(),
+        CalculatorComplex::new(0.7, -0.2),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([2], [2]).unwrap(),
+        CalculatorComplex::new(0.4, 0.0),
+    )
+    .unwrap();
+
+    let jw_spectrum = dense_eigenvalues(
+        JordanWignerEncoding::new(5)
+            .encode(&fh)
+            .unwrap()
+            .sparse_matrix_coo(Some(5))
+            .unwrap(),
+        5,
+    );
+    for encoding in encodings(5) {
+        let spectrum = dense_eigenvalues(
+            encoding
+                .encode(&fh)
+                .unwrap()
+                .sparse_matrix_coo(Some(5))
+                .unwrap(),
+            5,
+        );
+        for (value, jw_value) in spectrum.iter().zip(jw_spectrum.iter()) {
+            assert!((value - jw_value).abs() < 1e-10);
+        }
+    }
+}
+
+// Test that the encodings of a FermionOperator agree with the encodings of the corresponding FermionHamiltonian
+#[test]
+fn test_encode_operator() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([2], [0]).unwrap(),
+        CalculatorComplex::new(1.0, -0.5),
+    )
+    .unwrap();
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+
+    for encoding in encodings(3) {
+        let spin_operator = encoding.encode_operator(&fo).unwrap();
+        let spin_hamiltonian = encoding.encode(&fh).unwrap();
+        assert!((spin_operator - SpinOperator::from(spin_hamiltonian))
+            .truncate(1e-12)
+            .is_empty());
+    }
+}
+
+// Test that the encodings fail for operators acting on too many modes
+#[test]
+fn test_encodings_number_modes_exceeded() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [3]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [3]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    for encoding in encodings(3) {
+        assert_eq!(
+            encoding.encode(&fh),
+            Err(StruqtureError::NumberModesExceeded)
+        );
+        assert_eq!(
+            encoding.encode_operator(&fo),
+            Err(StruqtureError::NumberModesExceeded)
+        );
+    }
+}
@@ -10,18 +10,19 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use struqture::fermions::{
     FermionHamiltonian, FermionHamiltonianSystem, FermionLindbladNoiseOperator,
     FermionLindbladNoiseSystem, FermionLindbladOpenSystem, FermionOperator, FermionProduct,
     FermionSystem, HermitianFermionProduct,
 };
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{jordan_wigner_batch, JordanWignerFermionToSpin};
 use struqture::prelude::*;
 use struqture::spins::{
     DecoherenceProduct, PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinHamiltonianSystem,
     SpinLindbladNoiseOperator, SpinLindbladNoiseSystem, SpinLindbladOpenSystem, SpinOperator,
-    SpinSystem,
+    SpinSystem, ToSparseMatrixOperator,
 };
 
 #[test]
@@ -53,6 +54,81 @@ fn test_jw_fermion_product() {
     assert_eq!(fp.jordan_wigner(), so)
 }
 
+fn dense_eigenvalues(operator: &SpinOperator, number_spins: usize) -> Vec<f64> {
+    let dimension = 2usize.pow(number_spins as u32);
+    let mut matrix = nalgebra::DMatrix::<Complex64>::zeros(dimension, dimension);
+    let (values, (rows, columns)) = operator.sparse_matrix_coo(Some(number_spins)).unwrap();
+    for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+        matrix[(row, column)] += value;
+    }
+    let mut eigenvalues: Vec<f64> = matrix
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .copied()
+        .collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    eigenvalues
+}
+
+#[test]
+fn test_jw_with_ordering() {
+    let products = [
+        (FermionProduct::new([0], [2]).unwrap(), 0.5),
+        (FermionProduct::new([2], [0]).unwrap(), 0.5),
+        (FermionProduct::new([1], [1]).unwrap(), -1.0),
+        (FermionProduct::new([0, 1], [0, 1]).unwrap(), 2.0),
+        (FermionProduct::new([0, 2], [1, 2]).unwrap(), 0.3),
+        (FermionProduct::new([1, 2], [0, 2]).unwrap(), 0.3),
+    ];
+
+    let mut default_image = SpinOperator::new();
+    let mut reordered_image = SpinOperator::new();
+    for (product, coefficient) in products.iter() {
+        assert_eq!(
+            product.jordan_wigner_with_ordering(&[0, 1, 2]).unwrap(),
+            product.jordan_wigner()
+        );
+        default_image += product.jordan_wigner() * CalculatorFloat::from(*coefficient);
+        reordered_image += product.jordan_wigner_with_ordering(&[2, 0, 1]).unwrap()
+            * CalculatorFloat::from(*coefficient);
+    }
+    assert_ne!(default_image, reordered_image);
+
+    let default_spectrum = dense_eigenvalues(&default_image, 3);
+    let reordered_spectrum = dense_eigenvalues(&reordered_image, 3);
+    for (a, b) in default_spectrum.iter().zip(reordered_spectrum.iter()) {
+        assert!((a - b).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_jw_with_ordering_error() {
+    let fp = FermionProduct::new([0], [2]).unwrap();
+    assert!(fp.jordan_wigner_with_ordering(&[0, 0, 1]).is_err());
+    assert!(fp.jordan_wigner_with_ordering(&[0, 3, 1]).is_err());
+    assert!(fp.jordan_wigner_with_ordering(&[1, 0]).is_err());
+}
+
+#[test]
+fn test_jw_batch() {
+    let products = vec![
+        FermionProduct::new([], []).unwrap(),
+        FermionProduct::new([0], [0]).unwrap(),
+        FermionProduct::new([1], [2]).unwrap(),
+        FermionProduct::new([0, 3], [1, 4]).unwrap(),
+        FermionProduct::new([2, 5, 6], [0]).unwrap(),
+        FermionProduct::new([], [1, 3, 4]).unwrap(),
+        FermionProduct::new([1], [2]).unwrap(),
+    ];
+    let batch = jordan_wigner_batch(&products);
+    assert_eq!(batch.len(), products.len());
+    for (product, spin_operator) in products.iter().zip(batch.iter()) {
+        assert_eq!(&product.jordan_wigner(), spin_operator);
+    }
+    assert!(jordan_wigner_batch(&[]).is_empty());
+}
+
 #[test]
 fn test_jw_hermitian_fermion_product() {
     let hfp = HermitianFermionProduct::new([1], [2]).unwrap();
@@ -12,7 +12,7 @@
 
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use struqture::fermions::*;
-use struqture::mappings::JordanWignerSpinToFermion;
+use struqture::mappings::{JordanWignerFermionToSpin, JordanWignerSpinToFermion};
 use struqture::prelude::*;
 use struqture::spins::*;
 
@@ -251,3 +251,50 @@ fn test_jw_spin_systems() {
 
     assert_eq!(sos.jordan_wigner(), fos);
 }
+
+// Test that the Jordan-Wigner transformation and its inverse round-trip operators and hamiltonians
+#[test]
+fn test_jw_round_trip() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).y(2),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    assert_eq!(so.jordan_wigner().jordan_wigner().truncate(1e-12), so);
+
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0, 3], [1]).unwrap(),
+        CalculatorComplex::new(2.0, -1.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([2], [2]).unwrap(),
+        CalculatorComplex::new(0.5, 0.0),
+    )
+    .unwrap();
+    assert_eq!(fo.jordan_wigner().jordan_wigner().truncate(1e-12), fo);
+
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0).x(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().z(2), CalculatorFloat::from(-0.5))
+        .unwrap();
+    assert_eq!(sh.jordan_wigner().jordan_wigner().truncate(1e-12), sh);
+
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::new(-2.0, 0.0),
+    )
+    .unwrap();
+    assert_eq!(fh.jordan_wigner().jordan_wigner().truncate(1e-12), fh);
+}
@@ -10,5 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bravyi_kitaev;
+mod encodings;
 mod jordan_wigner_fermion_to_spin;
 mod jordan_wigner_spin_to_fermion;
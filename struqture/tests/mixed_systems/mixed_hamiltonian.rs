@@ -136,6 +136,21 @@ fn internal_map_len() {
     assert_eq!(mo.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the MixedHamiltonian
+#[test]
+fn number_of_terms() {
+    let pp_2: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedHamiltonian::new(1, 1, 1);
+    mo.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(mo.number_of_terms(), 1_usize);
+    assert_eq!(mo.number_of_terms(), mo.len());
+}
+
 // Test the iter, keys and values functions of the MixedHamiltonian
 #[test]
 fn internal_map_keys() {
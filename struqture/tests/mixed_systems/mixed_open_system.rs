@@ -15,14 +15,14 @@
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
-use struqture::bosons::BosonProduct;
+use struqture::bosons::{BosonLindbladOpenSystem, BosonProduct, HermitianBosonProduct};
 use struqture::fermions::FermionProduct;
 use struqture::mixed_systems::{
     HermitianMixedProduct, MixedDecoherenceProduct, MixedHamiltonianSystem,
     MixedLindbladNoiseSystem, MixedLindbladOpenSystem,
 };
 use struqture::prelude::*;
-use struqture::spins::{DecoherenceProduct, PauliProduct};
+use struqture::spins::{DecoherenceProduct, PauliProduct, SpinLindbladOpenSystem};
 #[cfg(feature = "json_schema")]
 use test_case::test_case;
 
@@ -73,6 +73,101 @@ fn new_system_none() {
     assert_eq!(vec![0], system.number_fermionic_modes());
 }
 
+// Test the from_subsystems function of the MixedLindbladOpenSystem
+#[test]
+fn from_subsystems() {
+    let mut spin_system = SpinLindbladOpenSystem::new(Some(2));
+    spin_system
+        .system_mut()
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    spin_system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::from(0.1),
+        )
+        .unwrap();
+    let mut boson_system = BosonLindbladOpenSystem::new(Some(1));
+    boson_system
+        .system_mut()
+        .set(
+            HermitianBosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    boson_system
+        .noise_mut()
+        .set(
+            (
+                BosonProduct::new([], [0]).unwrap(),
+                BosonProduct::new([], [0]).unwrap(),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    let mut expected = MixedLindbladOpenSystem::new([Some(2)], [Some(1)], []);
+    expected
+        .system_mut()
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([], []).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .system_mut()
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    let dephasing = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(1)],
+        [BosonProduct::new([], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    let decay = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new()],
+        [BosonProduct::new([], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    expected
+        .noise_mut()
+        .set((dephasing.clone(), dephasing), CalculatorComplex::from(0.1))
+        .unwrap();
+    expected
+        .noise_mut()
+        .set((decay.clone(), decay), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let combined =
+        MixedLindbladOpenSystem::from_subsystems(Some(spin_system), Some(boson_system), None)
+            .unwrap();
+    assert_eq!(combined, expected);
+    assert_eq!(combined.number_spins(), vec![2]);
+    assert_eq!(combined.number_bosonic_modes(), vec![1]);
+    assert!(combined.number_fermionic_modes().is_empty());
+
+    let empty = MixedLindbladOpenSystem::from_subsystems(None, None, None).unwrap();
+    assert_eq!(empty, MixedLindbladOpenSystem::new([], [], []));
+}
+
 // Test the group function of the MixedLindbladOpenSystem
 #[test]
 fn group() {
@@ -674,6 +769,46 @@ fn clone_partial_eq() {
     assert!(slos != slos_2);
 }
 
+// Test the to_hdf5 and from_hdf5 functions of the MixedLindbladOpenSystem
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut system = MixedLindbladOpenSystem::new([Some(2)], [None], []);
+    system
+        .system_mut()
+        .set(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let dephasing = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(1)],
+        [BosonProduct::new([], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    system
+        .noise_mut()
+        .set((dephasing.clone(), dephasing), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("mixed_open_system_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("mixed_open_system").unwrap();
+        system.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("mixed_open_system").unwrap();
+    let read = MixedLindbladOpenSystem::from_hdf5(&group).unwrap();
+    assert_eq!(read, system);
+}
+
 #[test]
 fn serde_json() {
     let pp: HermitianMixedProduct = HermitianMixedProduct::new(
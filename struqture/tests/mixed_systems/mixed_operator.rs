@@ -14,10 +14,13 @@
 
 // use num_complex::Complex64;
 use bincode::{deserialize, serialize};
+use nalgebra as na;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::str::FromStr;
 use struqture::bosons::BosonProduct;
 use struqture::fermions::FermionProduct;
 use struqture::prelude::*;
@@ -139,6 +142,289 @@ fn internal_map_len() {
     assert_eq!(mo.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the MixedOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(mo.number_of_terms(), 1_usize);
+    assert_eq!(mo.number_of_terms(), mo.len());
+}
+
+// Test the clear function of the MixedOperator
+#[test]
+fn clear() {
+    let pp_2: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(2), PauliProduct::new()],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedOperator::new(2, 1, 1);
+    mo.set(pp_2.clone(), CalculatorComplex::from(0.5)).unwrap();
+    mo.clear();
+    assert!(mo.is_empty());
+    assert_eq!(mo, MixedOperator::new(2, 1, 1));
+    assert_eq!(mo.current_number_spins(), vec![0_usize, 0_usize]);
+    assert_eq!(mo.current_number_bosonic_modes(), vec![0_usize]);
+    assert_eq!(mo.current_number_fermionic_modes(), vec![0_usize]);
+    // The subsystem structure is still enforced after clearing
+    assert!(mo.set(pp_2, CalculatorComplex::from(0.5)).is_ok());
+    let wrong_subsystems: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    assert!(mo
+        .set(wrong_subsystems, CalculatorComplex::from(0.5))
+        .is_err());
+}
+
+// Test the sparse_matrix_coo function of the MixedOperator against a manual Kronecker construction
+#[test]
+fn sparse_matrix_coo() {
+    let c = |re: f64| Complex64::new(re, 0.0);
+    let x = na::DMatrix::from_row_slice(2, 2, &[c(0.0), c(1.0), c(1.0), c(0.0)]);
+    let z = na::DMatrix::from_row_slice(2, 2, &[c(1.0), c(0.0), c(0.0), c(-1.0)]);
+    let number = na::DMatrix::from_diagonal(&na::DVector::from_vec(vec![c(0.0), c(1.0), c(2.0)]));
+    let identity = na::DMatrix::<Complex64>::identity(3, 3);
+    let to_dense = |(values, (rows, columns)): (Vec<Complex64>, (Vec<usize>, Vec<usize>)),
+                    dimension: usize| {
+        let mut dense = na::DMatrix::<Complex64>::zeros(dimension, dimension);
+        for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+            dense[(row, column)] += value;
+        }
+        dense
+    };
+
+    let mut mo = MixedOperator::new(1, 1, 0);
+    mo.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a0:").unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    let matrix = to_dense(mo.sparse_matrix_coo(&[1], &[3], &[]).unwrap(), 6);
+    let expected = x.kronecker(&number) * c(0.5);
+    assert!((matrix - expected).norm() < 1e-12);
+
+    // The boson mode 1 is the more significant part of the boson index
+    mo.add_operator_product(
+        MixedProduct::from_str(":S0Z:Bc1a1:").unwrap(),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    let matrix = to_dense(mo.sparse_matrix_coo(&[1], &[3], &[]).unwrap(), 18);
+    let expected = x.kronecker(&identity.kronecker(&number)) * c(0.5)
+        + z.kronecker(&number.kronecker(&identity)) * Complex64::new(0.0, 1.0);
+    assert!((matrix - expected).norm() < 1e-12);
+
+    assert!(matches!(
+        mo.sparse_matrix_coo(&[1], &[3], &[2]),
+        Err(StruqtureError::MissmatchedNumberSubsystems { .. })
+    ));
+}
+
+// Test that the sparse_matrix_coo function of the MixedOperator returns an error if the dimension overflows
+#[test]
+fn sparse_matrix_coo_dimension_overflow() {
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a1:Fc0a0:").unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+
+    // A single subsystem exceeding usize
+    assert!(matches!(
+        mo.sparse_matrix_coo(&[usize::BITS as usize], &[2], &[1]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        mo.sparse_matrix_coo(&[1], &[usize::MAX], &[1]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        mo.sparse_matrix_coo(&[1], &[2], &[usize::MAX]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    // Every subsystem fits into usize, the whole matrix does not
+    let half = usize::BITS as usize / 2;
+    assert!(matches!(
+        mo.sparse_matrix_coo(&[half], &[2], &[half]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the partial_trace_bosons function of the MixedOperator
+#[test]
+fn partial_trace_bosons() {
+    let mut mo = MixedOperator::new(1, 1, 0);
+    // Number operator: traces to 0 + 1 + 2 for a cutoff of 3
+    let number = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    // Annihilator c0: off-diagonal, traces to zero
+    let annihilator = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    // Boson identity: traces to the cutoff
+    let identity = MixedProduct::new(
+        [PauliProduct::new().y(0)],
+        [BosonProduct::new([], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    mo.set(number, CalculatorComplex::from(1.0)).unwrap();
+    mo.set(annihilator, CalculatorComplex::from(2.0)).unwrap();
+    mo.set(identity, CalculatorComplex::from(0.5)).unwrap();
+
+    let traced = mo.partial_trace_bosons(&[3]).unwrap();
+    let mut expected = MixedOperator::new(1, 0, 0);
+    expected
+        .set(
+            MixedProduct::new([PauliProduct::new().z(0)], [], []).unwrap(),
+            CalculatorComplex::from(3.0),
+        )
+        .unwrap();
+    expected
+        .set(
+            MixedProduct::new([PauliProduct::new().y(0)], [], []).unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    assert_eq!(traced, expected);
+
+    // The surviving coefficients depend on the cutoff
+    let traced = mo.partial_trace_bosons(&[4]).unwrap();
+    assert_eq!(
+        traced.get(&MixedProduct::new([PauliProduct::new().z(0)], [], []).unwrap()),
+        &CalculatorComplex::from(6.0)
+    );
+
+    assert!(mo.partial_trace_bosons(&[3, 3]).is_err());
+}
+
+// Test the substitute_parameters function of the MixedOperator
+#[test]
+fn substitute_parameters() {
+    let mut mo = MixedOperator::new(1, 1, 1);
+    let product = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    mo.set(product.clone(), CalculatorComplex::from("g * 3"))
+        .unwrap();
+
+    let mut map: HashMap<String, f64> = HashMap::new();
+    map.insert("g".to_string(), 0.5);
+    let substituted = mo.substitute_parameters(&map).unwrap();
+    assert_eq!(substituted.get(&product), &CalculatorComplex::from(1.5));
+    assert_eq!(substituted.len(), 1);
+
+    assert!(mo.substitute_parameters(&HashMap::new()).is_err());
+}
+
+// Test the truncate_by_subsystem function of the MixedOperator
+#[test]
+fn truncate_by_subsystem() {
+    let mut mo = MixedOperator::new(1, 1, 1);
+    let pure_spin = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let boson_coupled = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let large_fermion = MixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let identity = MixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    mo.set(pure_spin.clone(), CalculatorComplex::from(0.01))
+        .unwrap();
+    mo.set(boson_coupled.clone(), CalculatorComplex::from(0.01))
+        .unwrap();
+    mo.set(large_fermion.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    mo.set(identity.clone(), CalculatorComplex::from(0.001))
+        .unwrap();
+
+    let truncated = mo.truncate_by_subsystem(0.0, 0.1, 0.5);
+    assert_eq!(truncated.len(), 3);
+    assert_eq!(truncated.get(&pure_spin), &CalculatorComplex::from(0.01));
+    assert_eq!(truncated.get(&boson_coupled), &CalculatorComplex::from(0.0));
+    assert_eq!(truncated.get(&large_fermion), &CalculatorComplex::from(1.0));
+    assert_eq!(truncated.get(&identity), &CalculatorComplex::from(0.001));
+
+    // A spin-boson term is truncated with the larger of both thresholds
+    let truncated = mo.truncate_by_subsystem(0.1, 0.0, 0.0);
+    assert_eq!(truncated.get(&pure_spin), &CalculatorComplex::from(0.0));
+    assert_eq!(truncated.get(&boson_coupled), &CalculatorComplex::from(0.0));
+}
+
+// Test the support_histogram function of the MixedOperator
+#[test]
+fn support_histogram() {
+    let pure_spin: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(0), PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let spin_boson: MixedProduct = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new().x(1)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let other_pure_spin: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().y(2), PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedOperator::new(2, 1, 1);
+    mo.set(pure_spin, CalculatorComplex::from(0.5)).unwrap();
+    mo.set(spin_boson, CalculatorComplex::from(1.0)).unwrap();
+
+    let mut expected = BTreeMap::new();
+    expected.insert((vec![true, false], vec![false], vec![false]), 1);
+    expected.insert((vec![false, true], vec![true], vec![false]), 1);
+    assert_eq!(mo.support_histogram(), expected);
+
+    mo.set(other_pure_spin, CalculatorComplex::from(2.0))
+        .unwrap();
+    expected.insert((vec![true, false], vec![false], vec![false]), 2);
+    assert_eq!(mo.support_histogram(), expected);
+}
+
 // Test the iter, keys and values functions of the MixedOperator
 #[test]
 fn internal_map_keys() {
@@ -441,6 +727,101 @@ fn sub_so_so() {
     assert_eq!(mo_0 - mo_1, Ok(mo_0_1));
 }
 
+// Test the subtraction of an iterator of terms: MixedOperator - Vec<(MixedProduct, CalculatorComplex)>
+#[test]
+fn sub_so_terms() {
+    let mut mo = MixedOperator::new(1, 1, 0);
+    mo.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a0:").unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let terms = vec![(
+        MixedProduct::from_str(":S1Z:Bc1:").unwrap(),
+        CalculatorComplex::from(2.0),
+    )];
+    let mut expected = mo.clone();
+    expected
+        .add_operator_product(
+            MixedProduct::from_str(":S1Z:Bc1:").unwrap(),
+            CalculatorComplex::from(-2.0),
+        )
+        .unwrap();
+    assert_eq!(mo.clone() - terms, Ok(expected));
+
+    let terms = vec![(
+        MixedProduct::from_str(":S1Z:S0X:Bc1:").unwrap(),
+        CalculatorComplex::from(2.0),
+    )];
+    assert!((mo - terms).is_err());
+}
+
+// Test the checked subtraction: MixedOperator - MixedOperator
+#[test]
+fn checked_sub_so_so() {
+    let pp_0: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let pp_1: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().x(1)],
+        [BosonProduct::new([1], [2]).unwrap()],
+        [FermionProduct::new([1], [3]).unwrap()],
+    )
+    .unwrap();
+    let mut mo_0 = MixedOperator::new(1, 1, 1);
+    mo_0.add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut mo_1 = MixedOperator::new(1, 1, 1);
+    mo_1.add_operator_product(pp_1.clone(), CalculatorComplex::from(-1.0))
+        .unwrap();
+    let mut mo_0_1 = MixedOperator::new(1, 1, 1);
+    mo_0_1
+        .add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    mo_0_1
+        .add_operator_product(pp_1, CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(mo_0.clone().checked_sub(mo_1), Ok(mo_0_1));
+
+    let pp_2: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [
+            BosonProduct::new([0], [3]).unwrap(),
+            BosonProduct::new([1], [1]).unwrap(),
+        ],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut mo_2 = MixedOperator::new(1, 2, 1);
+    mo_2.add_operator_product(pp_2, CalculatorComplex::from(1.0))
+        .unwrap();
+    let error = StruqtureError::MissmatchedNumberSubsystems {
+        target_number_spin_subsystems: 1,
+        target_number_boson_subsystems: 1,
+        target_number_fermion_subsystems: 1,
+        actual_number_spin_subsystems: 1,
+        actual_number_boson_subsystems: 2,
+        actual_number_fermion_subsystems: 1,
+    };
+    assert_eq!(mo_0.clone().checked_sub(mo_2.clone()), Err(error));
+    // The `-` operator goes through checked_sub and rejects the mismatched subsystems as well
+    assert!((mo_0.clone() - mo_2).is_err());
+
+    // Empty operators on different subsystems are rejected as well
+    let error = StruqtureError::MissmatchedNumberSubsystems {
+        target_number_spin_subsystems: 1,
+        target_number_boson_subsystems: 1,
+        target_number_fermion_subsystems: 1,
+        actual_number_spin_subsystems: 1,
+        actual_number_boson_subsystems: 2,
+        actual_number_fermion_subsystems: 1,
+    };
+    assert_eq!(mo_0.checked_sub(MixedOperator::new(1, 2, 1)), Err(error));
+}
+
 // Test the multiplication: SpinOperator * Calculatorcomplex
 #[test]
 fn mul_so_cf() {
@@ -514,6 +895,86 @@ fn mul_so_so() {
     assert_eq!(mo_0 * mo_1, Ok(mo_0_1));
 }
 
+// Test the in-place arithmetic operators of MixedOperator: +=, -= and *=
+#[test]
+fn assign_operators() {
+    let mut a = MixedOperator::new(1, 1, 0);
+    a.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a0:").unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut b = MixedOperator::new(1, 1, 0);
+    b.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a0:").unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    b.add_operator_product(
+        MixedProduct::from_str(":S1Z:Bc1:").unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    let mut added = a.clone();
+    added += b.clone();
+    assert_eq!(added, (a.clone() + b.clone()).unwrap());
+
+    let mut subtracted = a.clone();
+    subtracted -= b.clone();
+    assert_eq!(subtracted, (a.clone() - b.clone()).unwrap());
+
+    let mut scaled = a.clone();
+    scaled *= CalculatorComplex::new(0.0, 2.0);
+    assert_eq!(scaled, a.clone() * CalculatorComplex::new(0.0, 2.0));
+    let mut scaled = a.clone();
+    scaled *= CalculatorFloat::from(3.0);
+    assert_eq!(scaled, a * CalculatorFloat::from(3.0));
+}
+
+// Test that += panics for MixedOperators defined on different subsystems
+#[test]
+#[should_panic(expected = "Cannot add MixedOperators defined on different subsystems")]
+fn add_assign_mismatched_subsystems() {
+    let mut a = MixedOperator::new(1, 1, 0);
+    a += MixedOperator::new(2, 1, 0);
+}
+
+// Test that -= panics for MixedOperators defined on different subsystems
+#[test]
+#[should_panic(expected = "Cannot subtract MixedOperators defined on different subsystems")]
+fn sub_assign_mismatched_subsystems() {
+    let mut a = MixedOperator::new(1, 1, 0);
+    a -= MixedOperator::new(1, 2, 0);
+}
+
+// Test that += and -= leave the MixedOperator unchanged when they panic for mismatched subsystems
+#[test]
+fn assign_operators_mismatched_subsystems_atomic() {
+    let mut a = MixedOperator::new(1, 1, 0);
+    a.add_operator_product(
+        MixedProduct::from_str(":S0X:Bc0a0:").unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut b = MixedOperator::new(2, 1, 0);
+    b.add_operator_product(
+        MixedProduct::from_str(":S1Z:S0X:Bc1:").unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    let mut added = a.clone();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| added += b.clone()));
+    assert!(result.is_err());
+    assert_eq!(added, a);
+
+    let mut subtracted = a.clone();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| subtracted -= b));
+    assert!(result.is_err());
+    assert_eq!(subtracted, a);
+}
+
 // Test the Iter traits of FermionOperator: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -116,6 +116,20 @@ fn internal_map_len() {
     assert_eq!(mo.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the MixedPlusMinusOperator
+#[test]
+fn number_of_terms() {
+    let mp_2: MixedPlusMinusProduct = MixedPlusMinusProduct::new(
+        [PlusMinusProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    );
+    let mut mo = MixedPlusMinusOperator::new(1, 1, 1);
+    mo.set(mp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(mo.number_of_terms(), 1_usize);
+    assert_eq!(mo.number_of_terms(), mo.len());
+}
+
 // Test the iter, keys and values functions of the MixedPlusMinusOperator
 #[test]
 fn internal_map_keys() {
@@ -682,6 +696,45 @@ fn to_mixed_product_3() {
     assert_eq!(mixed_op, test_new);
 }
 
+// Test the round trip MixedOperator -> MixedPlusMinusOperator -> MixedOperator
+#[test]
+fn round_trip_mixed_operator() {
+    let mut mixed_op = MixedOperator::new(2, 1, 1);
+    mixed_op
+        .add_operator_product(
+            MixedProduct::new(
+                [
+                    PauliProduct::from_str("0X1Z").unwrap(),
+                    PauliProduct::from_str("0Y").unwrap(),
+                ],
+                [BosonProduct::new([0], [1]).unwrap()],
+                [FermionProduct::new([0], [2]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    mixed_op
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new(), PauliProduct::from_str("2X").unwrap()],
+                [BosonProduct::new([], [0]).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(-2.0, 0.0),
+        )
+        .unwrap();
+
+    let mixed_pm_op = MixedPlusMinusOperator::from(mixed_op.clone());
+    assert_eq!(mixed_pm_op.current_number_spins(), vec![2, 3]);
+    let round_trip = MixedOperator::try_from(mixed_pm_op).unwrap();
+    assert_eq!(round_trip.current_number_spins(), vec![2, 3]);
+    assert_eq!(round_trip.current_number_bosonic_modes(), vec![2]);
+    assert_eq!(round_trip.current_number_fermionic_modes(), vec![3]);
+    assert_eq!(round_trip, mixed_op);
+}
+
 // Test the Iter traits of FermionOperator: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -1024,3 +1077,15 @@ fn test_mixed_plus_minus_operator_schema() {
 
     assert!(validation.is_ok());
 }
+
+// Test the min_supported_version_for_content function of the MixedPlusMinusOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut mpmo = MixedPlusMinusOperator::new(1, 0, 0);
+    mpmo.set(
+        MixedPlusMinusProduct::new([PlusMinusProduct::new().plus(0)], [], []),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(mpmo.min_supported_version_for_content(), (1, 2, 0));
+}
@@ -19,6 +19,7 @@ use qoqo_calculator::CalculatorComplex;
 use serde_test::{assert_tokens, Configure, Token};
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use struqture::bosons::*;
@@ -123,6 +124,122 @@ fn from_string_fail(stringformat: &str) {
     assert!(test_new.is_err());
 }
 
+// Test that the Kronecker product of the subsystem matrices gives the matrix of the MixedProduct
+#[test]
+fn subsystem_matrices() {
+    let mp = MixedProduct::from_str(":S0X:Bc0a0:").unwrap();
+    let matrices = mp.subsystem_matrices(&[1], &[3], &[]).unwrap();
+    assert_eq!(matrices.len(), 2);
+
+    let dimensions = [2, 3];
+    let mut kronecker: HashMap<(usize, usize), Complex64> = HashMap::new();
+    kronecker.insert((0, 0), Complex64::new(1.0, 0.0));
+    let mut dimension = 1;
+    for (matrix, factor_dimension) in matrices.iter().zip(dimensions) {
+        let (values, (rows, columns)) = matrix;
+        let mut new_kronecker = HashMap::new();
+        for ((row, column), value) in kronecker.iter() {
+            for ((factor_value, factor_row), factor_column) in
+                values.iter().zip(rows.iter()).zip(columns.iter())
+            {
+                new_kronecker.insert(
+                    (
+                        row * factor_dimension + factor_row,
+                        column * factor_dimension + factor_column,
+                    ),
+                    value * factor_value,
+                );
+            }
+        }
+        kronecker = new_kronecker;
+        dimension *= factor_dimension;
+    }
+    assert_eq!(dimension, 6);
+
+    // X on the spin tensored with the number operator on the boson mode
+    let mut expected: HashMap<(usize, usize), Complex64> = HashMap::new();
+    expected.insert((1, 4), Complex64::new(1.0, 0.0));
+    expected.insert((4, 1), Complex64::new(1.0, 0.0));
+    expected.insert((2, 5), Complex64::new(2.0, 0.0));
+    expected.insert((5, 2), Complex64::new(2.0, 0.0));
+    assert_eq!(kronecker.len(), expected.len());
+    for (key, value) in expected.iter() {
+        assert!((kronecker[key] - value).norm() < 1e-12);
+    }
+
+    // Creating a boson above the cutoff is truncated
+    let creator = MixedProduct::from_str(":Bc0:").unwrap();
+    let matrices = creator.subsystem_matrices(&[], &[2], &[]).unwrap();
+    assert_eq!(
+        matrices[0],
+        (vec![Complex64::new(1.0, 0.0)], (vec![1], vec![0]))
+    );
+
+    assert!(matches!(
+        mp.subsystem_matrices(&[1], &[], &[]),
+        Err(StruqtureError::MissmatchedNumberSubsystems { .. })
+    ));
+    assert_eq!(
+        mp.subsystem_matrices(&[0], &[3], &[]),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test constructing a MixedProduct from labeled subsystems
+#[test]
+fn from_labeled() {
+    let s0 = PauliProduct::new().x(0);
+    let s2 = PauliProduct::new().z(1).y(2);
+    let b1 = BosonProduct::new([0], [1]).unwrap();
+    let f0 = FermionProduct::new([0], [2]).unwrap();
+
+    let labeled = MixedProduct::from_labeled(
+        &[(2, s2.clone()), (0, s0.clone())],
+        &[(1, b1.clone())],
+        &[(0, f0.clone())],
+    )
+    .unwrap();
+    let positional = MixedProduct::new(
+        [s0, PauliProduct::new(), s2.clone()],
+        [BosonProduct::new([], []).unwrap(), b1],
+        [f0],
+    )
+    .unwrap();
+    assert_eq!(labeled, positional);
+    assert_eq!(labeled.spins().len(), 3);
+
+    let empty = MixedProduct::from_labeled(&[], &[], &[]).unwrap();
+    assert_eq!(empty, MixedProduct::new([], [], []).unwrap());
+
+    let duplicate = MixedProduct::from_labeled(&[(1, s2.clone()), (1, s2)], &[], &[]);
+    assert!(matches!(
+        duplicate,
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test that from_labeled rejects subsystem slots beyond the maximal number of subsystems
+#[test]
+fn from_labeled_slot_too_large() {
+    let bounded = MixedProduct::from_labeled(&[(63, PauliProduct::new().x(0))], &[], &[]).unwrap();
+    assert_eq!(bounded.spins().len(), 64);
+
+    let too_large = MixedProduct::from_labeled(
+        &[],
+        &[],
+        &[(usize::MAX, FermionProduct::new([0], [0]).unwrap())],
+    );
+    assert!(matches!(
+        too_large,
+        Err(StruqtureError::GenericError { .. })
+    ));
+    let too_large = MixedProduct::from_labeled(&[(64, PauliProduct::new())], &[], &[]);
+    assert!(matches!(
+        too_large,
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
 #[test_case(PauliProduct::from_str("").unwrap(), &[], &[], &[], &[]; "empty")]
 #[test_case(PauliProduct::from_str("0X").unwrap(), &[0], &[1], &[0], &[1]; "0 - 1")]
 #[test_case(PauliProduct::from_str("0Y").unwrap(), &[1], &[], &[1], &[]; "1 - empty")]
@@ -12,8 +12,8 @@
 
 //! Integration test for public API of DecoherenceOperator
 
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-// use num_complex::Complex64;
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
@@ -21,7 +21,7 @@ use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{DecoherenceOperator, DecoherenceProduct, PauliProduct, SpinOperator};
-use struqture::SpinIndex;
+use struqture::{SpinIndex, StruqtureError};
 use test_case::test_case;
 
 // Test the new function of the DecoherenceOperator
@@ -69,6 +69,16 @@ fn internal_map_len() {
     assert_eq!(so.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the DecoherenceOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: DecoherenceProduct = DecoherenceProduct::new().z(2);
+    let mut so = DecoherenceOperator::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
+
 // Test the set, get and keys/values/iter functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -577,6 +587,73 @@ fn serde_json() {
     assert_eq!(so, deserialized);
 }
 
+// Test the apply_to_statevector function of the DecoherenceOperator
+#[test]
+fn apply_to_statevector() {
+    let mut so = DecoherenceOperator::new();
+    so.set(
+        DecoherenceProduct::new().iy(0),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut output = vec![Complex64::new(0.0, 0.0); 2];
+
+    // iY |0> = -|1>
+    so.apply_to_statevector(
+        &[Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        vec![Complex64::new(0.0, 0.0), Complex64::new(-1.0, 0.0)]
+    );
+    // iY |1> = |0>
+    so.apply_to_statevector(
+        &[Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]
+    );
+
+    // 0.5 * X_0 Z_1 + 2i * iY_1 applied to |01> (qubit 0 in state |1>)
+    let mut so = DecoherenceOperator::new();
+    so.set(
+        DecoherenceProduct::new().x(0).z(1),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    so.set(
+        DecoherenceProduct::new().iy(1),
+        CalculatorComplex::new(0.0, 2.0),
+    )
+    .unwrap();
+    let mut state = vec![Complex64::new(0.0, 0.0); 4];
+    state[1] = Complex64::new(1.0, 0.0);
+    let mut output = vec![Complex64::new(0.0, 0.0); 4];
+    so.apply_to_statevector(&state, &mut output).unwrap();
+    assert_eq!(
+        output,
+        vec![
+            Complex64::new(0.5, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.0, -2.0)
+        ]
+    );
+
+    // Errors for too many spins and wrong lengths
+    let mut short_output = vec![Complex64::new(0.0, 0.0); 2];
+    assert_eq!(
+        so.apply_to_statevector(&state[..2], &mut short_output),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(so.apply_to_statevector(&state, &mut short_output).is_err());
+}
+
 /// Test DecoherenceOperator Serialization and Deserialization traits (readable)
 #[test]
 fn serde_readable() {
@@ -0,0 +1,126 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of FrozenSpinOperator
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+use struqture::prelude::*;
+use struqture::spins::{FrozenSpinOperator, PauliProduct, SpinOperator, ToSparseMatrixOperator};
+
+fn create_operator() -> SpinOperator {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().z(0).y(2),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from("a"))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(-1.0, 0.0))
+        .unwrap();
+    so
+}
+
+// Test that get of the FrozenSpinOperator returns the same values as the SpinOperator
+#[test]
+fn get_and_iter() {
+    let so = create_operator();
+    let frozen = so.clone().freeze();
+
+    assert_eq!(frozen.len(), so.len());
+    assert!(!frozen.is_empty());
+    assert!(FrozenSpinOperator::default().is_empty());
+    assert_eq!(frozen.current_number_spins(), so.current_number_spins());
+    for (key, value) in so.iter() {
+        assert_eq!(frozen.get(key), value);
+    }
+    assert_eq!(
+        frozen.get(&PauliProduct::new().y(5)),
+        &CalculatorComplex::ZERO
+    );
+
+    let keys: Vec<&PauliProduct> = frozen.keys().collect();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+    assert_eq!(keys, sorted_keys);
+    assert_eq!(frozen.values().len(), so.len());
+    assert_eq!(frozen.iter().len(), so.len());
+
+    assert_eq!(frozen.thaw(), so);
+}
+
+// Test that the matrix of the FrozenSpinOperator is the same as the matrix of the SpinOperator
+#[test]
+fn sparse_matrix() {
+    let mut so = create_operator();
+    so.remove(&PauliProduct::new().z(1));
+    let frozen = FrozenSpinOperator::from(so.clone());
+
+    assert_eq!(
+        frozen.sparse_matrix(None).unwrap(),
+        so.sparse_matrix(None).unwrap()
+    );
+    assert_eq!(
+        frozen.sparse_matrix(Some(4)).unwrap(),
+        so.sparse_matrix(Some(4)).unwrap()
+    );
+    let (values, (rows, columns)) = frozen.sparse_matrix_coo(Some(3)).unwrap();
+    let coo_matrix: HashMap<(usize, usize), Complex64> =
+        rows.into_iter().zip(columns).zip(values).collect();
+    assert_eq!(coo_matrix, so.sparse_matrix(Some(3)).unwrap());
+
+    let symbolic = create_operator().freeze();
+    assert!(symbolic.sparse_matrix(None).is_err());
+}
+
+// Test that the FrozenSpinOperator is modified by thawing it into a SpinOperator
+#[test]
+fn thaw_modify_freeze() {
+    let so = create_operator();
+    let frozen = so.clone().freeze();
+
+    let mut thawed = frozen.clone().thaw();
+    thawed
+        .set(PauliProduct::new().x(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    thawed.remove(&PauliProduct::new());
+    let refrozen = thawed.freeze();
+
+    let keys: Vec<&PauliProduct> = refrozen.keys().collect();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+    assert_eq!(keys, sorted_keys);
+    assert_eq!(refrozen.len(), so.len());
+    assert_eq!(
+        refrozen.get(&PauliProduct::new().x(1)),
+        &CalculatorComplex::from(2.0)
+    );
+    assert_eq!(refrozen.get(&PauliProduct::new()), &CalculatorComplex::ZERO);
+    assert_eq!(SpinOperator::from(frozen), so);
+}
+
+// Test the serialization of the FrozenSpinOperator
+#[test]
+fn serde() {
+    let so = create_operator();
+    let frozen = so.clone().freeze();
+
+    let serialized = serde_json::to_string(&frozen).unwrap();
+    let thawed: SpinOperator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(thawed, so);
+    let deserialized: FrozenSpinOperator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, frozen);
+}
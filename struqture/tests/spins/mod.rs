@@ -23,6 +23,10 @@ mod decoherence_operator;
 
 mod spin_operator;
 
+mod frozen_spin_operator;
+
+mod trotter_schedule;
+
 mod plus_minus_operator;
 
 mod spin_hamiltonian;
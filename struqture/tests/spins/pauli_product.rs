@@ -22,7 +22,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 use std::iter::{FromIterator, IntoIterator};
 use std::str::FromStr;
-use struqture::spins::{PauliProduct, SingleSpinOperator};
+use struqture::spins::{
+    pauli_basis, rotation_channel_superoperator_coo, PauliProduct, SingleSpinOperator,
+};
 use struqture::{CorrespondsTo, GetValue, SpinIndex, StruqtureError, SymmetricIndex};
 use test_case::test_case;
 
@@ -74,6 +76,26 @@ fn internal_map_set_get() {
     assert!(pp.iter().map(|(k, _)| k).all(|k| internal.contains_key(k)));
 }
 
+// Test that setting the identity with set_pauli canonicalizes the PauliProduct
+#[test]
+fn set_pauli_identity_canonical() {
+    let pp = PauliProduct::new()
+        .x(0)
+        .set_pauli(1, SingleSpinOperator::Identity);
+    let expected = PauliProduct::new().x(0);
+    assert_eq!(pp, expected);
+    assert_eq!(pp.len(), 1);
+    assert_eq!(pp.current_number_spins(), 1);
+    assert_eq!(format!("{}", pp), format!("{}", expected));
+    assert_eq!(PauliProduct::from_str("0X1I").unwrap(), expected);
+
+    let mut s_1 = DefaultHasher::new();
+    pp.hash(&mut s_1);
+    let mut s_2 = DefaultHasher::new();
+    expected.hash(&mut s_2);
+    assert_eq!(s_1.finish(), s_2.finish());
+}
+
 // Test the set_pauli and get functions of the PauliProduct
 #[test]
 fn hermitian_conjugate() {
@@ -114,6 +136,108 @@ fn x_y_z() {
     assert_eq!(pp.clone().z(3), pp.set_pauli(3, SingleSpinOperator::Z));
 }
 
+// Test the from_bitmasks and to_bitmasks functions of the PauliProduct
+#[test_case(0, 0, "I"; "identity")]
+#[test_case(0b1, 0, "0X"; "x")]
+#[test_case(0, 0b100, "2Z"; "z")]
+#[test_case(0b10, 0b10, "1Y"; "y")]
+#[test_case(0b1011, 0b1110, "0X1Y2Z3Y"; "mixed")]
+#[test_case(1 << 63, 1 << 63, "63Y"; "highest bit")]
+fn bitmasks(x_mask: u64, z_mask: u64, expected: &str) {
+    let pp = PauliProduct::from_bitmasks(x_mask, z_mask);
+    assert_eq!(pp, PauliProduct::from_str(expected).unwrap());
+    assert_eq!(pp.to_bitmasks(), (x_mask, z_mask));
+}
+
+#[test]
+#[should_panic]
+fn to_bitmasks_index_too_large() {
+    let _ = PauliProduct::new().x(64).to_bitmasks();
+}
+
+// Test that the rotation_channel_superoperator_coo is trace preserving and unital
+#[test_case("0X", 0.3, 1; "X0")]
+#[test_case("0Z1Y", 1.1, 2; "Z0 Y1")]
+#[test_case("1X", -0.7, 3; "X1 on 3 spins")]
+fn rotation_channel_superoperator_coo_unital(product: &str, angle: f64, number_spins: usize) {
+    let product = PauliProduct::from_str(product).unwrap();
+    let (values, (rows, columns)) =
+        rotation_channel_superoperator_coo(&product, angle, number_spins).unwrap();
+    let dimension = 2usize.pow(number_spins as u32);
+    let mut identity = vec![Complex64::new(0.0, 0.0); dimension * dimension];
+    for index in 0..dimension {
+        identity[index * dimension + index] = Complex64::new(1.0, 0.0);
+    }
+    // The flattened identity is a fixed point of S (unital channel) and of S^T (trace preserving channel)
+    let mut image = vec![Complex64::new(0.0, 0.0); dimension * dimension];
+    let mut trace_image = vec![Complex64::new(0.0, 0.0); dimension * dimension];
+    for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+        image[*row] += value * identity[*column];
+        trace_image[*column] += value * identity[*row];
+    }
+    for ((a, b), c) in image.iter().zip(trace_image.iter()).zip(identity.iter()) {
+        assert!((a - c).norm() < 1e-12);
+        assert!((b - c).norm() < 1e-12);
+    }
+
+    assert_eq!(
+        rotation_channel_superoperator_coo(&product, angle, 0),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test the pauli_basis function
+#[test]
+fn pauli_basis_two_qubits() {
+    let basis = pauli_basis(2, 1);
+    let expected: Vec<PauliProduct> = ["", "0X", "0Y", "0Z", "1X", "1Y", "1Z"]
+        .iter()
+        .map(|product| PauliProduct::from_str(product).unwrap())
+        .collect();
+    assert_eq!(basis, expected);
+}
+
+// Test the size of the pauli_basis
+#[test_case(2, 0, 1; "identity only")]
+#[test_case(2, 2, 16; "full two qubits")]
+#[test_case(3, 2, 1 + 9 + 27; "three qubits weight two")]
+#[test_case(2, 5, 16; "weight larger than qubits")]
+#[test_case(0, 1, 1; "no qubits")]
+fn pauli_basis_size(number_qubits: usize, max_weight: usize, expected: usize) {
+    let basis = pauli_basis(number_qubits, max_weight);
+    assert_eq!(basis.len(), expected);
+    let unique: std::collections::HashSet<PauliProduct> = basis.iter().cloned().collect();
+    assert_eq!(unique.len(), expected);
+    assert!(basis.iter().all(|product| product.len() <= max_weight));
+}
+
+// Test the commutes_with function of the PauliProduct
+#[test_case("0X", "0Z", false; "x0_z0")]
+#[test_case("0X", "1X", true; "x0_x1")]
+#[test_case("0X", "0X", true; "x0_x0")]
+#[test_case("0X1Y", "0Z1Z", true; "x0y1_z0z1")]
+#[test_case("0X1Y2Z", "0Z2Z", false; "x0y1z2_z0z2")]
+#[test_case("", "0Y", true; "identity")]
+fn commutes_with(left: &str, right: &str, commutes: bool) {
+    let left = PauliProduct::from_str(left).unwrap();
+    let right = PauliProduct::from_str(right).unwrap();
+    assert_eq!(left.commutes_with(&right), commutes);
+    assert_eq!(right.commutes_with(&left), commutes);
+}
+
+// Test the qubit_wise_commutes_with function of the PauliProduct
+#[test_case("0X", "0Z", false; "x0_z0")]
+#[test_case("0X", "1Z", true; "x0_z1")]
+#[test_case("0X1Y", "0X", true; "x0y1_x0")]
+#[test_case("0X1Y", "0Z1Z", false; "x0y1_z0z1")]
+#[test_case("", "0Y", true; "identity")]
+fn qubit_wise_commutes_with(left: &str, right: &str, commutes: bool) {
+    let left = PauliProduct::from_str(left).unwrap();
+    let right = PauliProduct::from_str(right).unwrap();
+    assert_eq!(left.qubit_wise_commutes_with(&right), commutes);
+    assert_eq!(right.qubit_wise_commutes_with(&left), commutes);
+}
+
 // Test the concatenate function of the PauliProduct
 #[test]
 fn concatenate() {
@@ -12,6 +12,7 @@
 
 //! Integration test for public API of PlusMinusLindbladNoiseOperator
 
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::{BTreeMap, HashMap};
@@ -19,7 +20,7 @@ use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use struqture::spins::{
     DecoherenceProduct, PlusMinusLindbladNoiseOperator, PlusMinusOperator, PlusMinusProduct,
-    SpinLindbladNoiseOperator,
+    SpinLindbladNoiseOperator, ToSparseMatrixSuperOperator,
 };
 use struqture::{OperateOnDensityMatrix, SpinIndex};
 use test_case::test_case;
@@ -79,6 +80,17 @@ fn internal_map_len() {
     assert_eq!(slno.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the PlusMinusLindbladNoiseOperator
+#[test]
+fn number_of_terms() {
+    let dp_2: PlusMinusProduct = PlusMinusProduct::new().z(2);
+    let mut slno = PlusMinusLindbladNoiseOperator::new();
+    slno.set((dp_2.clone(), dp_2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(slno.number_of_terms(), 1_usize);
+    assert_eq!(slno.number_of_terms(), slno.len());
+}
+
 // Test the try_set_noise and get functions of the PlusMinusLindbladNoiseOperator
 #[test]
 fn internal_map_set_get() {
@@ -233,6 +245,119 @@ fn internal_map_add_noise_from_full_operators_complex() {
     );
 }
 
+// Test the relaxation function of PlusMinusLindbladNoiseOperator
+#[test]
+fn relaxation() {
+    let gamma_minus = 0.5;
+    let gamma_z = 0.25;
+    let noise = PlusMinusLindbladNoiseOperator::relaxation(0, gamma_minus, gamma_z).unwrap();
+    assert_eq!(noise.len(), 2);
+    assert_eq!(
+        noise.get(&(
+            PlusMinusProduct::new().minus(0),
+            PlusMinusProduct::new().minus(0)
+        )),
+        &CalculatorComplex::from(gamma_minus)
+    );
+    assert_eq!(
+        noise.get(&(PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0))),
+        &CalculatorComplex::from(gamma_z)
+    );
+
+    // The state |1><1| that sigma^- decays into is the steady state of the channel
+    let spin_noise = SpinLindbladNoiseOperator::from(noise);
+    let c = |re: f64| Complex64::new(re, 0.0);
+    let steady_state = vec![c(0.0), c(0.0), c(0.0), c(1.0)];
+    for value in spin_noise
+        .apply_superoperator_n_times(&steady_state, 1, 1)
+        .unwrap()
+    {
+        assert!(value.norm() < 1e-12);
+    }
+    // The excited state decays into the steady state
+    let excited = spin_noise
+        .apply_superoperator_n_times(&[c(1.0), c(0.0), c(0.0), c(0.0)], 1, 1)
+        .unwrap();
+    assert!((excited[0] - c(-gamma_minus)).norm() < 1e-12);
+    assert!((excited[3] - c(gamma_minus)).norm() < 1e-12);
+    // Coherences decay with gamma_minus / 2 + 2 gamma_z
+    let coherence = spin_noise
+        .apply_superoperator_n_times(&[c(0.0), c(1.0), c(0.0), c(0.0)], 1, 1)
+        .unwrap();
+    assert!((coherence[1] - c(-gamma_minus / 2.0 - 2.0 * gamma_z)).norm() < 1e-12);
+
+    let zero_dephasing = PlusMinusLindbladNoiseOperator::relaxation(2, 1.0, 0.0).unwrap();
+    assert_eq!(zero_dephasing.len(), 1);
+    assert!(PlusMinusLindbladNoiseOperator::relaxation(0, -1.0, 0.0).is_err());
+    assert!(PlusMinusLindbladNoiseOperator::relaxation(0, 1.0, -0.1).is_err());
+    assert!(PlusMinusLindbladNoiseOperator::relaxation(0, f64::NAN, 0.0).is_err());
+}
+
+// Test that converting to the plus/minus basis and back preserves the superoperator
+#[test]
+fn to_plus_minus_noise_operator_round_trip() {
+    let mut noise = SpinLindbladNoiseOperator::new();
+    noise
+        .set(
+            (
+                DecoherenceProduct::new().x(0),
+                DecoherenceProduct::new().iy(0),
+            ),
+            CalculatorComplex::new(0.3, 0.1),
+        )
+        .unwrap();
+    noise
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().x(0),
+            ),
+            CalculatorComplex::new(0.0, -0.2),
+        )
+        .unwrap();
+    noise
+        .set(
+            (
+                DecoherenceProduct::new().iy(0).x(1),
+                DecoherenceProduct::new().iy(0).x(1),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    let plus_minus = noise.to_plus_minus_noise_operator();
+    assert_eq!(
+        plus_minus,
+        PlusMinusLindbladNoiseOperator::from(noise.clone())
+    );
+    // X and iY mix into + and -, producing cross terms
+    assert_ne!(
+        plus_minus.get(&(
+            PlusMinusProduct::new().plus(0),
+            PlusMinusProduct::new().minus(0)
+        )),
+        &CalculatorComplex::ZERO
+    );
+    let round_trip = plus_minus.to_spin_noise_operator();
+
+    let to_map = |operator: &SpinLindbladNoiseOperator| -> HashMap<(usize, usize), Complex64> {
+        let (values, (rows, columns)) = operator.sparse_matrix_superoperator_coo(Some(2)).unwrap();
+        rows.into_iter()
+            .zip(columns)
+            .zip(values)
+            .filter(|(_, value)| value.norm() > 1e-12)
+            .collect()
+    };
+    let original = to_map(&noise);
+    let converted = to_map(&round_trip);
+    assert!(!original.is_empty());
+    for key in original.keys().chain(converted.keys()) {
+        let left = original.get(key).copied().unwrap_or_default();
+        let right = converted.get(key).copied().unwrap_or_default();
+        assert!((left - right).norm() < 1e-12);
+    }
+}
+
 // Test the Iter traits of PlusMinusLindbladNoiseOperator: into_iter, from_iter and extend
 #[test]
 fn into_iter_from_iter_extend() {
@@ -860,3 +985,18 @@ fn test_plus_minus_noise_operator_schema() {
     let validation = schema_checker.validate(&value);
     assert!(validation.is_ok());
 }
+
+// Test the min_supported_version_for_content function of the PlusMinusLindbladNoiseOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut pmno = PlusMinusLindbladNoiseOperator::new();
+    pmno.set(
+        (
+            PlusMinusProduct::new().z(0),
+            PlusMinusProduct::new().minus(0),
+        ),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(pmno.min_supported_version_for_content(), (1, 1, 0));
+}
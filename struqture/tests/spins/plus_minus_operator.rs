@@ -22,7 +22,7 @@ use struqture::spins::{
     DecoherenceOperator, DecoherenceProduct, PauliProduct, PlusMinusOperator, PlusMinusProduct,
     SpinHamiltonian, SpinOperator,
 };
-use struqture::OperateOnDensityMatrix;
+use struqture::{MinSupportedVersion, OperateOnDensityMatrix};
 use test_case::test_case;
 
 // Test the new function of the PlusMinusOperator
@@ -70,6 +70,16 @@ fn internal_map_len() {
     assert_eq!(so.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the PlusMinusOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: PlusMinusProduct = PlusMinusProduct::new().z(2);
+    let mut so = PlusMinusOperator::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
+
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -908,6 +918,22 @@ fn pmo_from_so() {
     assert_eq!(PlusMinusOperator::from(spin_op), pm_op);
 }
 
+// Test the min_supported_version_for_content function of the PlusMinusOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut pmo = PlusMinusOperator::new();
+    assert_eq!(
+        pmo.min_supported_version_for_content(),
+        PlusMinusOperator::min_supported_version()
+    );
+    pmo.set(
+        PlusMinusProduct::new().plus(0),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(pmo.min_supported_version_for_content(), (1, 1, 0));
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_plus_minus_operator_schema() {
@@ -21,11 +21,12 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::{Hash, Hasher};
 use std::iter::{FromIterator, IntoIterator};
 use std::str::FromStr;
+use struqture::higher_spins::SpinSProduct;
 use struqture::spins::{
     DecoherenceProduct, PauliProduct, PlusMinusProduct, SingleDecoherenceOperator,
     SinglePlusMinusOperator, SingleSpinOperator,
 };
-use struqture::{SpinIndex, StruqtureError, SymmetricIndex};
+use struqture::{MinSupportedVersion, SpinIndex, StruqtureError, SymmetricIndex};
 
 // Test the new function of the PlusMinusProduct
 #[test]
@@ -968,3 +969,20 @@ fn test_plus_minus_product_schema() {
     let validation = schema_checker.validate(&value);
     assert!(validation.is_ok());
 }
+
+// Test the min_supported_version_of function for pairs of index types with different minimum versions
+#[test]
+fn min_supported_version_of_mixed_index_types() {
+    let decoherence_plus_minus = (
+        DecoherenceProduct::new().z(0),
+        PlusMinusProduct::new().plus(0),
+    );
+    assert_eq!(decoherence_plus_minus.min_supported_version_of(), (1, 1, 0));
+    let plus_minus_spin_s = (PlusMinusProduct::new().plus(0), SpinSProduct::new());
+    assert_eq!(plus_minus_spin_s.min_supported_version_of(), (1, 10, 0));
+    let decoherence = (
+        DecoherenceProduct::new().z(0),
+        DecoherenceProduct::new().x(1),
+    );
+    assert_eq!(decoherence.min_supported_version_of(), (1, 0, 0));
+}
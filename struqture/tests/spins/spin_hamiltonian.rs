@@ -13,6 +13,7 @@
 //! Integration test for public API of SpinHamiltonian
 
 use super::create_na_matrix_from_operator_list;
+use nalgebra as na;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
@@ -22,8 +23,8 @@ use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{
-    OperateOnSpins, PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator,
-    ToSparseMatrixOperator,
+    CommutationKind, GroupingStrategy, OperateOnSpins, PauliProduct, SingleSpinOperator,
+    SpinHamiltonian, SpinOperator, ToSparseMatrixOperator,
 };
 use struqture::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use test_case::test_case;
@@ -69,6 +70,16 @@ fn internal_map_len() {
     so.set(pp_2, CalculatorFloat::from(0.5)).unwrap();
     assert_eq!(so.len(), 1_usize);
 }
+
+// Test the number_of_terms function of the SpinHamiltonian
+#[test]
+fn number_of_terms() {
+    let pp_2: PauliProduct = PauliProduct::new().z(2);
+    let mut so = SpinHamiltonian::new();
+    so.set(pp_2, CalculatorFloat::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -308,6 +319,244 @@ fn separate_out_terms(number_spins: usize) {
     assert_eq!(result.1, remainder);
 }
 
+// Test reading a SpinHamiltonian from a Pauli-sum file with comments and blank lines
+#[test]
+fn from_pauli_sum_file() {
+    let input = "# Transverse field Ising model\n\
+                 0.5 X0 Z1  # coupling\n\
+                 \n\
+                 -1.0 z1\n\
+                 0.25 Z1 X0\n\
+                 2.0\n\
+                 \t  # indented comment\n";
+    let hamiltonian = SpinHamiltonian::from_pauli_sum_file(input.as_bytes()).unwrap();
+    let mut expected = SpinHamiltonian::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0).z(1), CalculatorFloat::from(0.75))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(-1.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(2.0))
+        .unwrap();
+    assert_eq!(hamiltonian, expected);
+}
+
+// Test that malformed lines of a Pauli-sum file are reported with their line number
+#[test_case("0.5 X0\n0.5 Q1\n", 2; "invalid pauli")]
+#[test_case("# comment\n\n1j X0\n", 3; "complex coefficient")]
+#[test_case("X0 0.5\n", 1; "missing coefficient")]
+#[test_case("0.5 X0 Z0\n", 1; "repeated qubit")]
+#[test_case("0.5 Xa\n", 1; "invalid index")]
+fn from_pauli_sum_file_malformed(input: &str, line_number: usize) {
+    match SpinHamiltonian::from_pauli_sum_file(input.as_bytes()) {
+        Err(StruqtureError::ParsingError { target_type, msg }) => {
+            assert_eq!(target_type, "SpinHamiltonian");
+            assert!(msg.starts_with(&format!("Line {}:", line_number)));
+        }
+        other => panic!("Expected a parsing error, got {:?}", other),
+    }
+}
+
+// Test the commuting_groups function of the SpinHamiltonian
+#[test]
+fn commuting_groups() {
+    let system = SpinHamiltonian::heisenberg(
+        3,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(2.0),
+        false,
+    ) + SpinHamiltonian::transverse_field_ising(
+        3,
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(0.3),
+        false,
+    );
+    let groups = system.commuting_groups();
+    assert!(groups.len() > 1);
+    assert!(groups.len() < system.len());
+
+    let mut reconstructed = SpinOperator::new();
+    let mut number_terms = 0;
+    for group in groups.iter() {
+        assert!(!group.is_empty());
+        for left in group.keys() {
+            for right in group.keys() {
+                assert!(left.commutes_with(right));
+            }
+        }
+        number_terms += group.len();
+        reconstructed += group.clone();
+    }
+    assert_eq!(number_terms, system.len());
+    assert_eq!(reconstructed, SpinOperator::from(system));
+
+    assert!(SpinHamiltonian::new().commuting_groups().is_empty());
+}
+
+// Test the group_commuting_terms function of the SpinHamiltonian for all strategies
+#[test_case(GroupingStrategy::Greedy(CommutationKind::QubitWise); "greedy_qubit_wise")]
+#[test_case(GroupingStrategy::Greedy(CommutationKind::General); "greedy_general")]
+#[test_case(GroupingStrategy::LargestDegreeFirst(CommutationKind::QubitWise); "largest_degree_first_qubit_wise")]
+#[test_case(GroupingStrategy::LargestDegreeFirst(CommutationKind::General); "largest_degree_first_general")]
+fn group_commuting_terms(strategy: GroupingStrategy) {
+    let system = SpinHamiltonian::heisenberg(
+        4,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(2.0),
+        true,
+    ) + SpinHamiltonian::transverse_field_ising(
+        4,
+        CalculatorFloat::from(0.7),
+        CalculatorFloat::from(0.3),
+        true,
+    );
+    let groups = system.group_commuting_terms(strategy);
+
+    let mut reconstructed = SpinOperator::new();
+    let mut number_terms = 0;
+    for group in groups.iter() {
+        assert!(!group.is_empty());
+        for left in group.keys() {
+            for right in group.keys() {
+                match strategy {
+                    GroupingStrategy::Greedy(CommutationKind::QubitWise)
+                    | GroupingStrategy::LargestDegreeFirst(CommutationKind::QubitWise) => {
+                        assert!(left.qubit_wise_commutes_with(right))
+                    }
+                    _ => assert!(left.commutes_with(right)),
+                }
+            }
+        }
+        number_terms += group.len();
+        reconstructed += group.clone();
+    }
+    assert_eq!(number_terms, system.len());
+    assert_eq!(reconstructed, SpinOperator::from(system.clone()));
+
+    // The XX, YY, ZZ couplings and the X fields form three qubit-wise commuting groups
+    if let GroupingStrategy::Greedy(CommutationKind::QubitWise) = strategy {
+        assert_eq!(groups.len(), 3);
+    }
+    assert_eq!(
+        system.commuting_groups(),
+        system.group_commuting_terms(GroupingStrategy::Greedy(CommutationKind::General))
+    );
+    assert!(SpinHamiltonian::new()
+        .group_commuting_terms(strategy)
+        .is_empty());
+}
+
+// Test the heisenberg function of the SpinHamiltonian
+#[test]
+fn heisenberg() {
+    let system = SpinHamiltonian::heisenberg(
+        2,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(1.0),
+        false,
+    );
+    assert_eq!(system.len(), 3);
+    assert_eq!(
+        system.get(&PauliProduct::new().y(0).y(1)),
+        &CalculatorFloat::from(1.0)
+    );
+
+    // Singlet/triplet spectrum of the isotropic two-site Heisenberg model
+    let sparse_matrix = system.sparse_matrix(Some(2)).unwrap();
+    let mut dense_matrix: na::DMatrix<f64> = na::DMatrix::zeros(4, 4);
+    for ((row, column), value) in sparse_matrix.iter() {
+        assert_eq!(value.im, 0.0);
+        dense_matrix[(*row, *column)] = value.re;
+    }
+    let mut eigenvalues: Vec<f64> = dense_matrix
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .cloned()
+        .collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (eigenvalue, expected) in eigenvalues.iter().zip([-3.0, 1.0, 1.0, 1.0].iter()) {
+        assert!((eigenvalue - expected).abs() < 1e-10);
+    }
+
+    let anisotropic = SpinHamiltonian::heisenberg(
+        3,
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from("jz"),
+        true,
+    );
+    assert_eq!(anisotropic.len(), 6);
+    assert_eq!(
+        anisotropic.get(&PauliProduct::new().x(0).x(2)),
+        &CalculatorFloat::from(0.5)
+    );
+    assert_eq!(
+        anisotropic.get(&PauliProduct::new().z(1).z(2)),
+        &CalculatorFloat::from("jz")
+    );
+    assert_eq!(
+        anisotropic.get(&PauliProduct::new().y(0).y(1)),
+        &CalculatorFloat::from(0.0)
+    );
+}
+
+// Test the transverse_field_ising function of the SpinHamiltonian
+#[test]
+fn transverse_field_ising() {
+    let system = SpinHamiltonian::transverse_field_ising(
+        3,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(0.5),
+        false,
+    );
+    assert_eq!(system.len(), 5);
+    assert_eq!(
+        system.get(&PauliProduct::new().z(0).z(1)),
+        &CalculatorFloat::from(-1.0)
+    );
+    assert_eq!(
+        system.get(&PauliProduct::new().x(2)),
+        &CalculatorFloat::from(-0.5)
+    );
+
+    let zz_01 = create_na_matrix_from_operator_list(&["I", "Z", "Z"]);
+    let zz_12 = create_na_matrix_from_operator_list(&["Z", "Z", "I"]);
+    let x_0 = create_na_matrix_from_operator_list(&["I", "I", "X"]);
+    let x_1 = create_na_matrix_from_operator_list(&["I", "X", "I"]);
+    let x_2 = create_na_matrix_from_operator_list(&["X", "I", "I"]);
+    let test_matrix =
+        (zz_01 + zz_12) * Complex64::new(-1.0, 0.0) + (x_0 + x_1 + x_2) * Complex64::new(-0.5, 0.0);
+
+    let sparse_matrix = system.sparse_matrix(Some(3)).unwrap();
+    for row in 0..8 {
+        for column in 0..8 {
+            let val = test_matrix[(row, column)];
+            match sparse_matrix.get(&(row, column)) {
+                Some(x) => assert_eq!(&val, x),
+                None => assert_eq!(val, Complex64::new(0.0, 0.0)),
+            }
+        }
+    }
+
+    let periodic = SpinHamiltonian::transverse_field_ising(
+        3,
+        CalculatorFloat::from(1.0),
+        CalculatorFloat::from(0.5),
+        true,
+    );
+    assert_eq!(periodic.len(), 6);
+    assert_eq!(
+        periodic.get(&PauliProduct::new().z(0).z(2)),
+        &CalculatorFloat::from(-1.0)
+    );
+}
+
 // Test the negative operation: -SpinHamiltonian
 #[test]
 fn negative_so() {
@@ -337,6 +586,32 @@ fn add_so_so() {
     assert_eq!(so_0.add(so_1), so_0_1);
 }
 
+// Test the addition of SpinHamiltonian and SpinOperator in both orders
+#[test]
+fn add_hamiltonian_operator() {
+    let pp_x: PauliProduct = PauliProduct::new().x(0);
+    let pp_y: PauliProduct = PauliProduct::new().y(0);
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(pp_x.clone(), CalculatorFloat::from(0.5))
+        .unwrap();
+    let mut operator = SpinOperator::new();
+    operator
+        .add_operator_product(pp_y.clone(), CalculatorComplex::new(0.0, 0.5))
+        .unwrap();
+
+    let sum: SpinOperator = &hamiltonian + operator.clone();
+    assert_eq!(sum.len(), 2);
+    assert_eq!(sum.get(&pp_x), &CalculatorComplex::new(0.5, 0.0));
+    assert_eq!(sum.get(&pp_y), &CalculatorComplex::new(0.0, 0.5));
+
+    let reverse: SpinOperator = operator + hamiltonian.clone();
+    assert_eq!(reverse, sum);
+
+    let converted = SpinOperator::from(hamiltonian);
+    assert_eq!(converted.get(&pp_x), &CalculatorComplex::new(0.5, 0.0));
+}
+
 // Test the subtraction: SpinHamiltonian - SpinHamiltonian
 #[test]
 fn sub_so_so() {
@@ -354,6 +629,24 @@ fn sub_so_so() {
     assert_eq!(so_0.sub(so_1), so_0_1);
 }
 
+// Test the commutator and anticommutator functions of the SpinHamiltonian
+#[test]
+fn commutator_anticommutator() {
+    let mut x = SpinHamiltonian::new();
+    x.add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    let mut z = SpinHamiltonian::new();
+    z.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(1.0))
+        .unwrap();
+
+    let mut expected_commutator = SpinOperator::new();
+    expected_commutator
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, -2.0))
+        .unwrap();
+    assert_eq!(x.commutator(&z), expected_commutator);
+    assert!(x.anticommutator(&z).is_empty());
+}
+
 // Test the multiplication: SpinHamiltonian * SpinHamiltonian with all possible pauli matrices
 #[test_case("0X", "0X", "0I", CalculatorComplex::new(1.0, 0.0); "x_x_identity")]
 #[test_case("0X1X", "0X", "0I1X", CalculatorComplex::new(1.0, 0.0); "x_x")]
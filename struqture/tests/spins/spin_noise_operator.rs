@@ -23,8 +23,10 @@ use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
-use struqture::spins::{DecoherenceOperator, DecoherenceProduct, SpinLindbladNoiseOperator};
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex};
+use struqture::spins::{
+    is_valid_lindblad_operator, DecoherenceOperator, DecoherenceProduct, SpinLindbladNoiseOperator,
+};
+use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use test_case::test_case;
 
 // Test the new function of the SpinLindbladNoiseOperator
@@ -79,6 +81,138 @@ fn internal_map_len() {
     assert_eq!(slno.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the SpinLindbladNoiseOperator
+#[test]
+fn number_of_terms() {
+    let dp_2: DecoherenceProduct = DecoherenceProduct::new().z(2);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.set((dp_2.clone(), dp_2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(slno.number_of_terms(), 1_usize);
+    assert_eq!(slno.number_of_terms(), slno.len());
+}
+
+// Test the apply_superoperator_n_times function of the SpinLindbladNoiseOperator for a dephasing channel
+#[test]
+fn apply_superoperator_n_times() {
+    let dp_0 = DecoherenceProduct::new().z(0);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.add_operator_product((dp_0.clone(), dp_0), CalculatorComplex::from(0.25))
+        .unwrap();
+
+    let c = |re: f64| Complex64::new(re, 0.0);
+    // The steady state of the dephasing is annihilated by the Lindbladian
+    let steady_state = vec![c(0.7), c(0.0), c(0.0), c(0.3)];
+    assert_eq!(
+        slno.apply_superoperator_n_times(&steady_state, 5, 1)
+            .unwrap(),
+        vec![c(0.0); 4]
+    );
+    assert_eq!(
+        slno.apply_superoperator_n_times(&steady_state, 0, 1)
+            .unwrap(),
+        steady_state
+    );
+
+    // Coherences decay with the rate -2 * 0.25 for each application
+    let plus_state = vec![c(0.5), c(0.5), c(0.5), c(0.5)];
+    assert_eq!(
+        slno.apply_superoperator_n_times(&plus_state, 3, 1).unwrap(),
+        vec![c(0.0), c(-0.0625), c(-0.0625), c(0.0)]
+    );
+    let padded_state = slno
+        .apply_superoperator_n_times(&[c(1.0); 16], 2, 2)
+        .unwrap();
+    assert_eq!(padded_state.len(), 16);
+
+    assert!(slno.apply_superoperator_n_times(&plus_state, 1, 2).is_err());
+    assert_eq!(
+        slno.apply_superoperator_n_times(&[], 1, 0),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test that apply_superoperator_n_times returns an error when the flattened density matrix overflows
+#[test]
+fn apply_superoperator_n_times_too_many_spins() {
+    let dp_0 = DecoherenceProduct::new().z(0);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.add_operator_product((dp_0.clone(), dp_0), CalculatorComplex::from(0.25))
+        .unwrap();
+
+    let result = slno.apply_superoperator_n_times(&[], 1, usize::BITS as usize / 2);
+    assert!(matches!(result, Err(StruqtureError::GenericError { .. })));
+    let result = slno.superoperator_expectation_value(&[], usize::BITS as usize / 2);
+    assert!(matches!(result, Err(StruqtureError::GenericError { .. })));
+}
+
+// Test the superoperator_expectation_value function of the SpinLindbladNoiseOperator for a dephasing channel
+#[test]
+fn superoperator_expectation_value() {
+    let dp_0 = DecoherenceProduct::new().z(0);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.add_operator_product((dp_0.clone(), dp_0), CalculatorComplex::from(0.25))
+        .unwrap();
+
+    let c = |re: f64| Complex64::new(re, 0.0);
+    let steady_state = vec![c(0.7), c(0.0), c(0.0), c(0.3)];
+    assert_eq!(
+        slno.superoperator_expectation_value(&steady_state, 1)
+            .unwrap(),
+        c(0.0)
+    );
+    let plus_state = vec![c(0.5), c(0.5), c(0.5), c(0.5)];
+    assert_eq!(
+        slno.superoperator_expectation_value(&plus_state, 1)
+            .unwrap(),
+        c(-0.25)
+    );
+
+    assert!(slno
+        .superoperator_expectation_value(&plus_state, 2)
+        .is_err());
+    assert_eq!(
+        slno.superoperator_expectation_value(&[], 0),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test that identity Lindblad operators are rejected by the SpinLindbladNoiseOperator
+#[test]
+fn add_identity_lindblad_operator() {
+    let identity = DecoherenceProduct::new();
+    let dp_0 = DecoherenceProduct::new().z(0);
+    assert!(!is_valid_lindblad_operator(&identity));
+    assert!(is_valid_lindblad_operator(&dp_0));
+    assert!(is_valid_lindblad_operator(
+        &DecoherenceProduct::new().x(0).iy(3)
+    ));
+
+    let mut slno = SpinLindbladNoiseOperator::new();
+    assert_eq!(
+        slno.add_operator_product(
+            (identity.clone(), dp_0.clone()),
+            CalculatorComplex::from(0.5)
+        ),
+        Err(StruqtureError::InvalidLindbladTerms)
+    );
+    assert_eq!(
+        slno.add_operator_product(
+            (dp_0.clone(), identity.clone()),
+            CalculatorComplex::from(0.5)
+        ),
+        Err(StruqtureError::InvalidLindbladTerms)
+    );
+    assert_eq!(
+        slno.add_operator_product((identity.clone(), identity), CalculatorComplex::from(0.5)),
+        Err(StruqtureError::InvalidLindbladTerms)
+    );
+    assert!(slno.is_empty());
+    assert!(slno
+        .add_operator_product((dp_0.clone(), dp_0), CalculatorComplex::from(0.5))
+        .is_ok());
+}
+
 // Test the try_set_noise and get functions of the SpinLindbladNoiseOperator
 #[test]
 fn internal_map_set_get() {
@@ -951,3 +1085,19 @@ fn test_spin_noise_operator_schema() {
     let validation = schema_checker.validate(&value);
     assert!(validation.is_ok());
 }
+
+// Test the min_supported_version_for_content function of the SpinLindbladNoiseOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut sno = SpinLindbladNoiseOperator::new();
+    assert_eq!(sno.min_supported_version_for_content(), (1, 0, 0));
+    sno.set(
+        (
+            DecoherenceProduct::new().z(0),
+            DecoherenceProduct::new().z(0),
+        ),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(sno.min_supported_version_for_content(), (1, 0, 0));
+}
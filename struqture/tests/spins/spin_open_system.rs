@@ -22,10 +22,10 @@ use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{
-    DecoherenceProduct, PauliProduct, SpinHamiltonianSystem, SpinLindbladNoiseSystem,
-    SpinLindbladOpenSystem,
+    DecoherenceProduct, PauliProduct, SpinHamiltonian, SpinHamiltonianSystem,
+    SpinLindbladNoiseSystem, SpinLindbladOpenSystem,
 };
-use struqture::SpinIndex;
+use struqture::{SpinIndex, StruqtureError};
 use test_case::test_case;
 
 // Test the new function of the SpinLindbladOpenSystem
@@ -316,6 +316,200 @@ fn add_slos_slos() {
     assert_eq!(slos_0 + slos_1, Ok(slos_0_1));
 }
 
+// Test building a dephasing SpinLindbladOpenSystem with from_hamiltonian_and_jumps
+#[test]
+fn from_hamiltonian_and_jumps() {
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.3))
+        .unwrap();
+    let jumps = vec![
+        (DecoherenceProduct::new().z(0), 0.1),
+        (DecoherenceProduct::new().z(1), 0.2),
+    ];
+    let open_system =
+        SpinLindbladOpenSystem::from_hamiltonian_and_jumps(hamiltonian.clone(), &jumps).unwrap();
+
+    assert_eq!(open_system.system().hamiltonian(), &hamiltonian);
+    assert_eq!(open_system.noise().len(), 2);
+    for (jump, rate) in jumps.iter() {
+        assert_eq!(
+            open_system.noise().get(&(jump.clone(), jump.clone())),
+            &CalculatorComplex::from(*rate)
+        );
+    }
+
+    let invalid = vec![(DecoherenceProduct::new(), 0.1)];
+    assert_eq!(
+        SpinLindbladOpenSystem::from_hamiltonian_and_jumps(hamiltonian, &invalid),
+        Err(StruqtureError::InvalidLindbladTerms)
+    );
+}
+
+// Test that add_hamiltonian_scalar only changes the identity coefficient of the Hamiltonian
+#[test]
+fn add_hamiltonian_scalar() {
+    let mut system = SpinLindbladOpenSystem::new(Some(2));
+    system
+        .system_mut()
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::from(0.1),
+        )
+        .unwrap();
+    let original = system.clone();
+
+    system
+        .add_hamiltonian_scalar(CalculatorFloat::from(1.5))
+        .unwrap();
+    system
+        .add_hamiltonian_scalar(CalculatorFloat::from(-0.5))
+        .unwrap();
+    assert_eq!(system.noise(), original.noise());
+    assert_eq!(system.system().len(), 2);
+    assert_eq!(
+        system.system().get(&PauliProduct::new()),
+        &CalculatorFloat::from(1.0)
+    );
+    assert_eq!(
+        system.system().get(&PauliProduct::new().z(0)),
+        &CalculatorFloat::from(0.5)
+    );
+
+    system
+        .add_hamiltonian_scalar(CalculatorFloat::from(-1.0))
+        .unwrap();
+    assert_eq!(system, original);
+}
+
+// Test the effective_hamiltonian function of the SpinLindbladOpenSystem for amplitude damping
+#[test]
+fn effective_hamiltonian() {
+    let omega = 0.8;
+    let gamma = 0.4;
+    let mut system = SpinLindbladOpenSystem::new(Some(1));
+    system
+        .system_mut()
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(omega / 2.0))
+        .unwrap();
+    // Amplitude damping with the Lindblad operator sigma^- = (X + iY) / 2
+    let x = DecoherenceProduct::new().x(0);
+    let iy = DecoherenceProduct::new().iy(0);
+    for left in [x.clone(), iy.clone()] {
+        for right in [x.clone(), iy.clone()] {
+            system
+                .noise_mut()
+                .set((left.clone(), right), CalculatorComplex::from(gamma / 4.0))
+                .unwrap();
+        }
+    }
+
+    // Textbook effective Hamiltonian H - i gamma / 2 |1><1| = H - i gamma / 4 (I - Z)
+    let effective = system.effective_hamiltonian().unwrap();
+    assert_eq!(effective.len(), 2);
+    assert_eq!(
+        effective.get(&PauliProduct::new()),
+        &CalculatorComplex::new(0.0, -gamma / 4.0)
+    );
+    assert_eq!(
+        effective.get(&PauliProduct::new().z(0)),
+        &CalculatorComplex::new(omega / 2.0, gamma / 4.0)
+    );
+
+    let effective_matrix = effective.sparse_matrix(Some(1)).unwrap();
+    assert_eq!(effective_matrix.get(&(0, 1)), None);
+    assert_eq!(effective_matrix.get(&(1, 0)), None);
+    assert_eq!(
+        effective_matrix.get(&(0, 0)),
+        Some(&Complex64::new(omega / 2.0, 0.0))
+    );
+    assert_eq!(
+        effective_matrix.get(&(1, 1)),
+        Some(&Complex64::new(-omega / 2.0, -gamma / 2.0))
+    );
+}
+
+// Test the concurrent_compose function of the SpinLindbladOpenSystem for purely Hamiltonian systems
+#[test]
+fn concurrent_compose_hamiltonian() {
+    let pp_0: PauliProduct = PauliProduct::new().x(0);
+    let pp_1: PauliProduct = PauliProduct::new().z(1);
+    let mut slos_0 = SpinLindbladOpenSystem::new(Some(2));
+    slos_0
+        .system_mut()
+        .set(pp_0.clone(), CalculatorFloat::from(0.4))
+        .unwrap();
+    slos_0
+        .system_mut()
+        .set(pp_1.clone(), CalculatorFloat::from(0.1))
+        .unwrap();
+    let mut slos_1 = SpinLindbladOpenSystem::new(Some(2));
+    slos_1
+        .system_mut()
+        .set(pp_1.clone(), CalculatorFloat::from(0.2))
+        .unwrap();
+
+    let composed = slos_0.concurrent_compose(&slos_1).unwrap();
+    assert_eq!(composed.system().get(&pp_0), &CalculatorFloat::from(0.4));
+    assert_eq!(
+        composed.system().get(&pp_1),
+        &CalculatorFloat::from(0.1 + 0.2)
+    );
+    assert_eq!(composed.system().len(), 2);
+    assert!(composed.noise().is_empty());
+    assert_eq!(composed.system().number_spins(), 2);
+
+    let other_size = SpinLindbladOpenSystem::new(Some(3));
+    assert_eq!(
+        slos_0.concurrent_compose(&other_size),
+        Err(StruqtureError::MissmatchedNumberSpins)
+    );
+}
+
+// Test the concurrent_compose function of the SpinLindbladOpenSystem for purely dissipative systems
+#[test]
+fn concurrent_compose_dissipative() {
+    let dp_0: DecoherenceProduct = DecoherenceProduct::new().z(0);
+    let dp_1: DecoherenceProduct = DecoherenceProduct::new().x(1);
+    let mut slos_0 = SpinLindbladOpenSystem::new(Some(2));
+    slos_0
+        .noise_mut()
+        .set((dp_0.clone(), dp_0.clone()), CalculatorComplex::from(0.5))
+        .unwrap();
+    let mut slos_1 = SpinLindbladOpenSystem::new(Some(2));
+    slos_1
+        .noise_mut()
+        .set((dp_0.clone(), dp_0.clone()), CalculatorComplex::from(0.5))
+        .unwrap();
+    slos_1
+        .noise_mut()
+        .set((dp_1.clone(), dp_1.clone()), CalculatorComplex::from(0.3))
+        .unwrap();
+
+    let composed = slos_0.concurrent_compose(&slos_1).unwrap();
+    assert_eq!(
+        composed.noise().get(&(dp_0.clone(), dp_0)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        composed.noise().get(&(dp_1.clone(), dp_1)),
+        &CalculatorComplex::from(0.3)
+    );
+    assert_eq!(composed.noise().len(), 2);
+    assert!(composed.system().is_empty());
+    assert_eq!(composed.noise().number_spins(), 2);
+}
+
 // Test the subtraction: SpinLindbladOpenSystem - SpinLindbladOpenSystem
 #[test]
 fn sub_slos_slos() {
@@ -475,6 +669,38 @@ fn clone_partial_eq() {
     assert!(slos != slos_2);
 }
 
+// Test the to_hdf5 and from_hdf5 functions of the SpinLindbladOpenSystem
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut system = SpinLindbladOpenSystem::new(Some(3));
+    system
+        .system_mut()
+        .set(PauliProduct::new().x(0).z(2), CalculatorFloat::from(0.4))
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().iy(1),
+                DecoherenceProduct::new().z(0),
+            ),
+            CalculatorComplex::new(0.5, -0.25),
+        )
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("spin_open_system_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("spin_open_system").unwrap();
+        system.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("spin_open_system").unwrap();
+    let read = SpinLindbladOpenSystem::from_hdf5(&group).unwrap();
+    assert_eq!(read, system);
+}
+
 /// Test SpinLindbladOpenSystem Serialization and Deserialization traits (readable)
 #[test]
 fn serde_json() {
@@ -950,6 +1176,113 @@ fn test_superoperator_hamiltonian_and_noise() {
     }
 }
 
+// Test that the COO, CSR and streamed superoperators are ordered by rows and agree with the HashMap superoperator
+#[test]
+fn test_superoperator_row_order() {
+    let mut system = SpinLindbladOpenSystem::new(None);
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("0X1Z").unwrap(), 0.5.into())
+        .unwrap();
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("2Y").unwrap(), 1.0.into())
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::from_str("1X").unwrap(),
+                DecoherenceProduct::from_str("0Z").unwrap(),
+            ),
+            0.3.into(),
+        )
+        .unwrap();
+
+    let matrix = system.sparse_matrix_superoperator(Some(3)).unwrap();
+    let (values, (rows, columns)) = system.sparse_matrix_superoperator_coo(Some(3)).unwrap();
+    assert_eq!(values.len(), matrix.len());
+    assert!(rows.windows(2).all(|pair| pair[0] <= pair[1]));
+    for (value, (row, column)) in values.iter().zip(rows.iter().zip(columns.iter())) {
+        assert_eq!(matrix.get(&(*row, *column)), Some(value));
+    }
+
+    let (csr_values, csr_columns, row_pointers) =
+        system.sparse_matrix_superoperator_csr(Some(3)).unwrap();
+    assert_eq!(row_pointers.len(), 65);
+    assert_eq!(csr_values.len(), matrix.len());
+    for row in 0..64 {
+        for position in row_pointers[row]..row_pointers[row + 1] {
+            assert_eq!(
+                matrix.get(&(row, csr_columns[position])),
+                Some(&csr_values[position])
+            );
+        }
+    }
+
+    let entries: Vec<(usize, usize, Complex64)> = system
+        .sparse_matrix_superoperator_entries(Some(3))
+        .unwrap()
+        .collect();
+    assert_eq!(entries.len(), matrix.len());
+    assert!(entries.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    for (row, column, value) in entries.iter() {
+        assert_eq!(matrix.get(&(*row, *column)), Some(value));
+    }
+
+    // Errors of single rows are propagated
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("0Z").unwrap(), "theta".into())
+        .unwrap();
+    assert!(system.sparse_matrix_superoperator_coo(Some(3)).is_err());
+    assert!(system.sparse_matrix_superoperator(Some(3)).is_err());
+    assert!(system.sparse_matrix_superoperator_csr(Some(3)).is_err());
+    assert!(system.sparse_matrix_superoperator_entries(Some(3)).is_err());
+}
+
+// Test that the parallel construction of the superoperator agrees with the serial construction
+#[cfg(feature = "parallel")]
+#[test]
+fn test_superoperator_parallel() {
+    let mut system = SpinLindbladOpenSystem::new(None);
+    system
+        .system_mut()
+        .set(PauliProduct::from_str("0X1Z").unwrap(), 0.5.into())
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::from_str("1X").unwrap(),
+                DecoherenceProduct::from_str("0Z").unwrap(),
+            ),
+            0.3.into(),
+        )
+        .unwrap();
+
+    let matrix = system.sparse_matrix_superoperator(Some(3)).unwrap();
+    let (values, (rows, columns)) = system
+        .sparse_matrix_superoperator_coo_parallel(Some(3))
+        .unwrap();
+    assert_eq!(values.len(), matrix.len());
+    assert!(rows.windows(2).all(|pair| pair[0] <= pair[1]));
+    for (value, (row, column)) in values.iter().zip(rows.iter().zip(columns.iter())) {
+        assert_eq!(matrix.get(&(*row, *column)), Some(value));
+    }
+
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_csr_parallel(Some(3))
+            .unwrap(),
+        system.sparse_matrix_superoperator_csr(Some(3)).unwrap()
+    );
+    assert_eq!(
+        system.system().sparse_matrix_csr_parallel(Some(3)).unwrap(),
+        system.system().sparse_matrix_csr(Some(3)).unwrap()
+    );
+}
+
 #[test_case("0Z", &["Z"]; "0Z")]
 #[test_case("1X", &["X", "I"]; "1X")]
 #[test_case("1Y", &["Y", "I"]; "1Y")]
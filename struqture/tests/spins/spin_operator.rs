@@ -24,9 +24,10 @@ use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{
-    OperateOnSpins, PauliProduct, SpinHamiltonian, SpinOperator, ToSparseMatrixOperator,
+    CommutationKind, GroupingStrategy, MatrixEndianness, OperateOnSpins, PauliProduct,
+    SingleSpinOperator, SpinHamiltonian, SpinOperator, ToSparseMatrixOperator,
 };
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex};
+use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use test_case::test_case;
 
 // Test the new function of the SpinOperator
@@ -71,6 +72,442 @@ fn internal_map_len() {
     assert_eq!(so.len(), 1_usize);
 }
 
+// Test the number_of_terms function of the SpinOperator
+#[test]
+fn number_of_terms() {
+    let pp_2: PauliProduct = PauliProduct::new().z(2);
+    let mut so = SpinOperator::new();
+    so.set(pp_2, CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(so.number_of_terms(), 1_usize);
+    assert_eq!(so.number_of_terms(), so.len());
+}
+
+// Test the clear function of the SpinOperator
+#[test]
+fn clear() {
+    let mut so = SpinOperator::with_capacity(2);
+    so.set(PauliProduct::new().z(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.set(PauliProduct::new().x(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.clear();
+    assert!(so.is_empty());
+    assert_eq!(so, SpinOperator::new());
+}
+
+// Test the truth_table function of the SpinOperator
+#[test]
+fn truth_table() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let one = Complex64::new(1.0, 0.0);
+    assert_eq!(
+        so.truth_table(1).unwrap(),
+        vec![vec![(1, one)], vec![(0, one)]]
+    );
+
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(
+        so.truth_table(2).unwrap(),
+        vec![
+            vec![(0, 2.0 * one)],
+            vec![(1, 2.0 * one)],
+            vec![(2, -2.0 * one)],
+            vec![(3, -2.0 * one)]
+        ]
+    );
+    assert_eq!(so.truth_table(1), Err(StruqtureError::NumberSpinsExceeded));
+    assert!(matches!(
+        so.truth_table(21),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.truth_table(usize::BITS as usize),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the apply_to_sparse_state function of the SpinOperator against the dense matrix-vector product
+#[test]
+fn apply_to_sparse_state() {
+    let mut so = SpinOperator::new();
+    so.set(
+        PauliProduct::new().x(0).z(2),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(1), CalculatorComplex::from(0.3))
+        .unwrap();
+    so.set(
+        PauliProduct::new().y(0).x(1).z(2),
+        CalculatorComplex::from(-0.7),
+    )
+    .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::from(0.1))
+        .unwrap();
+
+    let mut state: HashMap<usize, Complex64> = HashMap::new();
+    state.insert(1, Complex64::new(0.6, 0.0));
+    state.insert(6, Complex64::new(0.0, 0.8));
+    let result = so.apply_to_sparse_state(&state, 3).unwrap();
+
+    let matrix = so.sparse_matrix(Some(3)).unwrap();
+    let mut expected: HashMap<usize, Complex64> = HashMap::new();
+    for ((row, column), value) in matrix.iter() {
+        if let Some(amplitude) = state.get(column) {
+            *expected.entry(*row).or_insert(Complex64::new(0.0, 0.0)) += value * amplitude;
+        }
+    }
+    expected.retain(|_, amplitude| amplitude.norm() > 1e-14);
+    assert_eq!(result.len(), expected.len());
+    for (index, amplitude) in expected.iter() {
+        assert!((result[index] - amplitude).norm() < 1e-14);
+    }
+
+    // Errors for too many spins, too large basis state indices and symbolic coefficients
+    assert_eq!(
+        so.apply_to_sparse_state(&state, 2),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    let mut large_state: HashMap<usize, Complex64> = HashMap::new();
+    large_state.insert(8, Complex64::new(1.0, 0.0));
+    assert!(so.apply_to_sparse_state(&large_state, 3).is_err());
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.apply_to_sparse_state(&state, 3).is_err());
+}
+
+// Test that apply_to_sparse_state returns an error when the dimension does not fit into usize
+#[test]
+fn apply_to_sparse_state_too_many_spins() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(62), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut state: HashMap<usize, Complex64> = HashMap::new();
+    state.insert(1, Complex64::new(1.0, 0.0));
+
+    let result = so.apply_to_sparse_state(&state, 63).unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[&(1 + (1 << 62))], Complex64::new(1.0, 0.0));
+
+    assert!(matches!(
+        so.apply_to_sparse_state(&state, 64),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.apply_to_sparse_state(&state, usize::MAX),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+// Test the expectation_value functions of the SpinOperator against the dense matrix representation
+#[test]
+fn expectation_value() {
+    let mut so = SpinOperator::new();
+    so.set(
+        PauliProduct::new().x(0).z(2),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(1), CalculatorComplex::from(0.3))
+        .unwrap();
+    so.set(
+        PauliProduct::new().y(0).x(1).z(2),
+        CalculatorComplex::from(-0.7),
+    )
+    .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::from(0.1))
+        .unwrap();
+
+    let state: Vec<Complex64> = (0..8)
+        .map(|index| Complex64::new(0.1 * index as f64, 0.3 - 0.05 * index as f64))
+        .collect();
+    let density_matrix: Vec<Complex64> = (0..64)
+        .map(|index| state[index / 8] * state[index % 8].conj())
+        .collect();
+    let matrix = so.sparse_matrix(Some(3)).unwrap();
+    let mut expected = Complex64::new(0.0, 0.0);
+    for ((row, column), value) in matrix.iter() {
+        expected += state[*row].conj() * value * state[*column];
+    }
+    assert!((so.expectation_value(&state, 3).unwrap() - expected).norm() < 1e-12);
+    assert!(
+        (so.expectation_value_density_matrix(&density_matrix, 3)
+            .unwrap()
+            - expected)
+            .norm()
+            < 1e-12
+    );
+
+    // Errors for too many spins, wrong lengths and symbolic coefficients
+    assert_eq!(
+        so.expectation_value(&state[..4], 2),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(so.expectation_value(&state[..4], 3).is_err());
+    assert!(so
+        .expectation_value_density_matrix(&density_matrix[..8], 3)
+        .is_err());
+    // Oversized numbers of spins return an error instead of overflowing the dimension
+    assert!(matches!(
+        so.expectation_value(&state, usize::BITS as usize),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        so.expectation_value_density_matrix(&density_matrix, usize::BITS as usize / 2),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.expectation_value(&state, 3).is_err());
+    assert!(so
+        .expectation_value_density_matrix(&density_matrix, 3)
+        .is_err());
+}
+
+// Test the apply_to_statevector function of the SpinOperator against the dense matrix representation
+#[test]
+fn apply_to_statevector() {
+    let mut so = SpinOperator::new();
+    so.set(
+        PauliProduct::new().x(0).z(2),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(1), CalculatorComplex::from(0.3))
+        .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::from(0.1))
+        .unwrap();
+
+    let state: Vec<Complex64> = (0..8)
+        .map(|index| Complex64::new(0.1 * index as f64, 0.3 - 0.05 * index as f64))
+        .collect();
+    let matrix = so.sparse_matrix(Some(3)).unwrap();
+    let mut expected = [Complex64::new(0.0, 0.0); 8];
+    for ((row, column), value) in matrix.iter() {
+        expected[*row] += value * state[*column];
+    }
+    let mut output = vec![Complex64::new(1.0, 1.0); 8];
+    so.apply_to_statevector(&state, &mut output).unwrap();
+    for (result, reference) in output.iter().zip(expected.iter()) {
+        assert!((result - reference).norm() < 1e-12);
+    }
+
+    // Errors for too many spins, wrong lengths and symbolic coefficients
+    let mut short_output = vec![Complex64::new(0.0, 0.0); 4];
+    assert_eq!(
+        so.apply_to_statevector(&state[..4], &mut short_output),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(so.apply_to_statevector(&state, &mut short_output).is_err());
+    assert!(so
+        .apply_to_statevector(&state[..6], &mut output[..6])
+        .is_err());
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.apply_to_statevector(&state, &mut output).is_err());
+}
+
+// Test the sparse_matrix_csr and sparse_matrix_csc functions of the SpinOperator against the HashMap representation
+#[test]
+fn sparse_matrix_csr_csc() {
+    let mut so = SpinOperator::new();
+    so.set(
+        PauliProduct::new().x(0).z(2),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(1), CalculatorComplex::from(0.3))
+        .unwrap();
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from(0.1))
+        .unwrap();
+    let matrix = so.sparse_matrix(Some(3)).unwrap();
+
+    let (values, columns, row_pointers) = so.sparse_matrix_csr(Some(3)).unwrap();
+    assert_eq!(row_pointers.len(), 9);
+    assert_eq!(values.len(), matrix.len());
+    for row in 0..8 {
+        let row_columns = &columns[row_pointers[row]..row_pointers[row + 1]];
+        assert!(row_columns.windows(2).all(|pair| pair[0] < pair[1]));
+        for (column, value) in row_columns
+            .iter()
+            .zip(values[row_pointers[row]..row_pointers[row + 1]].iter())
+        {
+            assert_eq!(matrix.get(&(row, *column)), Some(value));
+        }
+    }
+
+    let (values, rows, column_pointers) = so.sparse_matrix_csc(Some(3)).unwrap();
+    assert_eq!(column_pointers.len(), 9);
+    assert_eq!(values.len(), matrix.len());
+    for column in 0..8 {
+        let column_rows = &rows[column_pointers[column]..column_pointers[column + 1]];
+        assert!(column_rows.windows(2).all(|pair| pair[0] < pair[1]));
+        for (row, value) in column_rows
+            .iter()
+            .zip(values[column_pointers[column]..column_pointers[column + 1]].iter())
+        {
+            assert!((matrix.get(&(*row, column)).unwrap() - value).norm() < 1e-12);
+        }
+    }
+
+    // Errors for empty operators without number of spins and symbolic coefficients
+    assert!(SpinOperator::new().sparse_matrix_csr(None).is_err());
+    assert!(SpinOperator::new().sparse_matrix_csc(None).is_err());
+    so.set(PauliProduct::new().z(1), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.sparse_matrix_csr(Some(3)).is_err());
+    assert!(so.sparse_matrix_csc(Some(3)).is_err());
+}
+
+// Test the hermitian_part and anti_hermitian_part functions of the SpinOperator
+#[test]
+fn hermitian_part() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().z(0), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    so.set(
+        PauliProduct::new().x(0).y(1),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().x(2), CalculatorComplex::from(3.0))
+        .unwrap();
+
+    let hermitian = so.hermitian_part();
+    let anti_hermitian = so.anti_hermitian_part();
+    assert_eq!(hermitian.len(), 2);
+    assert_eq!(
+        hermitian.get(&PauliProduct::new().z(0)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        anti_hermitian.get(&PauliProduct::new().x(0).y(1)),
+        &CalculatorComplex::new(0.0, -0.5)
+    );
+    assert!(SpinHamiltonian::try_from(hermitian.clone()).is_ok());
+    assert_eq!(hermitian.hermitian_conjugate(), hermitian);
+    assert_eq!(
+        anti_hermitian.hermitian_conjugate(),
+        -anti_hermitian.clone()
+    );
+    assert_eq!(hermitian + anti_hermitian, so);
+}
+
+// Test the coefficient_statistics function of the SpinOperator
+#[test]
+fn coefficient_statistics() {
+    use struqture::CoefficientStats;
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from(-2.0))
+        .unwrap();
+    so.set(PauliProduct::new().x(1), CalculatorComplex::new(3.0, 4.0))
+        .unwrap();
+    so.set(PauliProduct::new().y(2), CalculatorComplex::new(0.0, 0.5))
+        .unwrap();
+    so.set(PauliProduct::new().x(3), CalculatorComplex::from("theta"))
+        .unwrap();
+
+    assert_eq!(
+        so.coefficient_statistics().unwrap(),
+        CoefficientStats {
+            num_terms: 4,
+            max_abs: 5.0,
+            min_abs: 0.5,
+            mean_abs: 2.5,
+            num_symbolic: 1,
+        }
+    );
+    assert_eq!(
+        SpinOperator::new().coefficient_statistics().unwrap(),
+        CoefficientStats {
+            num_terms: 0,
+            max_abs: 0.0,
+            min_abs: 0.0,
+            mean_abs: 0.0,
+            num_symbolic: 0,
+        }
+    );
+}
+
+// Test the convert_keys function of the SpinOperator
+#[test]
+fn convert_keys() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(1).y(4), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let shifted = so
+        .clone()
+        .convert_keys(|pp| {
+            pp.iter().fold(PauliProduct::new(), |new, (index, op)| {
+                new.set_pauli(index + 1, *op)
+            })
+        })
+        .unwrap();
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(3), CalculatorComplex::from(0.5))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().x(2).y(5), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(shifted, expected);
+
+    // Z0 and Z2 are both mapped to Z0, their coefficients are added up
+    let folded = so
+        .convert_keys(|pp| {
+            pp.iter().fold(PauliProduct::new(), |new, (index, op)| {
+                new.set_pauli(index % 2, *op)
+            })
+        })
+        .unwrap();
+    assert_eq!(folded.len(), 2);
+    assert_eq!(
+        folded.get(&PauliProduct::new().z(0)),
+        &CalculatorComplex::from(1.5)
+    );
+    assert_eq!(
+        folded.get(&PauliProduct::new().y(0).x(1)),
+        &CalculatorComplex::from(2.0)
+    );
+}
+
+// Test the from_hashmap function of the SpinOperator
+#[test]
+fn from_hashmap() {
+    let pp_0 = PauliProduct::new().z(0);
+    let pp_1 = PauliProduct::new().x(1).y(2);
+    let pp_2 = PauliProduct::new().y(0).z(3);
+    let mut map: HashMap<PauliProduct, CalculatorComplex> = HashMap::new();
+    map.insert(pp_0.clone(), CalculatorComplex::from(0.5));
+    map.insert(pp_1.clone(), CalculatorComplex::new(0.0, 1.0));
+    map.insert(pp_2.clone(), CalculatorComplex::from("theta"));
+
+    let mut so = SpinOperator::new();
+    so.set(pp_0, CalculatorComplex::from(0.5)).unwrap();
+    so.set(pp_1, CalculatorComplex::new(0.0, 1.0)).unwrap();
+    so.set(pp_2, CalculatorComplex::from("theta")).unwrap();
+
+    let from_map = SpinOperator::from_hashmap(map);
+    assert_eq!(from_map.len(), 3);
+    assert_eq!(from_map, so);
+
+    let mut zero_map: HashMap<PauliProduct, CalculatorComplex> = HashMap::new();
+    zero_map.insert(PauliProduct::new().x(0), CalculatorComplex::ZERO);
+    assert_eq!(SpinOperator::from_hashmap(zero_map), SpinOperator::new());
+}
+
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -139,6 +576,245 @@ fn internal_map_add_operator_product() {
     assert_eq!(so.get(&pp_2), &CalculatorComplex::from(0.0));
 }
 
+// Test the set_new function of the SpinOperator
+#[test]
+fn internal_map_set_new() {
+    let pp_2: PauliProduct = PauliProduct::new().z(2);
+    let mut so = SpinOperator::new();
+
+    so.set_new(pp_2.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(so.get(&pp_2), &CalculatorComplex::from(0.5));
+
+    let error = so.set_new(pp_2.clone(), CalculatorComplex::from(1.0));
+    assert_eq!(
+        error,
+        Err(StruqtureError::OperatorIndexAlreadyOccupied {
+            index: format!("{:?}", pp_2)
+        })
+    );
+    assert_eq!(so.get(&pp_2), &CalculatorComplex::from(0.5));
+    assert_eq!(so.len(), 1);
+}
+
+// Test the to_hdf5 and from_hdf5 functions of the SpinOperator
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 0.5))
+        .unwrap();
+    so.set(PauliProduct::new().z(1).y(3), CalculatorComplex::from(-2.0))
+        .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::new(0.0, 3.0))
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("spin_operator_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("operator").unwrap();
+        so.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("operator").unwrap();
+    let read = SpinOperator::from_hdf5(&group).unwrap();
+    assert_eq!(read, so);
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .set(PauliProduct::new().x(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    let temporary_symbolic_file = crate::TemporaryHdf5File::new("spin_operator_hdf5_symbolic");
+    let file = hdf5::File::create(temporary_symbolic_file.path()).unwrap();
+    assert!(matches!(
+        symbolic.to_hdf5(&file),
+        Err(StruqtureError::CalculatorError(_))
+    ));
+}
+
+// Test the add_operator_products_batch function of the SpinOperator
+#[test]
+fn add_operator_products_batch() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(0).x(1);
+    let mut so = SpinOperator::new();
+    so.set(pp_0.clone(), CalculatorComplex::from(0.5)).unwrap();
+
+    so.add_operator_products_batch(vec![
+        (pp_0.clone(), CalculatorComplex::from(1.0)),
+        (pp_1.clone(), CalculatorComplex::from(2.0)),
+        (pp_1.clone(), CalculatorComplex::from(-2.0)),
+        (pp_0.clone(), CalculatorComplex::new(0.0, 1.0)),
+    ])
+    .unwrap();
+    assert_eq!(so.get(&pp_0), &CalculatorComplex::new(1.5, 1.0));
+    assert_eq!(so.get(&pp_1), &CalculatorComplex::from(0.0));
+    assert_eq!(so.len(), 1);
+}
+
+// Test that add_operator_products_batch of the SpinOperator removes terms cancelling existing terms
+#[test]
+fn add_operator_products_batch_cancel_existing() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(0).x(1);
+    let mut so = SpinOperator::new();
+    so.set(pp_0.clone(), CalculatorComplex::from(0.5)).unwrap();
+
+    so.add_operator_products_batch(vec![
+        (pp_0.clone(), CalculatorComplex::from(-0.5)),
+        (pp_1.clone(), CalculatorComplex::from(1.0)),
+    ])
+    .unwrap();
+    assert_eq!(so.get(&pp_0), &CalculatorComplex::from(0.0));
+    assert_eq!(so.get(&pp_1), &CalculatorComplex::from(1.0));
+    assert_eq!(so.len(), 1);
+}
+
+// Test the drain_filter function of the SpinOperator
+#[test]
+fn internal_map_drain_filter() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(0).x(1);
+    let pp_2: PauliProduct = PauliProduct::new().y(1).z(2);
+    let pp_3: PauliProduct = PauliProduct::new().x(0).y(1).z(2);
+    let mut so = SpinOperator::new();
+    so.set(pp_0.clone(), CalculatorComplex::from(0.5)).unwrap();
+    so.set(pp_1.clone(), CalculatorComplex::from(1.0)).unwrap();
+    so.set(pp_2.clone(), CalculatorComplex::from(1.5)).unwrap();
+    so.set(pp_3.clone(), CalculatorComplex::from(2.0)).unwrap();
+
+    let mut drained = so.drain_filter(|key, _| key.len() == 2);
+    drained.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut expected = vec![
+        (pp_1.clone(), CalculatorComplex::from(1.0)),
+        (pp_2.clone(), CalculatorComplex::from(1.5)),
+    ];
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(drained, expected);
+
+    let mut kept = SpinOperator::new();
+    kept.set(pp_0, CalculatorComplex::from(0.5)).unwrap();
+    kept.set(pp_3, CalculatorComplex::from(2.0)).unwrap();
+    assert_eq!(so, kept);
+    assert_eq!(so.get(&pp_1), &CalculatorComplex::from(0.0));
+    assert_eq!(so.get(&pp_2), &CalculatorComplex::from(0.0));
+
+    let nothing = so.drain_filter(|_, _| false);
+    assert!(nothing.is_empty());
+    assert_eq!(so, kept);
+}
+
+// Test the to_sorted_json_lines and from_sorted_json_lines functions of the SpinOperator
+#[cfg(feature = "json")]
+#[test]
+fn sorted_json_lines() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.set(
+        PauliProduct::new().x(0).x(1),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    so.set(PauliProduct::new().y(2), CalculatorComplex::from("param"))
+        .unwrap();
+    let mut so_changed = so.clone();
+    so_changed
+        .set(PauliProduct::new().z(1), CalculatorComplex::from(-1.0))
+        .unwrap();
+
+    let lines = so.to_sorted_json_lines();
+    let lines_changed = so_changed.to_sorted_json_lines();
+    assert_eq!(lines.lines().count(), 4);
+    assert_eq!(lines_changed.lines().count(), 5);
+    let added: Vec<&str> = lines_changed
+        .lines()
+        .filter(|line| !lines.lines().any(|other| other == *line))
+        .collect();
+    assert_eq!(added.len(), 1);
+    assert!(added[0].contains("1Z"));
+
+    assert_eq!(SpinOperator::from_sorted_json_lines(&lines).unwrap(), so);
+    assert_eq!(
+        SpinOperator::from_sorted_json_lines(&lines_changed).unwrap(),
+        so_changed
+    );
+    assert!(SpinOperator::from_sorted_json_lines("").is_err());
+    assert!(SpinOperator::from_sorted_json_lines(&format!("{}\n[\"0X\"]", lines)).is_err());
+}
+
+// Test the trace_distance function of the SpinOperator
+#[cfg(feature = "linalg")]
+#[test_case(1, 2.0_f64.sqrt(); "one spin")]
+#[test_case(2, 2.0 * 2.0_f64.sqrt(); "two spins")]
+fn trace_distance(number_spins: usize, expected: f64) {
+    let mut so_x = SpinOperator::new();
+    so_x.set(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut so_z = SpinOperator::new();
+    so_z.set(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    assert!(so_x.trace_distance(&so_x, number_spins).unwrap().abs() < 1e-10);
+    assert!((so_x.trace_distance(&so_z, number_spins).unwrap() - expected).abs() < 1e-10);
+    assert!((so_z.trace_distance(&so_x, number_spins).unwrap() - expected).abs() < 1e-10);
+}
+
+#[cfg(feature = "linalg")]
+#[test]
+fn trace_distance_error() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(2), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        so.trace_distance(&SpinOperator::new(), 2),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test the multiply_by_product_left and multiply_by_product_right functions of the SpinOperator
+#[test]
+fn multiply_by_product_left_right() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.set(PauliProduct::new().z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    let product = PauliProduct::new().z(0);
+
+    let mut left = so.clone();
+    left.multiply_by_product_left(&product, CalculatorComplex::from(0.5));
+    let mut expected_left = SpinOperator::new();
+    expected_left
+        .set(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 0.5))
+        .unwrap();
+    expected_left
+        .set(PauliProduct::new().z(0).z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(left, expected_left);
+    assert_eq!(
+        left,
+        (SpinOperator::new() + vec![(product.clone(), CalculatorComplex::from(0.5))]) * so.clone()
+    );
+
+    let mut right = so.clone();
+    right.multiply_by_product_right(&product, CalculatorComplex::from(0.5));
+    let mut expected_right = SpinOperator::new();
+    expected_right
+        .set(PauliProduct::new().y(0), CalculatorComplex::new(0.0, -0.5))
+        .unwrap();
+    expected_right
+        .set(PauliProduct::new().z(0).z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(right, expected_right);
+    assert_eq!(
+        right,
+        so * (SpinOperator::new() + vec![(product, CalculatorComplex::from(0.5))])
+    );
+
+    assert_ne!(left, right);
+}
+
 // Test the iter, keys and values functions of the SpinOperator
 #[test]
 fn internal_map_keys() {
@@ -166,60 +842,667 @@ fn internal_map_keys() {
     }
 }
 
-// Test the Iter traits of SpinOperator: into_iter, from_iter and extend
+// Test the in-place arithmetic operators of SpinOperator: +=, -= and *=
+#[test]
+fn assign_operators() {
+    let mut a = SpinOperator::new();
+    a.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    a.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(0.5, 1.0))
+        .unwrap();
+    let mut b = SpinOperator::new();
+    b.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    b.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::from(-1.0))
+        .unwrap();
+
+    let mut added = a.clone();
+    added += b.clone();
+    assert_eq!(added, a.clone() + b.clone());
+
+    let mut subtracted = a.clone();
+    subtracted -= b.clone();
+    assert_eq!(subtracted, a.clone() - b.clone());
+
+    let mut scaled = a.clone();
+    scaled *= CalculatorComplex::new(0.0, 2.0);
+    assert_eq!(scaled, a.clone() * CalculatorComplex::new(0.0, 2.0));
+    let mut scaled = a.clone();
+    scaled *= CalculatorFloat::from(3.0);
+    assert_eq!(scaled, a * CalculatorFloat::from(3.0));
+}
+
+// Test the Iter traits of SpinOperator: into_iter, from_iter and extend
+#[test]
+fn into_iter_from_iter_extend() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(1);
+    let mut system = SpinOperator::new();
+    system
+        .add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let system_iter = system.clone().into_iter();
+    assert_eq!(SpinOperator::from_iter(system_iter), system);
+    let system_iter = (&system)
+        .into_iter()
+        .map(|(key, value)| (key.clone(), value.clone()));
+    assert_eq!(SpinOperator::from_iter(system_iter), system);
+    let mut hamiltonian = SpinOperator::new();
+    hamiltonian
+        .add_operator_product(pp_0.clone(), 1.0.into())
+        .unwrap();
+    for (first, second) in system.into_iter().zip(hamiltonian.iter()) {
+        assert_eq!(first.0, *second.0);
+        assert_eq!(first.1, *second.1);
+    }
+
+    let mut system = SpinOperator::new();
+    system
+        .add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut mapping: BTreeMap<PauliProduct, CalculatorComplex> = BTreeMap::new();
+    mapping.insert(pp_1.clone(), CalculatorComplex::from(0.5));
+    let mapping_iter = mapping.into_iter();
+    system.extend(mapping_iter);
+
+    let mut system_1 = SpinOperator::new();
+    system_1
+        .add_operator_product(pp_0, CalculatorComplex::from(1.0))
+        .unwrap();
+    system_1
+        .add_operator_product(pp_1, CalculatorComplex::from(0.5))
+        .unwrap();
+
+    assert_eq!(system, system_1);
+}
+
+#[test]
+fn from_operator_pass() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let mut so_0 = SpinHamiltonian::new();
+    let _ = so_0.add_operator_product(pp_0.clone(), CalculatorFloat::from(1.0));
+    let mut so_0_1 = SpinOperator::new();
+    let _ = so_0_1.add_operator_product(pp_0, CalculatorComplex::from(1.0));
+
+    assert_eq!(SpinOperator::from(so_0), so_0_1);
+}
+
+// Test the from_csv_reader and to_csv functions of the SpinOperator
+#[test]
+fn csv_round_trip() {
+    let input = "key,real,imag\n0X1Z, 1.0, 0.5\n\nI,-2.0,0.0\n1Z0X,0.5,0\n";
+    let so = SpinOperator::from_csv_reader(input.as_bytes()).unwrap();
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0).z(1),
+            CalculatorComplex::new(1.5, 0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::new(-2.0, 0.0))
+        .unwrap();
+    assert_eq!(so, expected);
+
+    let csv = so.to_csv().unwrap();
+    assert_eq!(csv, "key,real,imag\nI,-2,0\n0X1Z,1.5,0.5\n");
+    assert_eq!(SpinOperator::from_csv_reader(csv.as_bytes()).unwrap(), so);
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from("a"))
+        .unwrap();
+    assert!(symbolic.to_csv().is_err());
+}
+
+// Test the errors of the from_csv_reader function of the SpinOperator
+#[test_case("0X,1.0\n", 1; "missing column")]
+#[test_case("0X,1.0,0.0\n0Q,1.0,0.0\n", 2; "invalid key")]
+#[test_case("key,real,imag\n0X,1.0,0.0\n0Z,a,0.0\n", 3; "invalid real")]
+#[test_case("0X,1.0,0.0\n\n0Z,1.0,b\n", 3; "invalid imag")]
+fn csv_errors(input: &str, line_number: usize) {
+    match SpinOperator::from_csv_reader(input.as_bytes()) {
+        Err(StruqtureError::ParsingError { target_type, msg }) => {
+            assert_eq!(target_type, "SpinOperator");
+            assert!(msg.starts_with(&format!("Line {}:", line_number)));
+        }
+        _ => panic!("Expected a ParsingError"),
+    }
+}
+
+// Test the variance_computational_basis function of the SpinOperator
+#[test_case(0, Complex64::new(1.0, 0.0); "state_0")]
+#[test_case(1, Complex64::new(-1.0, 0.0); "state_1")]
+#[test_case(2, Complex64::new(1.0, 0.0); "state_2")]
+fn variance_computational_basis_z(state_index: usize, expectation_value: Complex64) {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        so.expectation_value_computational_basis(state_index, 2),
+        Ok(expectation_value)
+    );
+    assert_eq!(
+        so.variance_computational_basis(state_index, 2),
+        Ok(Complex64::new(0.0, 0.0))
+    );
+}
+
+// Test the variance_computational_basis function of the SpinOperator for a non-diagonal operator
+#[test]
+fn variance_computational_basis_x() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        so.expectation_value_computational_basis(2, 2),
+        Ok(Complex64::new(-1.0, 0.0))
+    );
+    assert_eq!(
+        so.variance_computational_basis(2, 2),
+        Ok(Complex64::new(4.0, 0.0))
+    );
+
+    assert_eq!(
+        so.variance_computational_basis(0, 1),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(so.variance_computational_basis(4, 2).is_err());
+}
+
+// Test the find_terms_with function of the SpinOperator
+#[test]
+fn find_terms_with() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(0).z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(0).x(2), CalculatorComplex::from(3.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(4.0))
+        .unwrap();
+
+    let mut found = so.find_terms_with(0, SingleSpinOperator::X);
+    found.sort_by_key(|(a, _)| *a);
+    assert_eq!(
+        found,
+        vec![
+            (&PauliProduct::new().x(0), &CalculatorComplex::from(1.0)),
+            (
+                &PauliProduct::new().x(0).z(1),
+                &CalculatorComplex::from(2.0)
+            ),
+        ]
+    );
+
+    let found = so.find_terms_with(0, SingleSpinOperator::Identity);
+    assert_eq!(
+        found,
+        vec![(&PauliProduct::new().z(1), &CalculatorComplex::from(4.0))]
+    );
+    assert!(so.find_terms_with(2, SingleSpinOperator::Z).is_empty());
+}
+
+// Test the lazy sparse_matrix_entries iterator of the SpinOperator
+#[test]
+fn sparse_matrix_entries() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).z(2),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(-1.0))
+        .unwrap();
+
+    for number_spins in [None, Some(4)] {
+        let entries: Vec<(usize, usize, Complex64)> =
+            so.sparse_matrix_entries(number_spins).unwrap().collect();
+        let (values, (rows, columns)) = so.sparse_matrix_coo(number_spins).unwrap();
+        assert_eq!(entries.len(), values.len());
+        let coo_entries: HashMap<(usize, usize), Complex64> =
+            rows.into_iter().zip(columns).zip(values).collect();
+        let lazy_entries: HashMap<(usize, usize), Complex64> = entries
+            .into_iter()
+            .map(|(row, column, value)| ((row, column), value))
+            .collect();
+        assert_eq!(lazy_entries, coo_entries);
+    }
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from("a"))
+        .unwrap();
+    assert!(symbolic.sparse_matrix_entries(None).is_err());
+}
+
+// Test the round_coefficients function of the SpinOperator
+#[test]
+fn round_coefficients() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0),
+        CalculatorComplex::new(0.333333, 0.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().z(1),
+        CalculatorComplex::new(0.4999999999, -0.6666667),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::new(0.01, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(3), CalculatorComplex::new(-2.0, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(4), CalculatorComplex::from("a"))
+        .unwrap();
+
+    let rounded = so.round_coefficients(10);
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0),
+            CalculatorComplex::new(1.0 / 3.0, 0.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            PauliProduct::new().z(1),
+            CalculatorComplex::new(0.5, -2.0 / 3.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(3), CalculatorComplex::new(-2.0, 0.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().x(4), CalculatorComplex::from("a"))
+        .unwrap();
+    assert_eq!(rounded, expected);
+
+    assert_eq!(
+        so.round_coefficients(100).get(&PauliProduct::new().y(2)),
+        &CalculatorComplex::new(0.01, 0.0)
+    );
+    assert_eq!(
+        so.round_coefficients(2).get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::new(0.5, 0.0)
+    );
+}
+
+// Test the substitute_parameters function of the SpinOperator
+#[test]
+fn substitute_parameters() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().z(1),
+        CalculatorComplex::new(CalculatorFloat::from("2 * phi"), CalculatorFloat::from(0.5)),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(3), CalculatorComplex::from("phi - 1"))
+        .unwrap();
+
+    let mut map: HashMap<String, f64> = HashMap::new();
+    map.insert("theta".to_string(), 0.25);
+    map.insert("phi".to_string(), 1.0);
+    let substituted = so.substitute_parameters(&map).unwrap();
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(0.25))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(2.0, 0.5))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(2), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(substituted, expected);
+
+    map.remove("phi");
+    assert!(matches!(
+        so.substitute_parameters(&map),
+        Err(StruqtureError::CalculatorError(_))
+    ));
+}
+
+// Test the max_locality function of the SpinOperator
+#[test]
+fn max_locality() {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.max_locality(), 0);
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(so.max_locality(), 0);
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(4), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(1).y(2).z(3),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(so.max_locality(), 3);
+}
+
+// Test that truncate_with_error reports the Frobenius norm of the discarded part
+#[test]
+fn truncate_with_error() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.03))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::new(0.5, 0.04))
+        .unwrap();
+
+    let (truncated, error) = so.truncate_with_error(0.1);
+    assert_eq!(truncated, so.truncate(0.1));
+
+    let difference = so.clone() - truncated;
+    let expected: f64 = difference
+        .values()
+        .map(|value| {
+            let re: f64 = value.re.clone().try_into().unwrap();
+            let im: f64 = value.im.clone().try_into().unwrap();
+            re * re + im * im
+        })
+        .sum::<f64>()
+        .sqrt();
+    assert!((error - expected).abs() < 1e-12);
+    assert!((error - (0.03_f64.powi(2) + 0.04_f64.powi(2)).sqrt()).abs() < 1e-12);
+
+    let (untouched, no_error) = so.truncate_with_error(0.0);
+    assert_eq!(untouched, so);
+    assert_eq!(no_error, 0.0);
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().x(3), CalculatorComplex::from("a"))
+        .unwrap();
+    let (symbolic_truncated, symbolic_error) = symbolic.truncate_with_error(0.1);
+    assert_eq!(symbolic_truncated, symbolic);
+    assert_eq!(symbolic_error, 0.0);
+}
+
+// Test that truncate_keep_identity keeps a small identity term that truncate removes
+#[test]
+fn truncate_keep_identity() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(0.01))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.001))
+        .unwrap();
+
+    let truncated = so.truncate(0.1);
+    assert_eq!(truncated.len(), 1);
+    assert_eq!(
+        truncated.get(&PauliProduct::new()),
+        &CalculatorComplex::ZERO
+    );
+
+    let kept = so.truncate_keep_identity(0.1);
+    assert_eq!(kept.len(), 2);
+    assert_eq!(
+        kept.get(&PauliProduct::new()),
+        &CalculatorComplex::from(0.01)
+    );
+    assert_eq!(
+        kept.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::from(1.0)
+    );
+
+    let mut without_identity = SpinOperator::new();
+    without_identity
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        without_identity.truncate_keep_identity(0.1),
+        without_identity
+    );
+}
+
+// Test the normalized_overlap function of the SpinOperator
+#[test_case(PauliProduct::new().x(0), PauliProduct::new().x(0), 1.0; "x0 x0")]
+#[test_case(PauliProduct::new().x(0), PauliProduct::new().z(0), 0.0; "x0 z0")]
+#[test_case(PauliProduct::new().x(0), PauliProduct::new().x(0).z(3), 0.0; "x0 x0z3")]
+fn normalized_overlap(left: PauliProduct, right: PauliProduct, expected: f64) {
+    let mut a = SpinOperator::new();
+    a.add_operator_product(left, CalculatorComplex::from(2.0))
+        .unwrap();
+    let mut b = SpinOperator::new();
+    b.add_operator_product(right, CalculatorComplex::from(0.5))
+        .unwrap();
+    assert!((a.normalized_overlap(&b).unwrap() - expected).abs() < 1e-12);
+}
+
+// Test the normalized_overlap function of the SpinOperator for partially overlapping and zero operators
+#[test]
+fn normalized_overlap_partial() {
+    let mut a = SpinOperator::new();
+    a.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut b = a.clone();
+    b.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert!((a.normalized_overlap(&b).unwrap() - 1.0 / 2.0_f64.sqrt()).abs() < 1e-12);
+    assert!(
+        (a.normalized_overlap(&(a.clone() * CalculatorFloat::from(-3.0)))
+            .unwrap()
+            + 1.0)
+            .abs()
+            < 1e-12
+    );
+    assert!(a.normalized_overlap(&SpinOperator::new()).is_err());
+}
+
+// Test the to_sympy_string function of the SpinOperator
+#[test]
+fn to_sympy_string() {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.to_sympy_string(""), "0");
+    so.set(PauliProduct::new().x(0).z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(so.to_sympy_string(""), "0.5*Pauli('X',0)*Pauli('Z',1)");
+    assert_eq!(
+        so.to_sympy_string("sp."),
+        "0.5*sp.Pauli('X',0)*sp.Pauli('Z',1)"
+    );
+
+    so.set(PauliProduct::new(), CalculatorComplex::new(0.0, -2.0))
+        .unwrap();
+    so.set(PauliProduct::new().y(2), CalculatorComplex::from("theta"))
+        .unwrap();
+    so.set(PauliProduct::new().z(3), CalculatorComplex::new(1.0, 0.25))
+        .unwrap();
+    assert_eq!(
+        so.to_sympy_string(""),
+        "-2*I + (theta)*Pauli('Y',2) + (1 + 0.25*I)*Pauli('Z',3) + 0.5*Pauli('X',0)*Pauli('Z',1)"
+    );
+}
+
+// Test the real_part and imaginary_part functions of the SpinOperator
+#[test]
+fn real_and_imaginary_part() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::new(0.0, -3.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(3),
+        CalculatorComplex::new("theta", "phi"),
+    )
+    .unwrap();
+
+    let real_part = so.real_part();
+    assert_eq!(real_part.len(), 3);
+    assert_eq!(
+        real_part.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        real_part.get(&PauliProduct::new().y(2)),
+        &CalculatorComplex::ZERO
+    );
+    assert_eq!(
+        real_part.get(&PauliProduct::new().x(3)),
+        &CalculatorComplex::new("theta", 0.0)
+    );
+
+    let imaginary_part = so.imaginary_part();
+    assert_eq!(imaginary_part.len(), 3);
+    assert_eq!(
+        imaginary_part.get(&PauliProduct::new().y(2)),
+        &CalculatorComplex::from(-3.0)
+    );
+    assert_eq!(
+        imaginary_part.get(&PauliProduct::new().z(1)),
+        &CalculatorComplex::ZERO
+    );
+    assert_eq!(
+        imaginary_part.get(&PauliProduct::new().x(3)),
+        &CalculatorComplex::new("phi", 0.0)
+    );
+
+    let reconstructed = real_part + imaginary_part * CalculatorComplex::new(0.0, 1.0);
+    let numeric = |operator: &SpinOperator| -> Vec<(PauliProduct, CalculatorComplex)> {
+        let mut terms: Vec<(PauliProduct, CalculatorComplex)> = operator
+            .iter()
+            .filter(|(key, _)| **key != PauliProduct::new().x(3))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+        terms
+    };
+    assert_eq!(numeric(&reconstructed), numeric(&so));
+}
+
+// Test the diagonal_vector function of the SpinOperator
 #[test]
-fn into_iter_from_iter_extend() {
-    let pp_0: PauliProduct = PauliProduct::new().z(0);
-    let pp_1: PauliProduct = PauliProduct::new().x(1);
-    let mut system = SpinOperator::new();
-    system
-        .add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+fn diagonal_vector() {
+    let c = |re: f64| Complex64::new(re, 0.0);
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
         .unwrap();
+    assert_eq!(
+        so.diagonal_vector(2).unwrap(),
+        vec![c(1.0), c(-1.0), c(1.0), c(-1.0)]
+    );
 
-    let system_iter = system.clone().into_iter();
-    assert_eq!(SpinOperator::from_iter(system_iter), system);
-    let system_iter = (&system)
-        .into_iter()
-        .map(|(key, value)| (key.clone(), value.clone()));
-    assert_eq!(SpinOperator::from_iter(system_iter), system);
-    let mut hamiltonian = SpinOperator::new();
-    hamiltonian
-        .add_operator_product(pp_0.clone(), 1.0.into())
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 1.0))
         .unwrap();
-    for (first, second) in system.into_iter().zip(hamiltonian.iter()) {
-        assert_eq!(first.0, *second.0);
-        assert_eq!(first.1, *second.1);
+    so.add_operator_product(PauliProduct::new().z(0).z(2), CalculatorComplex::from(2.0))
+        .unwrap();
+    let diagonal = so.diagonal_vector(3).unwrap();
+    let matrix = so.sparse_matrix(Some(3)).unwrap();
+    assert_eq!(matrix.len(), 8);
+    for (index, entry) in diagonal.iter().enumerate() {
+        assert!((matrix[&(index, index)] - entry).norm() < 1e-12);
     }
 
-    let mut system = SpinOperator::new();
-    system
-        .add_operator_product(pp_0.clone(), CalculatorComplex::from(1.0))
+    assert_eq!(
+        so.diagonal_vector(2),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    so.add_operator_product(PauliProduct::new().x(1), CalculatorComplex::from(1.0))
         .unwrap();
-    let mut mapping: BTreeMap<PauliProduct, CalculatorComplex> = BTreeMap::new();
-    mapping.insert(pp_1.clone(), CalculatorComplex::from(0.5));
-    let mapping_iter = mapping.into_iter();
-    system.extend(mapping_iter);
+    assert!(matches!(
+        so.diagonal_vector(3),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
 
-    let mut system_1 = SpinOperator::new();
-    system_1
-        .add_operator_product(pp_0, CalculatorComplex::from(1.0))
+// Test that the to_canonical_hermitian_form function leaves a SpinOperator unchanged as PauliProducts are self-adjoint
+#[test]
+fn to_canonical_hermitian_form() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).y(1), CalculatorComplex::from(1.0))
         .unwrap();
-    system_1
-        .add_operator_product(pp_1, CalculatorComplex::from(0.5))
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::from(-0.5))
         .unwrap();
+    assert_eq!(so.to_canonical_hermitian_form(), so);
+}
 
-    assert_eq!(system, system_1);
+// Test the reverse_qubit_order function of the SpinOperator
+#[test]
+fn reverse_qubit_order() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    let reversed = so.reverse_qubit_order(3).unwrap();
+    let mut expected = SpinOperator::new();
+    expected
+        .set(PauliProduct::new().x(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(reversed, expected);
+
+    so.set(
+        PauliProduct::new().z(0).y(1),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    so.set(PauliProduct::new(), CalculatorComplex::from(2.0))
+        .unwrap();
+    let reversed = so.reverse_qubit_order(3).unwrap();
+    assert_eq!(
+        reversed.get(&PauliProduct::new().y(1).z(2)),
+        &CalculatorComplex::new(0.0, 1.0)
+    );
+    assert_eq!(
+        reversed.get(&PauliProduct::new()),
+        &CalculatorComplex::from(2.0)
+    );
+    assert_eq!(reversed.reverse_qubit_order(3), Ok(so));
 }
 
+// Test that reverse_qubit_order returns an error for too few spins
 #[test]
-fn from_operator_pass() {
-    let pp_0: PauliProduct = PauliProduct::new().z(0);
-    let mut so_0 = SpinHamiltonian::new();
-    let _ = so_0.add_operator_product(pp_0.clone(), CalculatorFloat::from(1.0));
-    let mut so_0_1 = SpinOperator::new();
-    let _ = so_0_1.add_operator_product(pp_0, CalculatorComplex::from(1.0));
+fn reverse_qubit_order_too_few_spins() {
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(3), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(
+        so.reverse_qubit_order(3),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
 
-    assert_eq!(SpinOperator::from(so_0), so_0_1);
+// Test the validate_within_qubits function of the SpinOperator
+#[test_case(4, true; "in range")]
+#[test_case(3, false; "out of range")]
+#[test_case(0, false; "no spins")]
+fn validate_within_qubits(number_spins: usize, valid: bool) {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.validate_within_qubits(0), Ok(()));
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1).y(3), CalculatorComplex::from(0.5))
+        .unwrap();
+    if valid {
+        assert_eq!(so.validate_within_qubits(number_spins), Ok(()));
+    } else {
+        assert_eq!(
+            so.validate_within_qubits(number_spins),
+            Err(StruqtureError::NumberSpinsExceeded)
+        );
+    }
 }
 
 // Test the separation of terms
@@ -359,6 +1642,189 @@ fn sub_so_so() {
     assert_eq!(so_0.sub(so_1), so_0_1);
 }
 
+// Test the commutator and anticommutator functions of the SpinOperator
+#[test]
+fn commutator_anticommutator() {
+    let mut x = SpinOperator::new();
+    x.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut y = SpinOperator::new();
+    y.add_operator_product(PauliProduct::new().y(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let mut expected_commutator = SpinOperator::new();
+    expected_commutator
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    assert_eq!(x.commutator(&y), expected_commutator);
+    assert!(x.anticommutator(&y).is_empty());
+
+    let mut expected_anticommutator = SpinOperator::new();
+    expected_anticommutator
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(x.anticommutator(&x), expected_anticommutator);
+    assert!(x.commutator(&x).is_empty());
+}
+
+// Test the group_commuting_terms function of the SpinOperator
+#[test]
+fn group_commuting_terms() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).x(1),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let qubit_wise = so.group_commuting_terms(GroupingStrategy::Greedy(CommutationKind::QubitWise));
+    assert_eq!(qubit_wise.len(), 2);
+    let general = so.group_commuting_terms(GroupingStrategy::LargestDegreeFirst(
+        CommutationKind::General,
+    ));
+    assert_eq!(general.len(), 2);
+    for groups in [qubit_wise, general] {
+        let mut reconstructed = SpinOperator::new();
+        for group in groups {
+            reconstructed += group;
+        }
+        assert_eq!(reconstructed, so);
+    }
+}
+
+// Test the tensor function of the SpinOperator
+#[test]
+fn tensor() {
+    let mut x = SpinOperator::new();
+    x.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    let mut y = SpinOperator::new();
+    y.add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    y.add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    // x ⊗ I + I ⊗ y
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(2), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(x.tensor(&y, 2).unwrap(), expected);
+    assert_eq!(SpinOperator::new().tensor(&y, 0).unwrap(), y);
+
+    // The matrix of the sum is the Kronecker sum of the matrices of the subsystems
+    let dimension = 8;
+    let combined = x.tensor(&y, 1).unwrap().sparse_matrix(Some(3)).unwrap();
+    let x_matrix = x.sparse_matrix(Some(1)).unwrap();
+    let y_matrix = y.sparse_matrix(Some(2)).unwrap();
+    for row in 0..dimension {
+        for column in 0..dimension {
+            let mut value = Complex64::new(0.0, 0.0);
+            if row >> 1 == column >> 1 {
+                value += x_matrix
+                    .get(&(row % 2, column % 2))
+                    .copied()
+                    .unwrap_or_default();
+            }
+            if row % 2 == column % 2 {
+                value += y_matrix
+                    .get(&(row >> 1, column >> 1))
+                    .copied()
+                    .unwrap_or_default();
+            }
+            assert_eq!(
+                combined.get(&(row, column)).copied().unwrap_or_default(),
+                value
+            );
+        }
+    }
+
+    let error = x.tensor(&y, 0);
+    assert!(matches!(error, Err(StruqtureError::GenericError { .. })));
+}
+
+// Test the exp_approx and heisenberg_transform functions of the SpinOperator
+#[test]
+fn exp_approx_heisenberg_transform() {
+    let theta: f64 = 0.3;
+    let mut generator = SpinOperator::new();
+    generator
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.0, theta))
+        .unwrap();
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(theta.cos()))
+        .unwrap();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0),
+            CalculatorComplex::new(0.0, theta.sin()),
+        )
+        .unwrap();
+    assert!((generator.exp_approx(20) - expected)
+        .truncate(1e-12)
+        .is_empty());
+
+    let mut identity = SpinOperator::new();
+    identity
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(SpinOperator::new().exp_approx(5), identity);
+
+    let mut x = SpinOperator::new();
+    x.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut generator = SpinOperator::new();
+    generator
+        .add_operator_product(
+            PauliProduct::new().z(0),
+            CalculatorComplex::new(0.0, theta / 2.0),
+        )
+        .unwrap();
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0),
+            CalculatorComplex::from(1.0 - theta * theta / 2.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::from(-theta))
+        .unwrap();
+    assert!((x.heisenberg_transform(&generator, 2) - expected)
+        .truncate(1e-12)
+        .is_empty());
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(
+            PauliProduct::new().x(0),
+            CalculatorComplex::from(theta.cos()),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            PauliProduct::new().y(0),
+            CalculatorComplex::from(-theta.sin()),
+        )
+        .unwrap();
+    assert!((x.heisenberg_transform(&generator, 20) - expected)
+        .truncate(1e-12)
+        .is_empty());
+    assert_eq!(x.heisenberg_transform(&x, 5), x);
+}
+
 // Test the multiplication: SpinOperator * SpinOperator with all possible pauli matrices
 #[test_case("0X", "0X", "0I", CalculatorComplex::from(1.0); "x_x_identity")]
 #[test_case("0X1X", "0X", "0I1X", CalculatorComplex::new(1.0, 0.0); "x_x")]
@@ -548,6 +2014,77 @@ fn serde_json() {
     assert_eq!(so, deserialized);
 }
 
+/// Test the version checked and unchecked json deserialization of the SpinOperator
+#[cfg(feature = "json")]
+#[test]
+fn from_json_unchecked() {
+    use struqture::{from_json, from_json_unchecked, STRUQTURE_VERSION};
+    let mut version = STRUQTURE_VERSION.split('.');
+    let major_version: u32 = version.next().unwrap().parse().unwrap();
+    let minor_version: u32 = version.next().unwrap().parse().unwrap();
+
+    let pp = PauliProduct::new().x(0);
+    let mut so = SpinOperator::new();
+    so.set(pp, CalculatorComplex::from(1.0)).unwrap();
+
+    let serialized = serde_json::to_string(&so).unwrap();
+    let checked: SpinOperator = from_json(&serialized).unwrap();
+    assert_eq!(checked, so);
+
+    let mut bumped: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+    bumped["_struqture_version"]["major_version"] = major_version.into();
+    bumped["_struqture_version"]["minor_version"] = (minor_version + 1).into();
+    let bumped = bumped.to_string();
+
+    let rejected: Result<SpinOperator, StruqtureError> = from_json(&bumped);
+    assert_eq!(
+        rejected,
+        Err(StruqtureError::VersionMissmatch {
+            library_major_version: major_version,
+            library_minor_version: minor_version,
+            data_major_version: major_version,
+            data_minor_version: minor_version + 1,
+        })
+    );
+    let unchecked: SpinOperator = from_json_unchecked(&bumped).unwrap();
+    assert_eq!(unchecked, so);
+
+    let invalid: Result<SpinOperator, StruqtureError> = from_json_unchecked("{\"items\": 1}");
+    assert!(invalid.is_err());
+}
+
+/// Test the version checked and unchecked bincode deserialization of the SpinOperator
+#[test]
+fn bincode_version_check() {
+    use struqture::{deserialize_with_version_check, STRUQTURE_VERSION};
+    let minor_version: u32 = STRUQTURE_VERSION
+        .split('.')
+        .nth(1)
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let mut so = SpinOperator::new();
+    so.set(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let serialized = bincode::serialize(&so).unwrap();
+    let checked: SpinOperator =
+        deserialize_with_version_check(|| bincode::deserialize(&serialized)).unwrap();
+    assert_eq!(checked, so);
+
+    // The minor version is the last field of the serialized SpinOperator
+    let mut bumped = serialized.clone();
+    let position = bumped.len() - 4;
+    bumped[position..].copy_from_slice(&(minor_version + 1).to_le_bytes());
+
+    let rejected: Result<SpinOperator, bincode::Error> =
+        deserialize_with_version_check(|| bincode::deserialize(&bumped));
+    assert!(rejected.is_err());
+    let unchecked: SpinOperator = bincode::deserialize(&bumped).unwrap();
+    assert_eq!(unchecked, so);
+}
+
 /// Test SpinOperator Serialization and Deserialization traits (readable)
 #[test]
 fn serde_readable() {
@@ -603,6 +2140,60 @@ fn bincode() {
     assert_eq!(so, decoded);
 }
 
+/// Test that symbolic coefficients survive json and bincode round trips exactly
+#[test]
+fn serde_symbolic_round_trip() {
+    use qoqo_calculator::Calculator;
+    let mut so = SpinOperator::new();
+    so.set(
+        PauliProduct::new().x(0).z(1),
+        CalculatorComplex::new("2*J + h", "J"),
+    )
+    .unwrap();
+    so.set(
+        PauliProduct::new().y(2),
+        CalculatorComplex::new(0.5, "-h / 3"),
+    )
+    .unwrap();
+
+    let mut calculator = Calculator::new();
+    calculator.set_variable("J", 0.3);
+    calculator.set_variable("h", 1.7);
+    let evaluate = |operator: &SpinOperator| -> Vec<(PauliProduct, f64, f64)> {
+        let mut values: Vec<(PauliProduct, f64, f64)> = operator
+            .iter()
+            .map(|(key, value)| {
+                (
+                    key.clone(),
+                    calculator.parse_get(value.re.clone()).unwrap(),
+                    calculator.parse_get(value.im.clone()).unwrap(),
+                )
+            })
+            .collect();
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        values
+    };
+    let expected = evaluate(&so);
+    let product = PauliProduct::new().x(0).z(1);
+    let term = expected.iter().find(|(key, _, _)| key == &product).unwrap();
+    assert_eq!(term.1, 2.0 * 0.3 + 1.7);
+
+    let json = serde_json::to_string(&so).unwrap();
+    let from_json: SpinOperator = serde_json::from_str(&json).unwrap();
+    assert_eq!(from_json, so);
+    assert_eq!(evaluate(&from_json), expected);
+
+    let encoded: Vec<u8> = bincode::serialize(&so).unwrap();
+    let from_bincode: SpinOperator = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(from_bincode, so);
+    assert_eq!(evaluate(&from_bincode), expected);
+
+    let encoded: Vec<u8> = bincode::serialize(&so.clone().compact()).unwrap();
+    let from_compact: SpinOperator = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(from_compact, so);
+    assert_eq!(evaluate(&from_compact), expected);
+}
+
 #[test]
 fn serde_compact() {
     use struqture::MINIMUM_STRUQTURE_VERSION;
@@ -712,6 +2303,243 @@ fn test_superoperator(pauli_representation: &str, pauli_operators: &[&str]) {
     }
 }
 
+#[test]
+fn anticommutator_superoperator_single_z() {
+    let mut system = SpinOperator::new();
+    system
+        .set(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    // Z.kron(I) + I.kron(Z.T) = diag(2, 0, 0, -2)
+    let cc0 = Complex64::new(0.0, 0.0);
+    let cc2 = Complex64::new(2.0, 0.0);
+    let test_matrix: na::DMatrix<Complex64> = na::dmatrix![
+        cc2, cc0, cc0, cc0;
+        cc0, cc0, cc0, cc0;
+        cc0, cc0, cc0, cc0;
+        cc0, cc0, cc0, -cc2
+    ];
+
+    let (test_vals, (test_rows, test_columns)) = system
+        .sparse_matrix_anticommutator_superoperator_coo(None)
+        .unwrap();
+    let mut coo_matrix = na::DMatrix::<Complex64>::zeros(4, 4);
+    for (val, (row, column)) in test_vals
+        .iter()
+        .zip(test_rows.iter().zip(test_columns.iter()))
+    {
+        coo_matrix[(*row, *column)] += *val;
+    }
+    assert_eq!(coo_matrix, test_matrix);
+    // The entries are sorted by row and column like the entries of the other superoperators
+    let (_, (rows, columns)) = system
+        .sparse_matrix_anticommutator_superoperator_coo(Some(2))
+        .unwrap();
+    let keys: Vec<(usize, usize)> = rows.into_iter().zip(columns).collect();
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(matches!(
+        system.sparse_matrix_anticommutator_superoperator_coo(Some(usize::BITS as usize / 2)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[test_case("1X", &["X", "I"]; "1X")]
+#[test_case("1Y", &["Y", "I"]; "1Y")]
+#[test_case("0X1Y", &["Y", "X"]; "0X1Y")]
+fn test_anticommutator_superoperator(pauli_representation: &str, pauli_operators: &[&str]) {
+    let mut system = SpinOperator::new();
+    let pp: PauliProduct = PauliProduct::from_str(pauli_representation).unwrap();
+    system.set(pp, CalculatorComplex::from(1.0)).unwrap();
+
+    let dimension = 4_usize.pow(pauli_operators.len() as u32);
+    let identities: Vec<&str> = (0..pauli_operators.len()).map(|_| "I").collect();
+    let i = create_na_matrix_from_operator_list(&identities);
+    let h = create_na_matrix_from_operator_list(pauli_operators);
+    let test_matrix = h.kronecker(&i) + i.kronecker(&h.transpose());
+
+    let (test_vals, (test_rows, test_columns)) = system
+        .sparse_matrix_anticommutator_superoperator_coo(None)
+        .unwrap();
+    let mut coo_matrix = na::DMatrix::<Complex64>::zeros(dimension, dimension);
+    for (val, (row, column)) in test_vals
+        .iter()
+        .zip(test_rows.iter().zip(test_columns.iter()))
+    {
+        coo_matrix[(*row, *column)] += *val;
+    }
+    assert_eq!(coo_matrix, test_matrix);
+}
+
+// Test that the sparse matrix of an empty SpinOperator requires an explicit number of spins
+#[test]
+fn sparse_matrix_empty_operator() {
+    let system = SpinOperator::new();
+
+    assert!(matches!(
+        system.sparse_matrix(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_coo(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    // The 4x4 zero matrix has no non-zero entries
+    assert_eq!(system.sparse_matrix(Some(2)).unwrap(), HashMap::new());
+    let empty_coo: CooSparseMatrix = (vec![], (vec![], vec![]));
+    assert_eq!(system.sparse_matrix_coo(Some(2)).unwrap(), empty_coo);
+    let dense = na::DMatrix::<Complex64>::zeros(4, 4);
+    let mut from_coo = na::DMatrix::<Complex64>::zeros(4, 4);
+    let (values, (rows, columns)) = system.sparse_matrix_coo(Some(2)).unwrap();
+    for (value, (row, column)) in values.into_iter().zip(rows.into_iter().zip(columns)) {
+        from_coo[(row, column)] = value;
+    }
+    assert_eq!(from_coo, dense);
+}
+
+// Test that the sparse matrix of a non-empty SpinOperator acting on no spins is inferred for zero spins
+#[test]
+fn sparse_matrix_identity_operator() {
+    let mut system = SpinOperator::new();
+    system
+        .set(PauliProduct::new(), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let identity_coo: CooSparseMatrix = (vec![Complex64::new(2.0, 0.0)], (vec![0], vec![0]));
+    assert_eq!(system.sparse_matrix_coo(None).unwrap(), identity_coo);
+    let empty_coo: CooSparseMatrix = (vec![], (vec![], vec![]));
+    assert_eq!(
+        system.sparse_matrix_superoperator_coo(None).unwrap(),
+        empty_coo
+    );
+}
+
+// Test that every sparse entry point of an empty SpinOperator requires an explicit number of spins
+#[test]
+fn sparse_matrix_empty_operator_all_entry_points() {
+    let system = SpinOperator::new();
+    let empty_coo: CooSparseMatrix = (vec![], (vec![], vec![]));
+
+    assert!(matches!(
+        system.sparse_matrix_csr(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_csc(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_entries(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_superoperator(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_superoperator_coo(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_superoperator_csr(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_superoperator_entries(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_anticommutator_superoperator_coo(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    // With an explicit number of spins all entry points return the zero matrix
+    assert_eq!(
+        system.sparse_matrix_csr(Some(2)).unwrap(),
+        (vec![], vec![], vec![0; 5])
+    );
+    assert_eq!(
+        system.sparse_matrix_csc(Some(2)).unwrap(),
+        (vec![], vec![], vec![0; 5])
+    );
+    assert_eq!(system.sparse_matrix_entries(Some(2)).unwrap().count(), 0);
+    assert_eq!(
+        system.sparse_matrix_superoperator(Some(2)).unwrap(),
+        HashMap::new()
+    );
+    assert_eq!(
+        system.sparse_matrix_superoperator_coo(Some(2)).unwrap(),
+        empty_coo
+    );
+    assert_eq!(
+        system.sparse_matrix_superoperator_csr(Some(2)).unwrap(),
+        (vec![], vec![], vec![0; 17])
+    );
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_entries(Some(2))
+            .unwrap()
+            .count(),
+        0
+    );
+
+    // The unitary part passes the number of spins explicitly
+    assert_eq!(system.unitary_sparse_matrix_coo().unwrap(), empty_coo);
+}
+
+#[test]
+fn sparse_matrix_coo_ordered_x0() {
+    let mut system = SpinOperator::new();
+    system
+        .set(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let (_, (little_rows, little_columns)) = system
+        .sparse_matrix_coo_ordered(Some(2), MatrixEndianness::LittleEndian)
+        .unwrap();
+    let mut little: Vec<(usize, usize)> = little_rows.into_iter().zip(little_columns).collect();
+    little.sort_unstable();
+    assert_eq!(little, vec![(0, 1), (1, 0), (2, 3), (3, 2)]);
+    assert_eq!(
+        system
+            .sparse_matrix_coo_ordered(Some(2), MatrixEndianness::LittleEndian)
+            .unwrap(),
+        system.sparse_matrix_coo(Some(2)).unwrap()
+    );
+
+    let (_, (big_rows, big_columns)) = system
+        .sparse_matrix_coo_ordered(Some(2), MatrixEndianness::BigEndian)
+        .unwrap();
+    let mut big: Vec<(usize, usize)> = big_rows.into_iter().zip(big_columns).collect();
+    big.sort_unstable();
+    assert_eq!(big, vec![(0, 2), (1, 3), (2, 0), (3, 1)]);
+}
+
+// The operator list is given with qubit 0 as the leftmost (most significant) entry
+#[test_case("0X", &["X", "I"]; "0X")]
+#[test_case("0Z1X", &["Z", "X"]; "0Z1X")]
+#[test_case("0X1Y", &["X", "Y"]; "0X1Y")]
+#[test_case("0Y2Z", &["Y", "I", "Z"]; "0Y2Z")]
+fn test_sparse_matrix_coo_big_endian(pauli_representation: &str, pauli_operators: &[&str]) {
+    let mut system = SpinOperator::new();
+    let pp: PauliProduct = PauliProduct::from_str(pauli_representation).unwrap();
+    system.set(pp, CalculatorComplex::from(1.0)).unwrap();
+
+    let dimension = 2_usize.pow(pauli_operators.len() as u32);
+    let test_matrix = create_na_matrix_from_operator_list(pauli_operators);
+    let (test_vals, (test_rows, test_columns)) = system
+        .sparse_matrix_coo_ordered(Some(pauli_operators.len()), MatrixEndianness::BigEndian)
+        .unwrap();
+    let mut coo_matrix = na::DMatrix::<Complex64>::zeros(dimension, dimension);
+    for (val, (row, column)) in test_vals
+        .iter()
+        .zip(test_rows.iter().zip(test_columns.iter()))
+    {
+        coo_matrix[(*row, *column)] += *val;
+    }
+    assert_eq!(coo_matrix, test_matrix);
+}
+
 #[test_case("0Z", &["Z"]; "0Z")]
 #[test_case("1X", &["X", "I"]; "1X")]
 #[test_case("1Y", &["Y", "I"]; "1Y")]
@@ -879,3 +2707,48 @@ fn test_spin_operator_schema() {
     let validation = schema_checker.validate(&value);
     assert!(validation.is_ok());
 }
+
+#[cfg(all(feature = "json_schema", feature = "json"))]
+#[test]
+fn test_all_spin_json_schemas() {
+    let bundle = struqture::spins::all_json_schemas();
+    let bundle = match bundle {
+        serde_json::Value::Object(ob) => ob,
+        _ => panic!(),
+    };
+    for name in [
+        "PauliProduct",
+        "DecoherenceProduct",
+        "PlusMinusProduct",
+        "SpinOperator",
+        "FrozenSpinOperator",
+        "SpinHamiltonian",
+        "DecoherenceOperator",
+        "PlusMinusOperator",
+        "SpinSystem",
+        "SpinHamiltonianSystem",
+        "SpinLindbladNoiseOperator",
+        "PlusMinusLindbladNoiseOperator",
+        "SpinLindbladNoiseSystem",
+        "SpinLindbladOpenSystem",
+    ] {
+        assert!(bundle.contains_key(name), "missing schema for {}", name);
+    }
+    assert_eq!(bundle.len(), 14);
+
+    let schema = serde_json::to_value(schemars::schema_for!(SpinOperator)).unwrap();
+    assert_eq!(bundle.get("SpinOperator"), Some(&schema));
+    let schema =
+        serde_json::to_value(schemars::schema_for!(struqture::spins::FrozenSpinOperator)).unwrap();
+    assert_eq!(bundle.get("FrozenSpinOperator"), Some(&schema));
+}
+
+// Test the min_supported_version_for_content function of the SpinOperator
+#[test]
+fn min_supported_version_for_content() {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.min_supported_version_for_content(), (1, 0, 0));
+    so.set(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(so.min_supported_version_for_content(), (1, 0, 0));
+}
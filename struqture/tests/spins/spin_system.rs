@@ -67,6 +67,97 @@ fn internal_map_len() {
     assert_eq!(system.len(), 1_usize);
 }
 
+// Test the drain_filter function of the SpinSystem, which removes the terms from the operator in place
+#[test]
+fn internal_map_drain_filter() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(0).x(1);
+    let mut system = SpinSystem::new(Some(3));
+    system
+        .set(pp_0.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .set(pp_1.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let drained = system.drain_filter(|_, value| value == &CalculatorComplex::from(1.0));
+    assert_eq!(drained, vec![(pp_1, CalculatorComplex::from(1.0))]);
+    assert_eq!(system.len(), 1_usize);
+    assert_eq!(system.get(&pp_0), &CalculatorComplex::from(0.5));
+    assert_eq!(system.number_spins(), 3_usize);
+}
+
+// Test that set_new of the SpinSystem leaves the system unchanged on errors
+#[test]
+fn internal_map_set_new() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(1);
+    let pp_5: PauliProduct = PauliProduct::new().x(5);
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .set(pp_0.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .set_new(pp_1.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    let unchanged = system.clone();
+
+    assert!(system
+        .set_new(pp_0.clone(), CalculatorComplex::from(2.0))
+        .is_err());
+    assert_eq!(
+        system.set_new(pp_5, CalculatorComplex::from(2.0)),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert_eq!(system, unchanged);
+    assert_eq!(
+        system.keys().collect::<Vec<&PauliProduct>>(),
+        unchanged.keys().collect::<Vec<&PauliProduct>>()
+    );
+}
+
+// Test the clear function of the SpinSystem
+#[test]
+fn clear() {
+    let mut system = SpinSystem::new(Some(3));
+    system
+        .set(PauliProduct::new().z(2), CalculatorComplex::from(0.5))
+        .unwrap();
+    system.clear();
+    assert!(system.is_empty());
+    assert_eq!(system.number_spins(), 3_usize);
+    assert_eq!(system, SpinSystem::new(Some(3)));
+}
+
+// Test that add_operator_products_batch of the SpinSystem leaves the system unchanged on failure
+#[test]
+fn add_operator_products_batch_transactional() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(1);
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .set(pp_0.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    let original = system.clone();
+
+    let error = system.add_operator_products_batch(vec![
+        (pp_0.clone(), CalculatorComplex::from(1.0)),
+        (pp_1.clone(), CalculatorComplex::from(2.0)),
+        (PauliProduct::new().z(2), CalculatorComplex::from(3.0)),
+    ]);
+    assert_eq!(error, Err(StruqtureError::NumberSpinsExceeded));
+    assert_eq!(system, original);
+
+    system
+        .add_operator_products_batch(vec![
+            (pp_0.clone(), CalculatorComplex::from(1.0)),
+            (pp_1.clone(), CalculatorComplex::from(2.0)),
+        ])
+        .unwrap();
+    assert_eq!(system.get(&pp_0), &CalculatorComplex::from(1.5));
+    assert_eq!(system.get(&pp_1), &CalculatorComplex::from(2.0));
+}
+
 // Test the set, set_pauli_product, get functions of the SpinSystem
 #[test]
 fn internal_map_set_get_dict() {
@@ -700,6 +791,30 @@ fn clone_partial_eq_system() {
     assert_eq!(system.clone(), system);
 }
 
+// Test the to_hdf5 and from_hdf5 functions of the SpinSystem
+#[cfg(feature = "hdf5")]
+#[test]
+fn hdf5_round_trip() {
+    let mut system = SpinSystem::new(None);
+    system
+        .set(PauliProduct::new().y(1), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    system
+        .set(PauliProduct::new(), CalculatorComplex::from(-0.5))
+        .unwrap();
+
+    let temporary_file = crate::TemporaryHdf5File::new("spin_system_hdf5_round_trip");
+    {
+        let file = hdf5::File::create(temporary_file.path()).unwrap();
+        let group = file.create_group("spin_system").unwrap();
+        system.to_hdf5(&group).unwrap();
+    }
+    let file = hdf5::File::open(temporary_file.path()).unwrap();
+    let group = file.group("spin_system").unwrap();
+    let read = SpinSystem::from_hdf5(&group).unwrap();
+    assert_eq!(read, system);
+}
+
 #[test]
 fn serde_json() {
     let pp: PauliProduct = PauliProduct::new().z(0);
@@ -868,6 +983,34 @@ fn serde_compact() {
     );
 }
 
+// Test that the superoperator of a SpinSystem uses its number of spins for all sparse forms
+#[test]
+fn superoperator_number_spins_larger_than_current() {
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .set(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(system.current_number_spins(), 1);
+
+    let matrix = system.sparse_matrix_superoperator(None).unwrap();
+    let (values, (rows, columns)) = system.sparse_matrix_superoperator_coo(None).unwrap();
+    assert_eq!(values.len(), matrix.len());
+    for (value, (row, column)) in values.iter().zip(rows.iter().zip(columns.iter())) {
+        assert!(*row < 16 && *column < 16);
+        assert_eq!(matrix.get(&(*row, *column)), Some(value));
+    }
+    let (csr_values, _, row_pointers) = system.sparse_matrix_superoperator_csr(None).unwrap();
+    assert_eq!(row_pointers.len(), 17);
+    assert_eq!(csr_values.len(), matrix.len());
+    assert_eq!(
+        system
+            .sparse_matrix_superoperator_entries(None)
+            .unwrap()
+            .count(),
+        matrix.len()
+    );
+}
+
 #[test_case("0Z", &["Z"]; "0Z")]
 #[test_case("0Z", &["I","Z"]; "0Z1I")]
 #[test_case("0X", &["I","X"]; "0X1I")]
@@ -0,0 +1,139 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of TrotterSchedule
+
+use nalgebra as na;
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::prelude::*;
+use struqture::spins::{
+    PauliProduct, SpinHamiltonian, SpinOperator, ToSparseMatrixOperator, TrotterSchedule,
+};
+use test_case::test_case;
+
+// Returns the dense matrix of a SpinOperator on two spins
+fn dense_matrix(operator: &SpinOperator) -> na::DMatrix<Complex64> {
+    let mut matrix: na::DMatrix<Complex64> = na::DMatrix::zeros(4, 4);
+    for ((row, column), value) in operator.sparse_matrix(Some(2)).unwrap() {
+        matrix[(row, column)] = value;
+    }
+    matrix
+}
+
+// Returns the unitary implemented by the TrotterSchedule on two spins
+fn schedule_unitary(schedule: &TrotterSchedule) -> na::DMatrix<Complex64> {
+    let mut unitary: na::DMatrix<Complex64> = na::DMatrix::identity(4, 4);
+    for (product, angle) in schedule.rotations() {
+        let mut operator = SpinOperator::new();
+        operator
+            .add_operator_product(product.clone(), CalculatorComplex::from(1.0))
+            .unwrap();
+        // exp(-i angle P) = cos(angle) I - i sin(angle) P for a Pauli product P
+        let rotation = na::DMatrix::<Complex64>::identity(4, 4) * Complex64::new(angle.cos(), 0.0)
+            + dense_matrix(&operator) * Complex64::new(0.0, -angle.sin());
+        unitary = rotation * unitary;
+    }
+    unitary
+}
+
+fn test_hamiltonian() -> SpinHamiltonian {
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0).x(1), CalculatorFloat::from(0.7))
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.3))
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().y(0), CalculatorFloat::from(0.2))
+        .unwrap();
+    hamiltonian
+}
+
+// Test the structure of the rotations created by from_hamiltonian
+#[test]
+fn from_hamiltonian_structure() {
+    let hamiltonian = test_hamiltonian();
+    let first_order = TrotterSchedule::from_hamiltonian(&hamiltonian, 1.0, 3, 1).unwrap();
+    assert_eq!(first_order.len(), 12);
+    let angle_sum: f64 = first_order
+        .rotations()
+        .iter()
+        .filter(|(product, _)| product == &PauliProduct::new().z(0))
+        .map(|(_, angle)| angle)
+        .sum();
+    assert!((angle_sum - 0.5).abs() < 1e-12);
+
+    let second_order = TrotterSchedule::from_hamiltonian(&hamiltonian, 1.0, 3, 2).unwrap();
+    assert_eq!(second_order.len(), 21);
+    let rotations = second_order.rotations();
+    for (forward, backward) in rotations[0..3].iter().zip(rotations[4..7].iter().rev()) {
+        assert_eq!(forward, backward);
+    }
+    assert_eq!(rotations[0..7], rotations[7..14]);
+    for (product, coefficient) in hamiltonian.iter() {
+        let coefficient: f64 = coefficient.clone().try_into().unwrap();
+        let angle_sum: f64 = rotations
+            .iter()
+            .filter(|(rotation_product, _)| rotation_product == product)
+            .map(|(_, angle)| angle)
+            .sum();
+        assert!((angle_sum - coefficient).abs() < 1e-12);
+    }
+
+    let empty = TrotterSchedule::from_hamiltonian(&SpinHamiltonian::new(), 1.0, 3, 2).unwrap();
+    assert!(empty.is_empty());
+}
+
+// Test the errors of from_hamiltonian
+#[test_case(1, 0; "zero_steps")]
+#[test_case(3, 1; "third_order")]
+#[test_case(0, 5; "zeroth_order")]
+fn from_hamiltonian_error(order: usize, steps: usize) {
+    let hamiltonian = test_hamiltonian();
+    assert!(TrotterSchedule::from_hamiltonian(&hamiltonian, 1.0, steps, order).is_err());
+}
+
+// Test that symbolic coefficients are rejected by from_hamiltonian
+#[test]
+fn from_hamiltonian_symbolic() {
+    let mut symbolic = SpinHamiltonian::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from("theta"))
+        .unwrap();
+    assert!(TrotterSchedule::from_hamiltonian(&symbolic, 1.0, 2, 1).is_err());
+}
+
+// Test that the Trotter error decreases with the expected order in time / steps
+#[test_case(1, 0.5; "first_order")]
+#[test_case(2, 0.25; "second_order")]
+fn trotter_error_scaling(order: usize, expected_ratio: f64) {
+    let hamiltonian = test_hamiltonian();
+    let time = 1.0;
+    let operator = SpinOperator::from(hamiltonian.clone());
+    let exact = (dense_matrix(&operator) * Complex64::new(0.0, -time)).exp();
+
+    let error = |steps: usize| -> f64 {
+        let schedule = TrotterSchedule::from_hamiltonian(&hamiltonian, time, steps, order).unwrap();
+        (schedule_unitary(&schedule) - exact.clone()).norm()
+    };
+    let coarse_error = error(20);
+    let fine_error = error(40);
+    assert!(fine_error < coarse_error);
+    assert!(fine_error > 0.0);
+    let ratio = fine_error / coarse_error;
+    assert!((ratio - expected_ratio).abs() < 0.2 * expected_ratio);
+}